@@ -0,0 +1,228 @@
+//! Static analysis of underconstrained variables.
+//!
+//! A variable that never reaches an assertion can take any value in a valid
+//! proof, which is one of the most common sources of soundness bugs. [`analyze`]
+//! walks a [`CircuitDefinition`] backwards from its assertions and from the
+//! operations that implicitly constrain their inputs, such as the range check of
+//! `ToBinary` or the non-zero divisor of `Div` (see [`has_effect`]), and reports:
+//!
+//! - private inputs that never reach an assertion,
+//! - outputs of `DivUnchecked`, `Div` and `Inverse` that never reach an assertion,
+//! - public inputs that are never read.
+//!
+//! ```rust,ignore
+//! let report = Prover::<MockProverBackend>::new().analyze_circuit::<MyCircuit>();
+//! report.assert_clean();
+//! ```
+
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    passes::has_effect,
+    types::{CircuitDefinition, OpCode, VariableType},
+};
+
+/// A single finding of the analysis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// A private input that never reaches an assertion.
+    UnconstrainedPrivateInput { index: u64 },
+    /// The output of a division or inversion that never reaches an assertion.
+    UnpinnedOutput {
+        operation: usize,
+        op: OpCode,
+        output: VariableType,
+        /// See [`Operation::label`](crate::types::Operation::label).
+        label: String,
+    },
+    /// A public input that is never read.
+    UnusedPublicInput { index: u64 },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::UnconstrainedPrivateInput { index } => {
+                write!(f, "private input {index} never reaches an assertion")
+            }
+            Issue::UnpinnedOutput {
+                operation,
+                op,
+                output,
+                label,
+            } => {
+                write!(f, "output {output:?} of operation {operation} ({op:?})")?;
+                if !label.is_empty() {
+                    write!(f, " in {label}")?;
+                }
+                write!(f, " never reaches an assertion")
+            }
+            Issue::UnusedPublicInput { index } => {
+                write!(f, "public input {index} is never read")
+            }
+        }
+    }
+}
+
+/// The result of [`analyze`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnalysisReport {
+    pub issues: Vec<Issue>,
+}
+
+impl AnalysisReport {
+    /// Returns `true` if no issue was found.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Panics with the full report if any issue was found.
+    ///
+    /// This is intended to be called from tests, so that CI fails on
+    /// underconstrained circuits.
+    #[track_caller]
+    pub fn assert_clean(&self) {
+        if !self.is_clean() {
+            panic!("circuit is underconstrained:\n{self}");
+        }
+    }
+}
+
+impl fmt::Display for AnalysisReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return writeln!(f, "no issues found");
+        }
+
+        for issue in &self.issues {
+            writeln!(f, "- {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if `op` constrains its inputs even when its outputs are unused.
+fn constrains_inputs(op: OpCode) -> bool {
+    has_effect(op) && op != OpCode::Println
+}
+
+/// Analyzes `circuit` for underconstrained variables.
+pub fn analyze(circuit: &CircuitDefinition) -> AnalysisReport {
+    let mut constrained = HashSet::new();
+
+    for operation in circuit.operations.iter().rev() {
+        let reaches = constrains_inputs(operation.op)
+            || operation
+                .outputs
+                .iter()
+                .any(|output| constrained.contains(output));
+
+        if reaches {
+            constrained.extend(operation.inputs.iter().cloned());
+        }
+    }
+
+    let read: HashSet<_> = circuit
+        .operations
+        .iter()
+        .flat_map(|operation| operation.inputs.iter())
+        .collect();
+
+    let mut issues = Vec::new();
+
+    for index in 0..circuit.private_len {
+        if !constrained.contains(&VariableType::Private(index)) {
+            issues.push(Issue::UnconstrainedPrivateInput { index });
+        }
+    }
+
+    for (idx, operation) in circuit.operations.iter().enumerate() {
+        if !matches!(
+            operation.op,
+            OpCode::DivUnchecked | OpCode::Div | OpCode::Inverse
+        ) {
+            continue;
+        }
+
+        for output in &operation.outputs {
+            if !constrained.contains(output) {
+                issues.push(Issue::UnpinnedOutput {
+                    operation: idx,
+                    op: operation.op,
+                    output: output.clone(),
+                    label: operation.label(),
+                });
+            }
+        }
+    }
+
+    for index in 0..circuit.public_len {
+        if !read.contains(&VariableType::Public(index)) {
+            issues.push(Issue::UnusedPublicInput { index });
+        }
+    }
+
+    AnalysisReport { issues }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{API, ProvingSystem, curve::BN254, test_builder};
+
+    #[test]
+    fn test_analyze() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let a = api.variable_initer_mut().new_private();
+        let b = api.variable_initer_mut().new_private();
+        let c = api.variable_initer_mut().new_public(false);
+        let _d = api.variable_initer_mut().new_public(false);
+
+        let sum = api.add(&a, &c);
+        api.assert_is_equal(&sum, &1);
+
+        api.println(&b);
+        let _inv = api.inverse(&a);
+
+        let circuit = api.build();
+        let label = circuit.operations[3].label();
+        let report = analyze(&circuit);
+
+        assert_eq!(
+            report.issues,
+            vec![
+                Issue::UnconstrainedPrivateInput { index: 1 },
+                Issue::UnpinnedOutput {
+                    operation: 3,
+                    op: OpCode::Inverse,
+                    output: VariableType::Local(1),
+                    label,
+                },
+                Issue::UnusedPublicInput { index: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_implicit_constraints() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let a = api.variable_initer_mut().new_private();
+        let b = api.variable_initer_mut().new_private();
+        let c = api.variable_initer_mut().new_private();
+
+        // Only range-checked, divided or used as boolean inputs
+        api.variable_to_binary(&a, 8);
+        let _ = api.div(&1, &b);
+        let _ = api.xor(&c, &1);
+
+        let report = analyze(&api.build());
+
+        assert!(
+            !report
+                .issues
+                .iter()
+                .any(|issue| matches!(issue, Issue::UnconstrainedPrivateInput { .. }))
+        );
+    }
+}
@@ -12,6 +12,12 @@ use crate::{
 pub trait API {
     fn metadata(&self) -> &impl Metadata;
 
+    /// Records an operation.
+    ///
+    /// This method and all operations built on it are `#[track_caller]`, so
+    /// implementations can attribute each operation to the circuit code that
+    /// recorded it through [`Location::caller`](std::panic::Location::caller).
+    #[track_caller]
     fn append_operation(
         &mut self,
         op: OpCode,
@@ -21,6 +27,35 @@ pub trait API {
 
     fn allocate_local_variable(&mut self) -> VariableType;
 
+    /// Enters a nested namespace. Prefer [`scope`](API::scope).
+    ///
+    /// Implementations that do not label operations can ignore namespaces,
+    /// which is the default.
+    fn push_namespace(&mut self, name: &str) {
+        let _ = name;
+    }
+
+    /// Leaves the namespace entered last. Prefer [`scope`](API::scope).
+    fn pop_namespace(&mut self) {}
+
+    /// Runs `f` inside the namespace `name`.
+    ///
+    /// Every operation recorded by `f` is labeled with the namespace, nested
+    /// inside the namespaces that are already active:
+    ///
+    /// ```rust,ignore
+    /// let sum = api.scope("adder", |api| api.add(&self.a, &self.b));
+    /// ```
+    fn scope<R>(&mut self, name: &str, f: impl FnOnce(&mut Self) -> R) -> R
+    where
+        Self: Sized,
+    {
+        self.push_namespace(name);
+        let res = f(self);
+        self.pop_namespace();
+        res
+    }
+
     fn allocate_local_variable_n(&mut self, n: u64) -> Vec<VariableType> {
         let mut res = Vec::with_capacity(n as usize);
         for _ in 0..n {
@@ -39,6 +74,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the sum
+    #[track_caller]
     fn add(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         self.add_multi(x1, x2, &[])
     }
@@ -52,6 +88,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the sum of all operands
+    #[track_caller]
     fn add_multi(
         &mut self,
         x1: &impl Variable,
@@ -81,6 +118,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the result a + (b * c)
+    #[track_caller]
     fn mul_acc(&mut self, a: &impl Variable, b: &impl Variable, c: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -100,6 +138,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the negated value
+    #[track_caller]
     fn neg(&mut self, x: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -118,6 +157,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the difference
+    #[track_caller]
     fn sub(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         self.sub_multi(x1, x2, &[])
     }
@@ -131,6 +171,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the result of all subtractions
+    #[track_caller]
     fn sub_multi(
         &mut self,
         x1: &impl Variable,
@@ -158,6 +199,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the product
+    #[track_caller]
     fn mul(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         self.mul_multi(x1, x2, &[])
     }
@@ -171,6 +213,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the product of all operands
+    #[track_caller]
     fn mul_multi(
         &mut self,
         x1: &impl Variable,
@@ -202,6 +245,7 @@ pub trait API {
     ///
     /// # Safety
     /// This operation does not verify that x2 ≠ 0. Use `div` for checked division.
+    #[track_caller]
     fn div_unchecked(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -228,6 +272,7 @@ pub trait API {
     ///
     /// # Panics
     /// The circuit will be unsatisfiable if x2 == 0
+    #[track_caller]
     fn div(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -249,6 +294,7 @@ pub trait API {
     ///
     /// # Panics
     /// The circuit will be unsatisfiable if x == 0
+    #[track_caller]
     fn inverse(&mut self, x: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -269,6 +315,7 @@ pub trait API {
     /// # Returns
     /// A vector of local variables representing the binary decomposition,
     /// where index 0 is the least significant bit
+    #[track_caller]
     fn variable_to_binary(&mut self, x: &impl Variable, n: u64) -> Vec<VariableType> {
         let res = self.allocate_local_variable_n(n);
 
@@ -287,6 +334,7 @@ pub trait API {
     ///
     /// # Returns
     /// A local variable representing the packed binary value
+    #[track_caller]
     fn variable_from_binary(&mut self, b: &[&dyn Variable]) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -307,6 +355,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the XOR result
+    #[track_caller]
     fn xor(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -323,6 +372,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the OR result
+    #[track_caller]
     fn or(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -339,6 +389,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the AND result
+    #[track_caller]
     fn and(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -359,6 +410,7 @@ pub trait API {
     ///
     /// # Returns
     /// A new local variable containing the selected value
+    #[track_caller]
     fn select(
         &mut self,
         x1: &impl Variable,
@@ -391,6 +443,7 @@ pub trait API {
     ///
     /// # Returns
     /// The selected value based on the 2-bit index
+    #[track_caller]
     fn lookup2(
         &mut self,
         b0: &impl Variable,
@@ -421,6 +474,7 @@ pub trait API {
     ///
     /// # Returns
     /// A boolean variable (1 if x == 0, 0 if x != 0)
+    #[track_caller]
     fn is_zero(&mut self, x: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -442,6 +496,7 @@ pub trait API {
     ///
     /// # Returns
     /// A local variable containing the comparison result (-1, 0, or 1)
+    #[track_caller]
     fn cmp(&mut self, x1: &impl Variable, x2: &impl Variable) -> VariableType {
         let res = self.allocate_local_variable();
 
@@ -461,6 +516,7 @@ pub trait API {
     ///
     /// # Panics
     /// The circuit will be unsatisfiable if x1 != x2
+    #[track_caller]
     fn assert_is_equal(&mut self, x1: &impl Variable, x2: &impl Variable) {
         self.append_operation(OpCode::AssertIsEqual, vec![x1.ty(), x2.ty()], vec![]);
     }
@@ -476,6 +532,7 @@ pub trait API {
     ///
     /// # Panics
     /// The circuit will be unsatisfiable if x1 == x2
+    #[track_caller]
     fn assert_is_different(&mut self, x1: &impl Variable, x2: &impl Variable) {
         self.append_operation(OpCode::AssertIsDifferent, vec![x1.ty(), x2.ty()], vec![]);
     }
@@ -490,6 +547,7 @@ pub trait API {
     ///
     /// # Panics
     /// The circuit will be unsatisfiable if x is not 0 or 1
+    #[track_caller]
     fn assert_is_boolean(&mut self, x: &impl Variable) {
         self.append_operation(OpCode::AssertIsBoolean, vec![x.ty()], vec![]);
     }
@@ -504,6 +562,7 @@ pub trait API {
     ///
     /// # Panics
     /// The circuit will be unsatisfiable if x is not in {0, 1, 2, 3}
+    #[track_caller]
     fn assert_is_crumb(&mut self, x: &impl Variable) {
         self.append_operation(OpCode::AssertIsCrumb, vec![x.ty()], vec![]);
     }
@@ -519,6 +578,7 @@ pub trait API {
     ///
     /// # Panics
     /// The circuit will be unsatisfiable if v > bound
+    #[track_caller]
     fn assert_is_less_or_equal(&mut self, v: &impl Variable, bound: &impl Variable) {
         self.append_operation(
             OpCode::AssertIsLessOrEqual,
@@ -534,6 +594,7 @@ pub trait API {
     ///
     /// # Arguments
     /// * `message` - The variable value to print
+    #[track_caller]
     fn println(&mut self, message: &impl Variable) {
         self.append_operation(OpCode::Println, vec![message.ty()], vec![]);
     }
@@ -0,0 +1,45 @@
+//! Modular arithmetic helpers over the circuit field.
+
+use num::{BigInt, Integer, One, Zero};
+
+/// Reduces `x` into `[0, p)`.
+pub(crate) fn reduce(x: &BigInt, p: &BigInt) -> BigInt {
+    x.mod_floor(p)
+}
+
+pub(crate) fn add(a: &BigInt, b: &BigInt, p: &BigInt) -> BigInt {
+    reduce(&(a + b), p)
+}
+
+pub(crate) fn sub(a: &BigInt, b: &BigInt, p: &BigInt) -> BigInt {
+    reduce(&(a - b), p)
+}
+
+pub(crate) fn mul(a: &BigInt, b: &BigInt, p: &BigInt) -> BigInt {
+    reduce(&(a * b), p)
+}
+
+pub(crate) fn neg(a: &BigInt, p: &BigInt) -> BigInt {
+    reduce(&-a, p)
+}
+
+/// Returns the multiplicative inverse of `a`, or `None` if it does not exist.
+pub(crate) fn inverse(a: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let a = reduce(a, p);
+    if a.is_zero() {
+        return None;
+    }
+
+    let gcd = a.extended_gcd(p);
+    if !gcd.gcd.is_one() {
+        return None;
+    }
+
+    Some(reduce(&gcd.x, p))
+}
+
+/// Returns `true` if `a` is 0 or 1 in the field.
+pub(crate) fn is_bit(a: &BigInt, p: &BigInt) -> bool {
+    let a = reduce(a, p);
+    a.is_zero() || a.is_one()
+}
@@ -1,14 +1,39 @@
+use std::{collections::HashMap, panic::Location};
+
 use crate::{
     API, Metadata, MetadataInfo, Variable, VariableIniter,
+    schema::SCHEMA_VERSION,
+    simplify::{Simplified, simplify},
     types::{CircuitDefinition, OpCode, Operation, VariableType},
 };
 
+/// Operation counts collected while building a circuit.
+///
+/// `recorded` counts every operation appended through the [`API`], while
+/// `emitted` counts the operations that end up in the [`CircuitDefinition`].
+/// Without simplification both are equal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuilderStats {
+    /// Operations appended through the API.
+    pub recorded: usize,
+    /// Operations kept in the circuit definition.
+    pub emitted: usize,
+    /// Operations removed because all of their inputs were constants.
+    pub folded: usize,
+    /// Operations removed or rewritten by an algebraic identity, such as `x * 1`.
+    pub simplified: usize,
+}
+
 #[doc(hidden)]
 #[derive(Debug)]
 pub struct CircuitBuilder {
     operations: Vec<Operation>,
     variable_initer: VariableIniter,
     metadata: MetadataInfo,
+    simplify: bool,
+    substitutions: HashMap<u64, VariableType>,
+    stats: BuilderStats,
+    namespace: Vec<String>,
 }
 
 impl CircuitBuilder {
@@ -17,9 +42,33 @@ impl CircuitBuilder {
             operations: Vec::new(),
             variable_initer: VariableIniter::default(),
             metadata,
+            simplify: false,
+            substitutions: HashMap::new(),
+            stats: BuilderStats::default(),
+            namespace: Vec::new(),
         }
     }
 
+    /// Enables constant folding and algebraic simplification.
+    ///
+    /// When enabled, operations whose inputs are all constants are evaluated in
+    /// the field of [`Metadata::field`] instead of being recorded, and identities
+    /// such as `x + 0`, `x * 1` or `x - x` are simplified. Every later use of a
+    /// removed operation's output is rewritten to its value, and local variables
+    /// are renumbered by [`build`](Self::build).
+    ///
+    /// Assertions are only removed when they always hold, so an unsatisfiable
+    /// circuit stays unsatisfiable.
+    pub fn with_simplify(mut self, enabled: bool) -> Self {
+        self.simplify = enabled;
+        self
+    }
+
+    /// Returns the operation counts collected so far.
+    pub fn stats(&self) -> &BuilderStats {
+        &self.stats
+    }
+
     pub fn variable_initer(&self) -> &VariableIniter {
         &self.variable_initer
     }
@@ -33,32 +82,88 @@ impl CircuitBuilder {
         op: OpCode,
         inputs: Vec<VariableType>,
         outputs: Vec<VariableType>,
+        location: &Location<'_>,
     ) {
+        self.stats.recorded += 1;
+
         let mut _outs = Vec::with_capacity(outputs.len());
         for output in outputs {
             _outs.push(output.ty());
         }
 
+        let mut inputs = inputs;
+
+        if self.simplify {
+            let resolved = inputs.into_iter().map(|x| self.resolve(x)).collect();
+
+            match simplify(op, resolved, _outs.len(), &self.metadata.field) {
+                Simplified::Keep {
+                    inputs: new_inputs,
+                    rewritten,
+                } => {
+                    if rewritten {
+                        self.stats.simplified += 1;
+                    }
+                    inputs = new_inputs;
+                }
+                Simplified::Replace { outputs, folded } => {
+                    if folded {
+                        self.stats.folded += 1;
+                    } else {
+                        self.stats.simplified += 1;
+                    }
+
+                    for (output, value) in _outs.into_iter().zip(outputs) {
+                        if let VariableType::Local(idx) = output {
+                            self.substitutions.insert(idx, value);
+                        }
+                    }
+                    return;
+                }
+                Simplified::Drop => {
+                    self.stats.folded += 1;
+                    return;
+                }
+            }
+        }
+
         let operation = Operation {
             op,
             inputs,
             outputs: _outs,
+            span: Some(location.into()),
+            namespace: self.namespace.clone(),
         };
 
+        self.stats.emitted += 1;
         self.operations.push(operation);
     }
 
+    fn resolve(&self, x: VariableType) -> VariableType {
+        match &x {
+            VariableType::Local(idx) => self.substitutions.get(idx).cloned().unwrap_or(x),
+            _ => x,
+        }
+    }
+
     fn _allocate_local_variable(&mut self) -> VariableType {
         self.variable_initer.new_local()
     }
 
     pub fn build(self) -> CircuitDefinition {
-        CircuitDefinition {
+        let mut definition = CircuitDefinition {
+            schema_version: SCHEMA_VERSION,
             private_len: self.variable_initer.private_index(),
             public_len: self.variable_initer.public_index(),
             local_len: self.variable_initer.local_index(),
             operations: self.operations,
+        };
+
+        if self.simplify {
+            definition.compact_locals();
         }
+
+        definition
     }
 }
 
@@ -73,10 +178,117 @@ impl API for CircuitBuilder {
         inputs: Vec<VariableType>,
         outputs: Vec<VariableType>,
     ) {
-        self._append_operation(op, inputs, outputs);
+        self._append_operation(op, inputs, outputs, Location::caller());
     }
 
     fn allocate_local_variable(&mut self) -> VariableType {
         self._allocate_local_variable()
     }
+
+    fn push_namespace(&mut self, name: &str) {
+        self.namespace.push(name.to_string());
+    }
+
+    fn pop_namespace(&mut self) {
+        self.namespace.pop();
+    }
+}
+
+/// A builder over the field of `C`, shared by the unit tests of this crate.
+#[cfg(test)]
+pub(crate) fn test_builder<C: crate::CurveId>(
+    proving_system: crate::ProvingSystem,
+) -> CircuitBuilder {
+    CircuitBuilder::new(MetadataInfo {
+        field: C::field(),
+        curve: C::curve_type(),
+        proving_system,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProvingSystem, curve::BN254};
+
+    fn builder(simplify: bool) -> CircuitBuilder {
+        test_builder::<BN254>(ProvingSystem::Groth16).with_simplify(simplify)
+    }
+
+    fn define(api: &mut CircuitBuilder) {
+        let x = api.variable_initer_mut().new_private();
+
+        let a = api.add(&3, &4);
+        let b = api.mul(&x, &1);
+        let c = api.add(&b, &0);
+        let d = api.sub(&c, &x);
+        let e = api.mul(&a, &x);
+        let f = api.div(&e, &-7);
+
+        api.assert_is_equal(&d, &0);
+        let g = api.neg(&x);
+        api.assert_is_equal(&f, &g);
+    }
+
+    #[test]
+    fn test_simplify() {
+        let mut plain = builder(false);
+        define(&mut plain);
+        assert_eq!(plain.stats().recorded, 9);
+        assert_eq!(plain.stats().emitted, 9);
+
+        let mut simplified = builder(true);
+        define(&mut simplified);
+        let stats = simplified.stats().clone();
+
+        assert_eq!(stats.recorded, 9);
+        assert_eq!(stats.folded, 2);
+        assert_eq!(stats.simplified, 3);
+        assert_eq!(stats.emitted, 4);
+
+        let definition = simplified.build();
+        assert_eq!(definition.local_len, 3);
+        assert_eq!(definition.operations.len(), 4);
+
+        // `a` is folded into the constant 7.
+        let mul = &definition.operations[0];
+        assert_eq!(mul.op, OpCode::Mul);
+        assert_eq!(
+            mul.inputs,
+            vec![VariableType::Constant(7.into()), VariableType::Private(0)]
+        );
+        assert_eq!(mul.outputs, vec![VariableType::Local(0)]);
+    }
+
+    #[test]
+    fn test_labels() {
+        let mut api = builder(false);
+        let x = api.variable_initer_mut().new_private();
+
+        api.scope("outer", |api| {
+            api.scope("inner", |api| api.assert_is_boolean(&x));
+        });
+        let line = line!() - 2;
+
+        let definition = api.build();
+        let operation = &definition.operations[0];
+
+        assert_eq!(operation.namespace, vec!["outer", "inner"]);
+        assert_eq!(operation.span.as_ref().unwrap().line, line);
+        assert!(
+            operation
+                .label()
+                .starts_with("outer/inner at core/src/builder.rs:")
+        );
+    }
+
+    #[test]
+    fn test_failing_assertion_is_kept() {
+        let mut api = builder(true);
+        api.assert_is_equal(&1, &2);
+        api.assert_is_equal(&1, &1);
+
+        let definition = api.build();
+        assert_eq!(definition.operations.len(), 1);
+    }
 }
@@ -1,6 +1,7 @@
 use num::BigInt;
+use ruint::aliases::U256;
 
-use crate::{API, VariableIniter, variable::CircuitVariable};
+use crate::{API, CurveId, Fr, VariableIniter, variable::CircuitVariable};
 
 /// Defines the logic of an arithmetic circuit for zero-knowledge proofs.
 ///
@@ -11,6 +12,25 @@ pub trait Circuit {
     fn define(&self, api: &mut impl API);
 }
 
+/// Defines a circuit inside a namespace.
+///
+/// The `#[circuit]` macro generates an inherent `define` method on every circuit
+/// structure that calls this, so sub-circuits are labeled with their struct name.
+#[doc(hidden)]
+pub trait DefineInScope<A> {
+    fn define_in_scope(&self, name: &str, api: &mut A);
+}
+
+impl<T, A> DefineInScope<A> for T
+where
+    T: Circuit + ?Sized,
+    A: API,
+{
+    fn define_in_scope(&self, name: &str, api: &mut A) {
+        api.scope(name, |api| Circuit::define(self, api));
+    }
+}
+
 /// Defines a circuit with witness data structure.
 ///
 /// This trait represents the witness data that corresponds to a `CircuitElement`.
@@ -143,3 +163,136 @@ define_circuit_element_for_from_u256!(i32);
 define_circuit_element_for_from_u256!(i16);
 define_circuit_element_for_from_u256!(i8);
 define_circuit_element_for_from_u256!(bool);
+define_circuit_element_for_from_u256!(U256);
+
+impl CircuitWitness for BigInt {
+    type CircuitElement = CircuitVariable<BigInt>;
+    type PublicWitness = BigInt;
+
+    fn create_public(initer: &mut VariableIniter, is_private: bool) -> Self::CircuitElement {
+        initer.new_public(is_private).into()
+    }
+
+    fn create_private(initer: &mut VariableIniter) -> Self::CircuitElement {
+        initer.new_private().into()
+    }
+
+    fn into_public_witness(self) -> Self::PublicWitness {
+        self
+    }
+
+    fn append_witness(
+        &self,
+        public: &mut Vec<BigInt>,
+        private: &mut Vec<BigInt>,
+        is_private: bool,
+    ) {
+        if is_private {
+            private.push(self.clone());
+        } else {
+            public.push(self.clone());
+        }
+    }
+}
+
+impl CircuitPublicWitness for BigInt {
+    fn append_public_witness(&self, witness: &mut Vec<BigInt>, is_private: bool) {
+        if !is_private {
+            witness.push(self.clone());
+        }
+    }
+}
+
+impl CircuitElement for CircuitVariable<BigInt> {
+    type CircuitWitness = BigInt;
+}
+
+impl CircuitElement for BigInt {
+    type CircuitWitness = BigInt;
+}
+
+impl<C: CurveId> CircuitWitness for Fr<C> {
+    type CircuitElement = CircuitVariable<Fr<C>>;
+    type PublicWitness = Fr<C>;
+
+    fn create_public(initer: &mut VariableIniter, is_private: bool) -> Self::CircuitElement {
+        initer.new_public(is_private).into()
+    }
+
+    fn create_private(initer: &mut VariableIniter) -> Self::CircuitElement {
+        initer.new_private().into()
+    }
+
+    fn into_public_witness(self) -> Self::PublicWitness {
+        self
+    }
+
+    fn append_witness(
+        &self,
+        public: &mut Vec<BigInt>,
+        private: &mut Vec<BigInt>,
+        is_private: bool,
+    ) {
+        if is_private {
+            private.push(self.value().clone());
+        } else {
+            public.push(self.value().clone());
+        }
+    }
+}
+
+impl<C: CurveId> CircuitPublicWitness for Fr<C> {
+    fn append_public_witness(&self, witness: &mut Vec<BigInt>, is_private: bool) {
+        if !is_private {
+            witness.push(self.value().clone());
+        }
+    }
+}
+
+impl<C: CurveId> CircuitElement for CircuitVariable<Fr<C>> {
+    type CircuitWitness = Fr<C>;
+}
+
+impl<C: CurveId> CircuitElement for Fr<C> {
+    type CircuitWitness = Fr<C>;
+}
+
+impl<T: CircuitWitness, const N: usize> CircuitWitness for [T; N] {
+    type CircuitElement = [T::CircuitElement; N];
+    type PublicWitness = [T::PublicWitness; N];
+
+    fn create_public(initer: &mut VariableIniter, is_private: bool) -> Self::CircuitElement {
+        std::array::from_fn(|_| T::create_public(initer, is_private))
+    }
+
+    fn create_private(initer: &mut VariableIniter) -> Self::CircuitElement {
+        std::array::from_fn(|_| T::create_private(initer))
+    }
+
+    fn into_public_witness(self) -> Self::PublicWitness {
+        self.map(T::into_public_witness)
+    }
+
+    fn append_witness(
+        &self,
+        public: &mut Vec<BigInt>,
+        private: &mut Vec<BigInt>,
+        is_private: bool,
+    ) {
+        for element in self {
+            element.append_witness(public, private, is_private);
+        }
+    }
+}
+
+impl<T: CircuitPublicWitness, const N: usize> CircuitPublicWitness for [T; N] {
+    fn append_public_witness(&self, witness: &mut Vec<BigInt>, is_private: bool) {
+        for element in self {
+            element.append_public_witness(witness, is_private);
+        }
+    }
+}
+
+impl<T: CircuitElement, const N: usize> CircuitElement for [T; N] {
+    type CircuitWitness = [T::CircuitWitness; N];
+}
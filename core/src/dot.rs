@@ -0,0 +1,248 @@
+//! Graphviz export of the circuit dataflow graph.
+//!
+//! [`DotExporter`] renders a [`CircuitDefinition`] in the DOT language: every
+//! operation and every input is a node, and an edge goes from the producer of a
+//! variable to each operation reading it.
+//!
+//! ```rust,ignore
+//! let definition = Prover::<MockProverBackend>::new().define_circuit::<MyCircuit>();
+//! let dot = DotExporter::new().with_clusters(true).export(&definition);
+//! std::fs::write("circuit.dot", dot)?;
+//! ```
+
+use std::{collections::HashMap, fmt::Write};
+
+use crate::types::{CircuitDefinition, Operation, VariableType};
+
+/// Exporter of circuit definitions to DOT.
+#[derive(Debug, Clone)]
+pub struct DotExporter {
+    clusters: bool,
+    namespace_labels: bool,
+    highlight_assertions: bool,
+    highlight_public: bool,
+}
+
+impl Default for DotExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DotExporter {
+    /// Creates an exporter that highlights assertions and public inputs, without clusters.
+    pub fn new() -> Self {
+        Self {
+            clusters: false,
+            namespace_labels: true,
+            highlight_assertions: true,
+            highlight_public: true,
+        }
+    }
+
+    /// Groups the operations of each namespace into a (nested) cluster.
+    pub fn with_clusters(mut self, clusters: bool) -> Self {
+        self.clusters = clusters;
+        self
+    }
+
+    /// Adds the namespace of an operation to its label. Ignored when clustering.
+    pub fn with_namespace_labels(mut self, namespace_labels: bool) -> Self {
+        self.namespace_labels = namespace_labels;
+        self
+    }
+
+    /// Fills assertion nodes with a distinct color.
+    pub fn with_highlight_assertions(mut self, highlight: bool) -> Self {
+        self.highlight_assertions = highlight;
+        self
+    }
+
+    /// Fills public input nodes with a distinct color.
+    pub fn with_highlight_public(mut self, highlight: bool) -> Self {
+        self.highlight_public = highlight;
+        self
+    }
+
+    /// Renders `circuit` as a DOT digraph.
+    pub fn export(&self, circuit: &CircuitDefinition) -> String {
+        let mut out =
+            String::from("digraph circuit {\n  rankdir=TB;\n  node [fontname=\"monospace\"];\n");
+
+        for i in 0..circuit.public_len {
+            let style = if self.highlight_public {
+                ", style=filled, fillcolor=lightblue"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "  public_{i} [label=\"public {i}\", shape=ellipse{style}];"
+            );
+        }
+        for i in 0..circuit.private_len {
+            let _ = writeln!(out, "  private_{i} [label=\"private {i}\", shape=ellipse];");
+        }
+
+        if self.clusters {
+            let mut root = Cluster::default();
+            for (idx, operation) in circuit.operations.iter().enumerate() {
+                root.insert(&operation.namespace, idx);
+            }
+            let mut next_id = 0;
+            self.write_cluster(&mut out, &root, circuit, &mut next_id, 1);
+        } else {
+            for (idx, operation) in circuit.operations.iter().enumerate() {
+                self.write_operation(&mut out, idx, operation, 1);
+            }
+        }
+
+        self.write_edges(&mut out, circuit);
+
+        out.push_str("}\n");
+        out
+    }
+
+    fn write_cluster(
+        &self,
+        out: &mut String,
+        cluster: &Cluster,
+        circuit: &CircuitDefinition,
+        next_id: &mut usize,
+        depth: usize,
+    ) {
+        for &idx in &cluster.operations {
+            self.write_operation(out, idx, &circuit.operations[idx], depth);
+        }
+
+        let indent = "  ".repeat(depth);
+        for (name, child) in &cluster.children {
+            let _ = writeln!(out, "{indent}subgraph cluster_{next_id} {{");
+            let _ = writeln!(out, "{indent}  label=\"{}\";", escape(name));
+            *next_id += 1;
+            self.write_cluster(out, child, circuit, next_id, depth + 1);
+            let _ = writeln!(out, "{indent}}}");
+        }
+    }
+
+    fn write_operation(&self, out: &mut String, idx: usize, operation: &Operation, depth: usize) {
+        let mut label = format!("{:?}", operation.op);
+        if self.namespace_labels && !self.clusters && !operation.namespace.is_empty() {
+            label = format!("{label}\n{}", operation.namespace.join("/"));
+        }
+
+        let style = if self.highlight_assertions && operation.op.is_assertion() {
+            ", style=filled, fillcolor=salmon"
+        } else {
+            ""
+        };
+
+        let indent = "  ".repeat(depth);
+        let _ = writeln!(
+            out,
+            "{indent}op_{idx} [label=\"{}\", shape=box{style}];",
+            escape(&label)
+        );
+    }
+
+    fn write_edges(&self, out: &mut String, circuit: &CircuitDefinition) {
+        // Local variable -> (producing operation, output position if it has several outputs).
+        let mut producers = HashMap::new();
+
+        for (idx, operation) in circuit.operations.iter().enumerate() {
+            for (pos, input) in operation.inputs.iter().enumerate() {
+                let (source, output) = match input {
+                    VariableType::Public(i) => (format!("public_{i}"), None),
+                    VariableType::Private(i) => (format!("private_{i}"), None),
+                    VariableType::Local(i) => match producers.get(i) {
+                        Some((producer, output)) => (format!("op_{producer}"), *output),
+                        None => continue,
+                    },
+                    VariableType::Constant(v) => {
+                        let _ =
+                            writeln!(out, "  const_{idx}_{pos} [label=\"{v}\", shape=plaintext];");
+                        (format!("const_{idx}_{pos}"), None)
+                    }
+                };
+
+                match output {
+                    Some(output) => {
+                        let _ = writeln!(out, "  {source} -> op_{idx} [label=\"[{output}]\"];");
+                    }
+                    None => {
+                        let _ = writeln!(out, "  {source} -> op_{idx};");
+                    }
+                }
+            }
+
+            let multiple = operation.outputs.len() > 1;
+            for (pos, output) in operation.outputs.iter().enumerate() {
+                if let VariableType::Local(i) = output {
+                    producers.insert(*i, (idx, multiple.then_some(pos)));
+                }
+            }
+        }
+    }
+}
+
+/// A namespace with its own operations and nested namespaces, in order of appearance.
+#[derive(Default)]
+struct Cluster {
+    operations: Vec<usize>,
+    children: Vec<(String, Cluster)>,
+}
+
+impl Cluster {
+    fn insert(&mut self, namespace: &[String], idx: usize) {
+        let Some((first, rest)) = namespace.split_first() else {
+            self.operations.push(idx);
+            return;
+        };
+
+        let pos = match self.children.iter().position(|(name, _)| name == first) {
+            Some(pos) => pos,
+            None => {
+                self.children.push((first.clone(), Cluster::default()));
+                self.children.len() - 1
+            }
+        };
+
+        self.children[pos].1.insert(rest, idx);
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{API, ProvingSystem, curve::BN254, test_builder};
+
+    #[test]
+    fn test_export() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let a = api.variable_initer_mut().new_private();
+        let b = api.variable_initer_mut().new_public(false);
+
+        api.scope("outer", |api| {
+            let s = api.add(&a, &3);
+            api.scope("inner", |api| api.assert_is_equal(&s, &b));
+        });
+        let definition = api.build();
+
+        let dot = DotExporter::new().export(&definition);
+        assert!(dot.contains("op_0 [label=\"Add\\nouter\", shape=box];"));
+        assert!(dot.contains("fillcolor=salmon"));
+        assert!(dot.contains("fillcolor=lightblue"));
+        assert!(dot.contains("private_0 -> op_0;"));
+        assert!(dot.contains("op_0 -> op_1;"));
+
+        let dot = DotExporter::new().with_clusters(true).export(&definition);
+        assert!(dot.contains("subgraph cluster_0 {\n    label=\"outer\";"));
+        assert!(dot.contains("subgraph cluster_1 {\n      label=\"inner\";"));
+    }
+}
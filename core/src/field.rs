@@ -0,0 +1,158 @@
+//! Field element type bound to the scalar field of a curve.
+//!
+//! # Reduction Policy
+//!
+//! Every [`Fr`] holds a canonical value in `[0, p)`, where `p` is `C::field()`.
+//! Values outside of this range are handled explicitly by the constructor used:
+//!
+//! - [`Fr::new`] reduces the value modulo `p`. Values greater than or equal to `p`
+//!   wrap around, and negative values are mapped to their additive inverse
+//!   (`-1` becomes `p - 1`).
+//! - [`Fr::from_canonical`] rejects any value that is negative or not less than `p`.
+//!
+//! Witnesses of other types, such as `U256`, `BigInt` or signed integers, are
+//! reduced like [`Fr::new`] by the provers before they reach the backend, see
+//! [`Witness::reduce`](crate::types::Witness::reduce).
+//!
+//! ```rust,ignore
+//! use rsnark_core::{Fr, curve::BN254};
+//!
+//! let a = Fr::<BN254>::new(-1);
+//! assert_eq!(a.value(), &(BN254::field() - 1));
+//!
+//! assert!(Fr::<BN254>::from_canonical(BN254::field()).is_none());
+//! ```
+
+use std::{fmt, marker::PhantomData};
+
+use num::{BigInt, Integer, Signed, Zero};
+
+use crate::CurveId;
+
+/// An element of the scalar field of curve `C`.
+///
+/// `Fr` can be used as a field type of `#[circuit]` structures and as a constant
+/// [`Variable`](crate::Variable) in circuit operations. See the
+/// [module documentation](crate::field) for the reduction policy.
+pub struct Fr<C> {
+    value: BigInt,
+    marker: PhantomData<C>,
+}
+
+impl<C> Fr<C>
+where
+    C: CurveId,
+{
+    /// Creates a field element, reducing `value` modulo `C::field()`.
+    ///
+    /// Negative values are mapped to their additive inverse in the field.
+    pub fn new(value: impl Into<BigInt>) -> Self {
+        let value = value.into().mod_floor(&C::field());
+
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a field element from a value already in canonical form.
+    ///
+    /// # Returns
+    /// `None` if `value` is negative or not less than `C::field()`.
+    pub fn from_canonical(value: impl Into<BigInt>) -> Option<Self> {
+        let value = value.into();
+
+        if value.is_negative() || value >= C::field() {
+            return None;
+        }
+
+        Some(Self {
+            value,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the additive identity.
+    pub fn zero() -> Self {
+        Self::new(0)
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<C> Fr<C> {
+    /// Returns the canonical value of this element, in `[0, p)`.
+    pub fn value(&self) -> &BigInt {
+        &self.value
+    }
+
+    /// Consumes this element and returns its canonical value.
+    pub fn into_inner(self) -> BigInt {
+        self.value
+    }
+
+    /// Returns `true` if this element is zero.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<C> Clone for Fr<C> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> PartialEq for Fr<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<C> Eq for Fr<C> {}
+
+impl<C> fmt::Debug for Fr<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Fr").field(&self.value).finish()
+    }
+}
+
+impl<C> fmt::Display for Fr<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<C> From<Fr<C>> for BigInt {
+    fn from(value: Fr<C>) -> Self {
+        value.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::BN254;
+
+    #[test]
+    fn test_reduction_policy() {
+        let p = BN254::field();
+
+        assert_eq!(Fr::<BN254>::new(p.clone()).value(), &BigInt::zero());
+        assert_eq!(Fr::<BN254>::new(p.clone() + 5).value(), &BigInt::from(5));
+        assert_eq!(Fr::<BN254>::new(-1).value(), &(p.clone() - 1));
+
+        assert!(Fr::<BN254>::from_canonical(-1).is_none());
+        assert!(Fr::<BN254>::from_canonical(p.clone()).is_none());
+        assert_eq!(
+            Fr::<BN254>::from_canonical(p.clone() - 1).unwrap(),
+            Fr::new(-1)
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Deterministic fingerprints of circuits.
+//!
+//! A [`Fingerprint`] is the SHA3-256 hash of a canonical encoding of a
+//! [`CircuitDefinition`] and the [`MetadataInfo`] of the backend it targets: the
+//! field, curve and proving system, the variable counts, and every operation with
+//! its inputs and outputs. Source spans and namespaces do not affect the
+//! constraints, so they are not part of the fingerprint, and neither is the
+//! schema version, so definitions migrated from an older schema keep their
+//! fingerprint. Curves, proving systems and operations are hashed by fixed names
+//! that do not change with the Rust enums.
+//!
+//! Keys, proofs and compiled circuits can be wrapped into a small envelope with
+//! [`seal`], and [`open`] refuses envelopes made for a different circuit:
+//!
+//! ```rust,ignore
+//! let fingerprint = circuit_prover.fingerprint();
+//! let bytes = fingerprint::seal(&fingerprint, &pk.serialize()?);
+//! let pk = ProvingKey::deserialize(fingerprint::open(&fingerprint, &bytes)?.to_vec())?;
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use sha3::{Digest, Sha3_256};
+
+use crate::{
+    CurveType, MetadataInfo, ProvingSystem,
+    types::{CircuitDefinition, VariableType},
+};
+
+/// Magic bytes at the start of a sealed envelope.
+const MAGIC: &[u8; 4] = b"rsfp";
+
+/// The fingerprint of a circuit, see the [module documentation](self).
+///
+/// Serialized as a lowercase hex string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Fingerprint(pub [u8; 32]);
+
+/// An error while checking a fingerprint.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FingerprintError {
+    #[error("invalid fingerprint `{0}`")]
+    InvalidFingerprint(String),
+
+    #[error("data is not a sealed envelope")]
+    InvalidEnvelope,
+
+    #[error("circuit fingerprint mismatch: expected {expected}, found {found}")]
+    Mismatch {
+        expected: Fingerprint,
+        found: Fingerprint,
+    },
+}
+
+impl Fingerprint {
+    /// Computes the fingerprint of `circuit` compiled for `metadata`.
+    pub fn new(circuit: &CircuitDefinition, metadata: &MetadataInfo) -> Self {
+        let mut hasher = Sha3_256::new();
+
+        let mut bytes = |data: &[u8]| {
+            hasher.update((data.len() as u64).to_be_bytes());
+            hasher.update(data);
+        };
+
+        bytes(b"rsnark-circuit");
+        bytes(&metadata.field.to_signed_bytes_be());
+        bytes(curve_tag(&metadata.curve).as_bytes());
+        bytes(proving_system_tag(&metadata.proving_system).as_bytes());
+        bytes(&circuit.public_len.to_be_bytes());
+        bytes(&circuit.private_len.to_be_bytes());
+        bytes(&circuit.local_len.to_be_bytes());
+        bytes(&(circuit.operations.len() as u64).to_be_bytes());
+
+        for operation in &circuit.operations {
+            bytes(operation.op.as_str().as_bytes());
+
+            for vars in [&operation.inputs, &operation.outputs] {
+                bytes(&(vars.len() as u64).to_be_bytes());
+
+                for var in vars {
+                    let (tag, data) = match var {
+                        VariableType::Public(i) => (0u8, i.to_be_bytes().to_vec()),
+                        VariableType::Private(i) => (1, i.to_be_bytes().to_vec()),
+                        VariableType::Local(i) => (2, i.to_be_bytes().to_vec()),
+                        VariableType::Constant(v) => (3, v.to_signed_bytes_be()),
+                    };
+                    bytes(&[tag]);
+                    bytes(&data);
+                }
+            }
+        }
+
+        Self(hasher.finalize().into())
+    }
+}
+
+/// Name of a curve in fingerprints, which must never change.
+fn curve_tag(curve: &CurveType) -> &'static str {
+    match curve {
+        CurveType::Mock => "mock",
+        CurveType::BN254 => "bn254",
+        CurveType::BLS12_381 => "bls12-381",
+        CurveType::BLS24_317 => "bls24-317",
+        CurveType::BLS12_377 => "bls12-377",
+        CurveType::BW6_761 => "bw6-761",
+        CurveType::BLS24_315 => "bls24-315",
+        CurveType::BW6_633 => "bw6-633",
+        CurveType::Goldilocks => "goldilocks",
+        CurveType::BabyBear => "babybear",
+    }
+}
+
+/// Name of a proving system in fingerprints, which must never change.
+fn proving_system_tag(proving_system: &ProvingSystem) -> &'static str {
+    match proving_system {
+        ProvingSystem::Mock => "mock",
+        ProvingSystem::Groth16 => "groth16",
+        ProvingSystem::Plonk => "plonk",
+        ProvingSystem::Plonky2 => "plonky2",
+        ProvingSystem::Plonky3 => "plonky3",
+        ProvingSystem::Nova => "nova",
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = FingerprintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || FingerprintError::InvalidFingerprint(s.to_string());
+
+        if s.len() != 64 || !s.is_ascii() {
+            return Err(error());
+        }
+
+        let mut res = [0u8; 32];
+        for (i, byte) in res.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).map_err(|_| error())?;
+        }
+
+        Ok(Self(res))
+    }
+}
+
+/// Prefixes `payload` with the fingerprint of the circuit it belongs to.
+pub fn seal(fingerprint: &Fingerprint, payload: &[u8]) -> Vec<u8> {
+    let mut res = Vec::with_capacity(MAGIC.len() + 32 + payload.len());
+
+    res.extend_from_slice(MAGIC);
+    res.extend_from_slice(&fingerprint.0);
+    res.extend_from_slice(payload);
+
+    res
+}
+
+/// Splits a sealed envelope into its fingerprint and payload.
+pub fn unseal(data: &[u8]) -> Result<(Fingerprint, &[u8]), FingerprintError> {
+    let rest = data
+        .strip_prefix(MAGIC)
+        .ok_or(FingerprintError::InvalidEnvelope)?;
+
+    let (fingerprint, payload) = rest
+        .split_first_chunk::<32>()
+        .ok_or(FingerprintError::InvalidEnvelope)?;
+
+    Ok((Fingerprint(*fingerprint), payload))
+}
+
+/// Returns the payload of a sealed envelope, if it was sealed for `expected`.
+pub fn open<'a>(expected: &Fingerprint, data: &'a [u8]) -> Result<&'a [u8], FingerprintError> {
+    let (found, payload) = unseal(data)?;
+
+    if &found != expected {
+        return Err(FingerprintError::Mismatch {
+            expected: *expected,
+            found,
+        });
+    }
+
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{API, CircuitBuilder, CurveId, curve::BN254};
+
+    fn definition(constant: u64) -> (CircuitDefinition, MetadataInfo) {
+        let metadata = MetadataInfo {
+            field: BN254::field(),
+            curve: BN254::curve_type(),
+            proving_system: ProvingSystem::Groth16,
+        };
+
+        let mut api = CircuitBuilder::new(metadata.clone());
+        let a = api.variable_initer_mut().new_private();
+        api.scope("check", |api| api.assert_is_equal(&a, &constant));
+
+        (api.build(), metadata)
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let (circuit, metadata) = definition(1);
+        let fingerprint = Fingerprint::new(&circuit, &metadata);
+
+        let mut moved = circuit.clone();
+        moved.operations[0].span = None;
+        moved.operations[0].namespace.clear();
+        assert_eq!(Fingerprint::new(&moved, &metadata), fingerprint);
+
+        let mut migrated = circuit.clone();
+        migrated.schema_version = 0;
+        assert_eq!(Fingerprint::new(&migrated, &metadata), fingerprint);
+
+        let (other, _) = definition(2);
+        let other = Fingerprint::new(&other, &metadata);
+        assert_ne!(other, fingerprint);
+
+        assert_eq!(
+            fingerprint.to_string().parse::<Fingerprint>(),
+            Ok(fingerprint)
+        );
+
+        let sealed = seal(&fingerprint, b"key");
+        assert_eq!(open(&fingerprint, &sealed), Ok(&b"key"[..]));
+        assert_eq!(
+            open(&other, &sealed),
+            Err(FingerprintError::Mismatch {
+                expected: other,
+                found: fingerprint
+            })
+        );
+        assert_eq!(open(&other, b"key"), Err(FingerprintError::InvalidEnvelope));
+    }
+
+    // Changing this value invalidates every sealed artifact
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let (circuit, metadata) = definition(1);
+
+        assert_eq!(
+            Fingerprint::new(&circuit, &metadata).to_string(),
+            "90fcfd80b17ca7954dbd414a3e5487ff816ab51858ceb826ead45f47e6939dad"
+        );
+    }
+}
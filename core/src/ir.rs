@@ -0,0 +1,402 @@
+//! Line-oriented textual form of [`CircuitDefinition`].
+//!
+//! The textual IR is meant for code review, snapshot tests and hand-written
+//! circuits. [`CircuitDefinition`] implements [`Display`](fmt::Display) and
+//! [`FromStr`], and both directions round-trip:
+//!
+//! ```text
+//! public 1
+//! private 2
+//! local 3
+//!
+//! l0 = add s0, s1
+//! l1 = mul l0, 3 [adder]
+//! l2 = is_zero l1 [adder/check] @ src/main.rs:14:21
+//! assert_is_equal l2, p0
+//! ```
+//!
+//! - `p<n>`, `s<n>` and `l<n>` are public, private (secret) and local variables,
+//!   any other operand is a decimal constant.
+//! - Outputs are listed before `=`, operations without outputs omit it.
+//! - The namespace follows in brackets, the source span after `@`. Spans are
+//!   only printed by the alternate form `{:#}`, so that the default output stays
+//!   stable when circuit code moves.
+//! - Namespace segments are joined with `/`, and a backslash escapes `\\`, `/`,
+//!   `#`, `@`, `[` and `]` inside a segment.
+//! - The `public`, `private` and `local` headers may be omitted, in which case the
+//!   counts are inferred from the highest index in use.
+//! - Everything after `#` is a comment.
+
+use std::{fmt, str::FromStr};
+
+use num::BigInt;
+
+use crate::{
+    schema::SCHEMA_VERSION,
+    types::{CircuitDefinition, OpCode, Operation, Span, VariableType},
+};
+
+/// An error while parsing the textual IR.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("line {line}: {message}")]
+pub struct ParseError {
+    /// The 1-based line of the error.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for VariableType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariableType::Public(i) => write!(f, "p{i}"),
+            VariableType::Private(i) => write!(f, "s{i}"),
+            VariableType::Local(i) => write!(f, "l{i}"),
+            VariableType::Constant(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl FromStr for VariableType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let index = |rest: &str| {
+            rest.parse::<u64>()
+                .map_err(|_| format!("invalid variable `{s}`"))
+        };
+
+        match s.split_at_checked(1) {
+            Some(("p", rest)) => index(rest).map(VariableType::Public),
+            Some(("s", rest)) => index(rest).map(VariableType::Private),
+            Some(("l", rest)) => index(rest).map(VariableType::Local),
+            _ => s
+                .parse::<BigInt>()
+                .map(VariableType::Constant)
+                .map_err(|_| format!("invalid operand `{s}`")),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.outputs.is_empty() {
+            write_list(f, &self.outputs)?;
+            write!(f, " = ")?;
+        }
+
+        write!(f, "{}", self.op.as_str())?;
+        if !self.inputs.is_empty() {
+            write!(f, " ")?;
+            write_list(f, &self.inputs)?;
+        }
+
+        if !self.namespace.is_empty() {
+            write!(f, " [")?;
+            for (i, segment) in self.namespace.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "/")?;
+                }
+                for c in segment.chars() {
+                    if ESCAPED.contains(&c) {
+                        write!(f, "\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+            }
+            write!(f, "]")?;
+        }
+
+        if f.alternate()
+            && let Some(span) = &self.span
+        {
+            write!(f, " @ {span}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for CircuitDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "public {}", self.public_len)?;
+        writeln!(f, "private {}", self.private_len)?;
+        writeln!(f, "local {}", self.local_len)?;
+
+        if !self.operations.is_empty() {
+            writeln!(f)?;
+        }
+
+        for operation in &self.operations {
+            if f.alternate() {
+                writeln!(f, "{operation:#}")?;
+            } else {
+                writeln!(f, "{operation}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for CircuitDefinition {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut public_len = None;
+        let mut private_len = None;
+        let mut local_len = None;
+        let mut operations = Vec::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            let error = |message: String| ParseError {
+                line: idx + 1,
+                message,
+            };
+
+            let line = match unescaped(line).find(|&(_, c)| c == '#') {
+                Some((i, _)) => &line[..i],
+                None => line,
+            }
+            .trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let header = match line.split_once(' ') {
+                Some(("public", n)) => Some((&mut public_len, n)),
+                Some(("private", n)) => Some((&mut private_len, n)),
+                Some(("local", n)) => Some((&mut local_len, n)),
+                _ => None,
+            };
+
+            if let Some((len, n)) = header {
+                let n = n
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|_| error(format!("invalid count `{n}`")))?;
+                *len = Some(n);
+            } else {
+                operations.push(parse_operation(line).map_err(error)?);
+            }
+        }
+
+        // Counts inferred from the highest index in use, for omitted headers.
+        let (mut public, mut private, mut local) = (0, 0, 0);
+        for var in operations
+            .iter()
+            .flat_map(|op| op.inputs.iter().chain(&op.outputs))
+        {
+            match var {
+                VariableType::Public(i) => public = public.max(i + 1),
+                VariableType::Private(i) => private = private.max(i + 1),
+                VariableType::Local(i) => local = local.max(i + 1),
+                VariableType::Constant(_) => {}
+            }
+        }
+
+        Ok(CircuitDefinition {
+            schema_version: SCHEMA_VERSION,
+            public_len: public_len.unwrap_or(public),
+            private_len: private_len.unwrap_or(private),
+            local_len: local_len.unwrap_or(local),
+            operations,
+        })
+    }
+}
+
+/// Characters escaped with a backslash in namespace segments.
+const ESCAPED: [char; 6] = ['\\', '/', '#', '@', '[', ']'];
+
+/// The characters of `s` that are not escaped by a backslash, with their byte
+/// offsets. Backslashes and the characters they escape are skipped.
+fn unescaped(s: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut escaped = false;
+    s.char_indices().filter(move |&(_, c)| {
+        if escaped {
+            escaped = false;
+            false
+        } else {
+            escaped = c == '\\';
+            !escaped
+        }
+    })
+}
+
+fn parse_namespace(s: &str) -> Vec<String> {
+    let mut namespace = vec![String::new()];
+    let mut escaped = false;
+    for c in s.chars() {
+        match c {
+            _ if escaped => {
+                escaped = false;
+                namespace.last_mut().unwrap().push(c);
+            }
+            '\\' => escaped = true,
+            '/' => namespace.push(String::new()),
+            _ => namespace.last_mut().unwrap().push(c),
+        }
+    }
+
+    namespace
+}
+
+fn write_list(f: &mut fmt::Formatter<'_>, vars: &[VariableType]) -> fmt::Result {
+    for (i, var) in vars.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{var}")?;
+    }
+
+    Ok(())
+}
+
+fn parse_list(s: &str) -> Result<Vec<VariableType>, String> {
+    s.split(',').map(|var| var.trim().parse()).collect()
+}
+
+fn parse_operation(line: &str) -> Result<Operation, String> {
+    let (line, span) = match line.rsplit_once(" @ ") {
+        Some((line, span)) => (line.trim(), Some(parse_span(span.trim())?)),
+        None => (line, None),
+    };
+
+    let close = unescaped(line).last().filter(|&(_, c)| c == ']');
+    let open = unescaped(line).filter(|&(_, c)| c == '[').last();
+    let (line, namespace) = match (open, close) {
+        (Some((open, _)), Some((close, _))) => {
+            (line[..open].trim(), parse_namespace(&line[open + 1..close]))
+        }
+        _ => (line, Vec::new()),
+    };
+
+    let (outputs, rest) = match line.split_once('=') {
+        Some((outputs, rest)) => (parse_list(outputs)?, rest.trim()),
+        None => (Vec::new(), line),
+    };
+
+    let (name, inputs) = rest.split_once(' ').unwrap_or((rest, ""));
+    let op = name
+        .parse::<OpCode>()
+        .map_err(|_| format!("unknown operation `{name}`"))?;
+    let inputs = if inputs.trim().is_empty() {
+        Vec::new()
+    } else {
+        parse_list(inputs)?
+    };
+
+    Ok(Operation {
+        op,
+        inputs,
+        outputs,
+        span,
+        namespace,
+    })
+}
+
+fn parse_span(s: &str) -> Result<Span, String> {
+    let error = || format!("invalid span `{s}`");
+
+    let mut parts = s.rsplitn(3, ':');
+    let column = parts
+        .next()
+        .and_then(|c| c.parse().ok())
+        .ok_or_else(error)?;
+    let line = parts
+        .next()
+        .and_then(|l| l.parse().ok())
+        .ok_or_else(error)?;
+    let file = parts.next().ok_or_else(error)?.to_string();
+
+    Ok(Span { file, line, column })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{API, ProvingSystem, curve::BN254, test_builder};
+
+    #[test]
+    fn test_round_trip() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let a = api.variable_initer_mut().new_private();
+        let b = api.variable_initer_mut().new_public(false);
+
+        api.scope("outer", |api| {
+            let s = api.sub(&a, &-3);
+            let bits = api.variable_to_binary(&s, 4);
+            api.assert_is_equal(&bits[0], &b);
+        });
+        let definition = api.build();
+
+        for text in [definition.to_string(), format!("{definition:#}")] {
+            let parsed: CircuitDefinition = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), definition.to_string());
+        }
+
+        let text = format!("{definition:#}");
+        let parsed: CircuitDefinition = text.parse().unwrap();
+        assert_eq!(parsed.operations[0].span, definition.operations[0].span);
+        assert_eq!(format!("{parsed:#}"), text);
+    }
+
+    #[test]
+    fn test_round_trip_escaped_namespace() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let a = api.variable_initer_mut().new_private();
+
+        api.scope("a/b # [c] @ d\\", |api| {
+            api.scope("", |api| api.assert_is_boolean(&a));
+        });
+        let definition = api.build();
+
+        let text = definition.to_string();
+        assert!(text.contains(r"[a\/b \# \[c\] \@ d\\/]"), "{text}");
+
+        let parsed: CircuitDefinition = text.parse().unwrap();
+        assert_eq!(
+            parsed.operations[0].namespace,
+            definition.operations[0].namespace
+        );
+        assert_eq!(parsed.to_string(), text);
+    }
+
+    #[test]
+    fn test_parse() {
+        let definition: CircuitDefinition = "
+            # hand-written
+            l0 = mul p0, l2
+            l1, l2 = to_binary s1, 2 [bits]
+            assert_is_equal l0, 17
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            (
+                definition.public_len,
+                definition.private_len,
+                definition.local_len
+            ),
+            (1, 2, 3)
+        );
+        assert_eq!(definition.operations[1].outputs.len(), 2);
+        assert_eq!(definition.operations[1].namespace, vec!["bits"]);
+
+        let err = "public 1\nl0 = frobnicate p0"
+            .parse::<CircuitDefinition>()
+            .unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_op_names_match_serde() {
+        for op in OpCode::ALL {
+            assert_eq!(
+                serde_json::to_value(op).unwrap(),
+                serde_json::Value::from(op.as_str())
+            );
+            assert_eq!(op.as_str().parse::<OpCode>(), Ok(op));
+        }
+    }
+}
@@ -15,12 +15,45 @@ pub use builder::*;
 mod circuit;
 pub use circuit::*;
 
+mod step;
+pub use step::*;
+
 mod initer;
 pub use initer::*;
 
 mod metadata;
 pub use metadata::*;
 
+pub mod field;
+#[doc(inline)]
+pub use field::Fr;
+
+mod arith;
+mod simplify;
+
+pub mod passes;
+
+pub mod analysis;
+
+pub mod stats;
+
+pub mod dot;
+
+pub mod ir;
+
+pub mod schema;
+
+pub mod fingerprint;
+
+pub mod r1cs;
+
+pub mod plonkish;
+
+pub mod typed;
+
+pub mod recursion;
+
 pub use num::BigInt;
+pub use ruint::aliases::U256;
 
 pub use rsnark_macros::circuit;
@@ -57,6 +57,8 @@ macro_rules! define_curve {
 /// - [`curve::BLS24_315`]
 /// - [`curve::BW6_761`]
 /// - [`curve::BW6_633`]
+/// - [`curve::Goldilocks`]
+/// - [`curve::BabyBear`]
 ///
 pub mod curve {
     use super::*;
@@ -97,6 +99,9 @@ pub mod curve {
         7,
         "39705142709513438335025689890408969744933502416914749335064285505637884093126342347073617133569"
     );
+
+    define_curve!(Goldilocks, 8, "18446744069414584321");
+    define_curve!(BabyBear, 9, "2013265921");
 }
 
 /// Enumeration of supported elliptic curve types.
@@ -116,6 +121,10 @@ pub mod curve {
 /// - [`BW6_761`](CurveType::BW6_761): BW6 curve with 761-bit prime
 /// - [`BW6_633`](CurveType::BW6_633): BW6 curve with 633-bit prime
 ///
+/// ## Small Fields
+/// - [`Goldilocks`](CurveType::Goldilocks): The 64-bit field `2^64 - 2^32 + 1`, without a curve
+/// - [`BabyBear`](CurveType::BabyBear): The 31-bit field `2^31 - 2^27 + 1`, without a curve
+///
 /// ## Testing Curves
 /// - [`Mock`](CurveType::Mock): Mock curve for testing purposes
 #[derive(Debug, Clone)]
@@ -128,6 +137,8 @@ pub enum CurveType {
     BW6_761,
     BLS24_315,
     BW6_633,
+    Goldilocks,
+    BabyBear,
 }
 
 /// Enumeration of supported zero-knowledge proving systems.
@@ -141,6 +152,9 @@ pub enum CurveType {
 /// ## Production Systems
 /// - [`Groth16`](ProvingSystem::Groth16): Efficient zk-SNARK with constant-size proofs and fast verification
 /// - [`Plonk`](ProvingSystem::Plonk): Universal zk-SNARK with universal trusted setup
+/// - [`Plonky2`](ProvingSystem::Plonky2): FRI-based PLONK without trusted setup, with fast recursion
+/// - [`Plonky3`](ProvingSystem::Plonky3): STARKs over small fields, experimental
+/// - [`Nova`](ProvingSystem::Nova): Folding scheme for incremental computations, see [`StepCircuit`](crate::StepCircuit)
 ///
 /// ## Testing Systems
 /// - [`Mock`](ProvingSystem::Mock): Mock proving system for testing without cryptographic operations
@@ -150,6 +164,9 @@ pub enum ProvingSystem {
     Mock,
     Groth16,
     Plonk,
+    Plonky2,
+    Plonky3,
+    Nova,
 }
 
 #[doc(hidden)]
@@ -171,6 +188,8 @@ pub struct MetadataInfo {
 /// - [`field`](Metadata::field): Returns the prime field modulus
 /// - [`curve`](Metadata::curve): Returns the elliptic curve type
 /// - [`proving_system`](Metadata::proving_system): Returns the proving system type
+/// - [`field_bits`](Metadata::field_bits): Returns the bit length of the field modulus
+/// - [`is_small_field`](Metadata::is_small_field): Returns whether the field fits in 64 bits
 ///
 /// # Usage
 ///
@@ -193,8 +212,24 @@ pub trait Metadata {
 
     /// Returns a reference to the proving system type used by this configuration.
     fn proving_system(&self) -> &ProvingSystem;
+
+    /// Returns the number of bits of the prime field modulus.
+    fn field_bits(&self) -> u64 {
+        self.field().bits()
+    }
+
+    /// Returns `true` for fields of at most 64 bits, such as Goldilocks and BabyBear.
+    ///
+    /// Gadgets that pack values into a single field element, such as
+    /// 32-bit integer arithmetic, have to split them on small fields.
+    fn is_small_field(&self) -> bool {
+        self.field_bits() <= SMALL_FIELD_BITS
+    }
 }
 
+/// The largest bit length of a field considered small by [`Metadata::is_small_field`].
+pub const SMALL_FIELD_BITS: u64 = 64;
+
 impl Metadata for MetadataInfo {
     fn field(&self) -> &BigInt {
         &self.field
@@ -0,0 +1,17 @@
+use crate::{passes::Pass, types::CircuitDefinition};
+
+/// Renumbers local variables densely and updates `local_len`.
+///
+/// See [`CircuitDefinition::compact_locals`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompactLocals;
+
+impl Pass for CompactLocals {
+    fn name(&self) -> &'static str {
+        "compact-locals"
+    }
+
+    fn run(&self, circuit: &mut CircuitDefinition) {
+        circuit.compact_locals();
+    }
+}
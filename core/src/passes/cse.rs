@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use crate::{
+    passes::Pass,
+    types::{CircuitDefinition, OpCode, VariableType},
+};
+
+/// Deduplicates identical operations on identical inputs.
+///
+/// Inputs of commutative operations are sorted before comparison, so `a + b`
+/// and `b + a` are considered identical. Every use of a removed operation's
+/// outputs is rewritten to the outputs of the first occurrence. `Println` is
+/// never deduplicated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommonSubexpressionElimination;
+
+fn is_commutative(op: OpCode) -> bool {
+    matches!(
+        op,
+        OpCode::Add
+            | OpCode::Mul
+            | OpCode::Xor
+            | OpCode::Or
+            | OpCode::And
+            | OpCode::AssertIsEqual
+            | OpCode::AssertIsDifferent
+    )
+}
+
+impl Pass for CommonSubexpressionElimination {
+    fn name(&self) -> &'static str {
+        "common-subexpression-elimination"
+    }
+
+    fn run(&self, circuit: &mut CircuitDefinition) {
+        let mut seen: HashMap<(OpCode, Vec<VariableType>), Vec<VariableType>> = HashMap::new();
+        let mut substitutions: HashMap<u64, VariableType> = HashMap::new();
+
+        let operations = std::mem::take(&mut circuit.operations);
+
+        for mut operation in operations {
+            for input in &mut operation.inputs {
+                if let VariableType::Local(i) = input
+                    && let Some(var) = substitutions.get(i)
+                {
+                    *input = var.clone();
+                }
+            }
+
+            if operation.op == OpCode::Println {
+                circuit.operations.push(operation);
+                continue;
+            }
+
+            let mut key = operation.inputs.clone();
+            if is_commutative(operation.op) {
+                key.sort();
+            }
+
+            match seen.get(&(operation.op, key.clone())) {
+                Some(outputs) if outputs.len() == operation.outputs.len() => {
+                    for (output, existing) in operation.outputs.iter().zip(outputs) {
+                        if let VariableType::Local(i) = output {
+                            substitutions.insert(*i, existing.clone());
+                        }
+                    }
+                }
+                _ => {
+                    seen.insert((operation.op, key), operation.outputs.clone());
+                    circuit.operations.push(operation);
+                }
+            }
+        }
+    }
+}
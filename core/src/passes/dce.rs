@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use crate::{
+    passes::Pass,
+    types::{CircuitDefinition, OpCode, VariableType},
+};
+
+/// Removes operations whose outputs are never used.
+///
+/// An operation is live if it has an effect on its own, or if one of its outputs
+/// is read by a live operation. Operations with an effect are assertions,
+/// `Println`, and operations that implicitly constrain their inputs, see
+/// [`has_effect`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeadCodeElimination;
+
+/// Returns `true` if `op` must be kept even when its outputs are unused.
+///
+/// Besides assertions, this includes every operation that can make a circuit
+/// unsatisfiable: division and inversion (non-zero divisor), bit decomposition
+/// (range check), and bitwise or selection operations (boolean inputs).
+pub fn has_effect(op: OpCode) -> bool {
+    match op {
+        OpCode::Add
+        | OpCode::MulAcc
+        | OpCode::Neg
+        | OpCode::Sub
+        | OpCode::Mul
+        | OpCode::IsZero
+        | OpCode::Cmp => false,
+        OpCode::DivUnchecked
+        | OpCode::Div
+        | OpCode::Inverse
+        | OpCode::ToBinary
+        | OpCode::FromBinary
+        | OpCode::Xor
+        | OpCode::Or
+        | OpCode::And
+        | OpCode::Select
+        | OpCode::Lookup2
+        | OpCode::AssertIsEqual
+        | OpCode::AssertIsDifferent
+        | OpCode::AssertIsBoolean
+        | OpCode::AssertIsCrumb
+        | OpCode::AssertIsLessOrEqual
+        | OpCode::AssertGroth16Proof
+        | OpCode::AssertPlonkProof
+        | OpCode::Println => true,
+    }
+}
+
+impl Pass for DeadCodeElimination {
+    fn name(&self) -> &'static str {
+        "dead-code-elimination"
+    }
+
+    fn run(&self, circuit: &mut CircuitDefinition) {
+        let mut used = HashSet::new();
+        let mut live = vec![false; circuit.operations.len()];
+
+        for (idx, operation) in circuit.operations.iter().enumerate().rev() {
+            let is_live = has_effect(operation.op)
+                || operation.outputs.iter().any(|output| match output {
+                    VariableType::Local(i) => used.contains(i),
+                    _ => true,
+                });
+
+            if is_live {
+                live[idx] = true;
+
+                for input in &operation.inputs {
+                    if let VariableType::Local(i) = input {
+                        used.insert(*i);
+                    }
+                }
+            }
+        }
+
+        let mut live = live.into_iter();
+        circuit.operations.retain(|_| live.next().unwrap_or(true));
+    }
+}
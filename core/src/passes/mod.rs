@@ -0,0 +1,116 @@
+//! Optimization passes over [`CircuitDefinition`].
+//!
+//! A pass rewrites a circuit definition in place without changing the set of
+//! satisfying witnesses. Passes can be run individually, or as a pipeline
+//! selected by an [`OptimizationLevel`].
+
+use crate::types::CircuitDefinition;
+
+mod dce;
+pub use dce::*;
+
+mod cse;
+pub use cse::*;
+
+mod compact;
+pub use compact::*;
+
+/// A transformation over a [`CircuitDefinition`].
+pub trait Pass {
+    /// Returns the name of this pass.
+    fn name(&self) -> &'static str;
+
+    /// Runs this pass over `circuit`.
+    fn run(&self, circuit: &mut CircuitDefinition);
+}
+
+/// How much optimization is applied to a circuit before it is compiled.
+///
+/// The default is [`OptimizationLevel::None`], which passes the circuit to the
+/// backend exactly as it was defined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OptimizationLevel {
+    /// No optimization.
+    #[default]
+    None,
+    /// Constant folding and algebraic simplification while the circuit is built.
+    Basic,
+    /// [`Basic`](OptimizationLevel::Basic), followed by common-subexpression
+    /// elimination, dead-code elimination and local renumbering.
+    Full,
+}
+
+impl OptimizationLevel {
+    /// Returns `true` if the circuit builder should simplify operations.
+    pub fn simplify(&self) -> bool {
+        *self >= OptimizationLevel::Basic
+    }
+
+    /// Returns the passes to run over a built circuit definition, in order.
+    pub fn passes(&self) -> Vec<Box<dyn Pass>> {
+        match self {
+            OptimizationLevel::None | OptimizationLevel::Basic => Vec::new(),
+            OptimizationLevel::Full => vec![
+                Box::new(CommonSubexpressionElimination),
+                Box::new(DeadCodeElimination),
+                Box::new(CompactLocals),
+            ],
+        }
+    }
+
+    /// Runs the passes of this level over `circuit`.
+    pub fn run(&self, circuit: &mut CircuitDefinition) {
+        for pass in self.passes() {
+            pass.run(circuit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        API, ProvingSystem,
+        curve::BN254,
+        test_builder,
+        types::{OpCode, VariableType},
+    };
+
+    #[test]
+    fn test_full_pipeline() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let x = api.variable_initer_mut().new_private();
+        let y = api.variable_initer_mut().new_public(false);
+
+        let a = api.add(&x, &y);
+        let b = api.add(&y, &x);
+        let _square = api.mul(&a, &a);
+        let _range = api.variable_to_binary(&x, 8);
+        let c = api.mul(&a, &b);
+        api.assert_is_equal(&c, &y);
+        api.assert_is_equal(&y, &c);
+
+        let mut circuit = api.build();
+        assert_eq!(circuit.operations.len(), 7);
+
+        OptimizationLevel::Full.run(&mut circuit);
+
+        // `b` is deduplicated into `a`, so `a * b` reuses `_square`.
+
+        let ops: Vec<_> = circuit.operations.iter().map(|o| o.op).collect();
+        assert_eq!(
+            ops,
+            vec![
+                OpCode::Add,
+                OpCode::Mul,
+                OpCode::ToBinary,
+                OpCode::AssertIsEqual
+            ]
+        );
+        assert_eq!(circuit.local_len, 10);
+        assert_eq!(
+            circuit.operations[1].inputs,
+            vec![VariableType::Local(0), VariableType::Local(0)]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use num::{BigInt, Zero};
+
+use crate::{
+    arith,
+    plonkish::{Gate, Plonkish},
+    r1cs::{CompileError, Constraint, LinearCombination, ONE, R1cs},
+    types::CircuitDefinition,
+};
+
+/// A linear combination split into its variable terms and its constant.
+type Terms = (Vec<(usize, BigInt)>, BigInt);
+
+impl Plonkish {
+    /// Compiles `circuit` into a gate list over the field of modulus `field`.
+    pub fn compile(circuit: &CircuitDefinition, field: &BigInt) -> Result<Self, CompileError> {
+        Ok(Self::from_r1cs(R1cs::compile(circuit, field)?))
+    }
+
+    /// Converts a rank-1 constraint system into a gate list.
+    pub fn from_r1cs(r1cs: R1cs) -> Self {
+        let mut lowering = Lowering {
+            p: r1cs.field.clone(),
+            gates: Vec::new(),
+            sums: Vec::new(),
+            next: r1cs.num_wires(),
+            operation: None,
+        };
+
+        for input in 1..=r1cs.public_len {
+            let q_l = arith::neg(&BigInt::from(1), &lowering.p);
+            lowering.gate(
+                [Some(q_l), None, None, None, None],
+                [Some(input), None, None],
+            );
+        }
+
+        for constraint in &r1cs.constraints {
+            lowering.operation = Some(constraint.operation);
+            lowering.lower(constraint);
+        }
+
+        Plonkish {
+            gates: lowering.gates,
+            r1cs,
+            sums: lowering.sums,
+        }
+    }
+}
+
+struct Lowering {
+    p: BigInt,
+    gates: Vec<Gate>,
+    sums: Vec<Vec<(usize, BigInt)>>,
+    /// Index of the next variable introduced by an addition gate.
+    next: usize,
+    /// Index of the operation being lowered.
+    operation: Option<usize>,
+}
+
+impl Lowering {
+    fn lower(&mut self, constraint: &Constraint) {
+        let p = self.p.clone();
+
+        // a * k = c and k * b = c are linear
+        if let Some(k) = constraint.b.as_constant() {
+            return self.linear(&constraint.a.scale(&k, &p).sub(&constraint.c, &p));
+        }
+        if let Some(k) = constraint.a.as_constant() {
+            return self.linear(&constraint.b.scale(&k, &p).sub(&constraint.c, &p));
+        }
+
+        let (a, ka) = self.single(&constraint.a);
+        let (b, kb) = self.single(&constraint.b);
+        let (c, kc) = self.single(&constraint.c);
+
+        // (ca * a + ka) * (cb * b + kb) = cc * c + kc
+        let (a, ca) = a.expect("non-constant linear combination");
+        let (b, cb) = b.expect("non-constant linear combination");
+        let (c, cc) = c.map_or((None, BigInt::zero()), |(c, cc)| (Some(c), cc));
+
+        self.gate(
+            [
+                Some(arith::mul(&ca, &kb, &p)),
+                Some(arith::mul(&ka, &cb, &p)),
+                Some(arith::neg(&cc, &p)),
+                Some(arith::mul(&ca, &cb, &p)),
+                Some(arith::sub(&arith::mul(&ka, &kb, &p), &kc, &p)),
+            ],
+            [Some(a), Some(b), c],
+        );
+    }
+
+    /// Constrains `lc` to zero, three variables per gate.
+    fn linear(&mut self, lc: &LinearCombination) {
+        let (mut terms, constant) = split(lc);
+        if terms.is_empty() && constant.is_zero() {
+            return;
+        }
+
+        while terms.len() > 3 {
+            let rest = terms.split_off(2);
+            let sum = self.sum(&terms);
+            terms = [vec![(sum, BigInt::from(1))], rest].concat();
+        }
+
+        let mut selectors = [None, None, None, None, Some(constant)];
+        let mut wires = [None; 3];
+        for (idx, (var, coeff)) in terms.into_iter().enumerate() {
+            selectors[idx] = Some(coeff);
+            wires[idx] = Some(var);
+        }
+        self.gate(selectors, wires);
+    }
+
+    /// Reduces `lc` to at most one variable term and a constant.
+    fn single(&mut self, lc: &LinearCombination) -> (Option<(usize, BigInt)>, BigInt) {
+        let (mut terms, constant) = split(lc);
+
+        while terms.len() > 1 {
+            let rest = terms.split_off(2);
+            let sum = self.sum(&terms);
+            terms = [vec![(sum, BigInt::from(1))], rest].concat();
+        }
+
+        (terms.pop(), constant)
+    }
+
+    /// Introduces a variable equal to the weighted sum of two variables.
+    fn sum(&mut self, terms: &[(usize, BigInt)]) -> usize {
+        let [(l, cl), (r, cr)] = terms else {
+            unreachable!("sums are built two terms at a time")
+        };
+
+        let var = self.next;
+        self.next += 1;
+        self.sums.push(terms.to_vec());
+        self.gate(
+            [
+                Some(cl.clone()),
+                Some(cr.clone()),
+                Some(arith::neg(&BigInt::from(1), &self.p)),
+                None,
+                None,
+            ],
+            [Some(*l), Some(*r), Some(var)],
+        );
+
+        var
+    }
+
+    /// Appends a gate with selectors `[qL, qR, qO, qM, qC]`, where `None` means zero.
+    fn gate(&mut self, selectors: [Option<BigInt>; 5], wires: [Option<usize>; 3]) {
+        let [q_l, q_r, q_o, q_m, q_c] = selectors.map(Option::unwrap_or_default);
+
+        self.gates.push(Gate {
+            q_l,
+            q_r,
+            q_o,
+            q_m,
+            q_c,
+            wires,
+            operation: self.operation,
+        });
+    }
+}
+
+fn split(lc: &LinearCombination) -> Terms {
+    let constant = lc.0.get(&ONE).cloned().unwrap_or_default();
+    let terms =
+        lc.0.iter()
+            .filter(|(var, _)| **var != ONE)
+            .map(|(var, coeff)| (*var, coeff.clone()))
+            .collect();
+
+    (terms, constant)
+}
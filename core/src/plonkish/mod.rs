@@ -0,0 +1,183 @@
+//! Native PLONK-style arithmetization of circuits.
+//!
+//! [`Plonkish::compile`] lowers a [`CircuitDefinition`](crate::types::CircuitDefinition)
+//! into a list of gates over three wire columns `a`, `b` and `c`, each satisfying
+//!
+//! ```text
+//! qL * a + qR * b + qO * c + qM * a * b + qC + PI = 0
+//! ```
+//!
+//! where `PI` is the public input of the gate. Wires refer to variables, and
+//! wires sharing a variable are tied together by the copy-constraint
+//! [`permutation`](Plonkish::permutation).
+//!
+//! The circuit is first lowered to [`R1cs`], which defines the semantics of every
+//! operation and computes the witness. Each rank-1 constraint becomes a single
+//! gate when its sides have at most one variable each; wider linear combinations
+//! are first reduced with addition gates. The first `public_len` gates bind the
+//! public inputs, with `qL = -1`.
+//!
+//! ```rust,ignore
+//! let plonkish = Plonkish::compile(&definition, &BN254::field())?;
+//! println!("{}", plonkish.stats().constraints);
+//! plonkish.check(&plonkish.solve(&witness)?)?;
+//! ```
+
+mod lower;
+
+mod solver;
+
+use num::BigInt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    r1cs::R1cs,
+    stats::{ConstraintStats, ConstraintSystemKind},
+};
+
+/// The wire columns of a gate.
+pub const COLUMNS: usize = 3;
+
+/// A single gate, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gate {
+    pub q_l: BigInt,
+    pub q_r: BigInt,
+    pub q_o: BigInt,
+    pub q_m: BigInt,
+    pub q_c: BigInt,
+    /// Variables of the `a`, `b` and `c` wires. Unused wires are `None` and take the value zero.
+    pub wires: [Option<usize>; COLUMNS],
+    /// Index of the operation this gate comes from, `None` for public input gates.
+    pub operation: Option<usize>,
+}
+
+/// A PLONK-style gate list, see the [module documentation](self).
+///
+/// Variables `0..num_wires` of the underlying [`R1cs`] keep their indices, and
+/// variable `0` is never used by a gate. Variables introduced by addition gates
+/// follow them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Plonkish {
+    pub gates: Vec<Gate>,
+    pub(crate) r1cs: R1cs,
+    /// Variables introduced by addition gates, as weighted sums of earlier variables.
+    pub(crate) sums: Vec<Vec<(usize, BigInt)>>,
+}
+
+impl Plonkish {
+    /// Returns the modulus of the field the gates are defined over.
+    pub fn field(&self) -> &BigInt {
+        &self.r1cs.field
+    }
+
+    pub fn public_len(&self) -> usize {
+        self.r1cs.public_len
+    }
+
+    pub fn private_len(&self) -> usize {
+        self.r1cs.private_len
+    }
+
+    /// Returns the total number of variables, including the unused variable `0`.
+    pub fn num_variables(&self) -> usize {
+        self.r1cs.num_wires() + self.sums.len()
+    }
+
+    /// Returns the copy-constraint permutation over all wire positions.
+    ///
+    /// The wire of column `j` in gate `i` has position `i * COLUMNS + j`. The
+    /// permutation maps every position to the next position holding the same
+    /// variable, so that its cycles are exactly the sets of equal wires.
+    pub fn permutation(&self) -> Vec<usize> {
+        let mut sigma: Vec<usize> = (0..self.gates.len() * COLUMNS).collect();
+        let mut last: Vec<Option<usize>> = vec![None; self.num_variables()];
+        let mut first: Vec<Option<usize>> = vec![None; self.num_variables()];
+
+        for (i, gate) in self.gates.iter().enumerate() {
+            for (j, wire) in gate.wires.iter().enumerate() {
+                let Some(var) = wire else { continue };
+                let pos = i * COLUMNS + j;
+
+                match last[*var] {
+                    Some(prev) => sigma[prev] = pos,
+                    None => first[*var] = Some(pos),
+                }
+                last[*var] = Some(pos);
+            }
+        }
+
+        for (first, last) in first.into_iter().zip(last) {
+            if let (Some(first), Some(last)) = (first, last) {
+                sigma[last] = first;
+            }
+        }
+
+        sigma
+    }
+
+    /// Returns the gate count report of this gate list.
+    pub fn stats(&self) -> ConstraintStats {
+        ConstraintStats {
+            system: ConstraintSystemKind::Scs,
+            constraints: self.gates.len() as u64,
+            internal_variables: (self.r1cs.num_internal() + self.sums.len()) as u64,
+            public_variables: self.public_len() as u64,
+            secret_variables: self.private_len() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Zero;
+
+    use super::*;
+    use crate::{
+        API, CurveId, ProvingSystem, curve::BN254, r1cs::SolveError, test_builder, types::Witness,
+    };
+
+    #[test]
+    fn test_compile_and_solve() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Plonk);
+        let x = api.variable_initer_mut().new_private();
+        let y = api.variable_initer_mut().new_private();
+        let z = api.variable_initer_mut().new_private();
+        let out = api.variable_initer_mut().new_public(false);
+
+        // out = (x + y + z) * x + 1, with x boolean
+        let sum = api.add(&x, &y);
+        let sum = api.add(&sum, &z);
+        let product = api.mul(&sum, &x);
+        let res = api.add(&product, &1);
+        api.assert_is_equal(&res, &out);
+        api.assert_is_boolean(&x);
+
+        let plonkish = Plonkish::compile(&api.build(), &BN254::field()).unwrap();
+        // public input, two additions, mul, assert, boolean
+        assert_eq!(plonkish.stats().constraints, 6);
+
+        let witness = |x: i64, y: i64, z: i64, out: i64| {
+            Witness::from((vec![BigInt::from(out)], vec![x.into(), y.into(), z.into()]))
+        };
+        assert!(plonkish.is_satisfied(&witness(1, 2, 3, 7)));
+        assert!(!plonkish.is_satisfied(&witness(1, 2, 3, 8)));
+        assert!(!plonkish.is_satisfied(&witness(2, 2, 3, 15)));
+
+        let mut assignment = plonkish.solve(&witness(1, 2, 3, 7)).unwrap();
+        assignment[0] = BigInt::zero();
+        assert_eq!(
+            plonkish.check(&assignment),
+            Err(SolveError::WrongConstantWire {
+                found: BigInt::zero()
+            })
+        );
+
+        let sigma = plonkish.permutation();
+        let gates = &plonkish.gates;
+        for (pos, next) in sigma.into_iter().enumerate() {
+            let wire = |pos: usize| gates[pos / COLUMNS].wires[pos % COLUMNS];
+            assert_eq!(wire(pos), wire(next));
+        }
+    }
+}
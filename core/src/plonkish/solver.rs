@@ -0,0 +1,74 @@
+use num::{BigInt, One, Zero};
+
+use crate::{
+    arith,
+    plonkish::Plonkish,
+    r1cs::{ONE, SolveError},
+    types::Witness,
+};
+
+impl Plonkish {
+    /// Computes the full assignment of all variables from the inputs of `witness`.
+    ///
+    /// The assignment is not checked against the gates, see [`Plonkish::check`].
+    pub fn solve(&self, witness: &Witness) -> Result<Vec<BigInt>, SolveError> {
+        let p = self.field();
+        let mut assignment = self.r1cs.solve(witness)?;
+
+        for terms in &self.sums {
+            let sum = terms.iter().fold(BigInt::zero(), |acc, (var, coeff)| {
+                acc + coeff * &assignment[*var]
+            });
+            assignment.push(arith::reduce(&sum, p));
+        }
+
+        Ok(assignment)
+    }
+
+    /// Checks that `assignment` satisfies every gate.
+    ///
+    /// Public input gates read their public input from `assignment` itself, so
+    /// only the gates after them can fail.
+    pub fn check(&self, assignment: &[BigInt]) -> Result<(), SolveError> {
+        let p = self.field();
+
+        if assignment.len() != self.num_variables() {
+            return Err(SolveError::WrongAssignmentLength {
+                expected: self.num_variables(),
+                found: assignment.len(),
+            });
+        }
+        if !assignment[ONE].is_one() {
+            return Err(SolveError::WrongConstantWire {
+                found: assignment[ONE].clone(),
+            });
+        }
+
+        let zero = BigInt::zero();
+        for (idx, gate) in self.gates.iter().enumerate().skip(self.public_len()) {
+            let [a, b, c] = gate
+                .wires
+                .map(|wire| wire.map_or(&zero, |var| &assignment[var]));
+            let value =
+                &gate.q_l * a + &gate.q_r * b + &gate.q_o * c + &gate.q_m * a * b + &gate.q_c;
+
+            if !arith::reduce(&value, p).is_zero() {
+                return Err(SolveError::Unsatisfied {
+                    constraint: idx,
+                    operation: gate
+                        .operation
+                        .expect("only public input gates have no operation"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether `witness` can be extended to a satisfying assignment.
+    pub fn is_satisfied(&self, witness: &Witness) -> bool {
+        self.solve(witness)
+            .and_then(|assignment| self.check(&assignment))
+            .is_ok()
+    }
+}
@@ -0,0 +1,77 @@
+//! Export in the binary `.r1cs` format of circom.
+//!
+//! See <https://github.com/iden3/r1csfile/blob/master/doc/r1cs_bin_format.md>.
+//! All public inputs are exported as public inputs, there are no public outputs.
+
+use std::io::{self, Write};
+
+use crate::r1cs::{LinearCombination, R1cs};
+
+const HEADER_SECTION: u32 = 1;
+const CONSTRAINTS_SECTION: u32 = 2;
+const WIRE_TO_LABEL_SECTION: u32 = 3;
+
+impl R1cs {
+    /// Writes this constraint system in the binary `.r1cs` format of circom.
+    pub fn write_circom(&self, w: &mut impl Write) -> io::Result<()> {
+        // Field elements are little endian, padded to a multiple of 8 bytes.
+        let n8 = self.field.bits().div_ceil(64) as usize * 8;
+        let element = |value: &num::BigInt| {
+            let (_, mut bytes) = value.to_bytes_le();
+            bytes.resize(n8, 0);
+            bytes
+        };
+
+        let mut header = Vec::new();
+        header.extend((n8 as u32).to_le_bytes());
+        header.extend(element(&self.field));
+        header.extend((self.num_wires() as u32).to_le_bytes());
+        header.extend(0u32.to_le_bytes());
+        header.extend((self.public_len as u32).to_le_bytes());
+        header.extend((self.private_len as u32).to_le_bytes());
+        header.extend((self.num_wires() as u64).to_le_bytes());
+        header.extend((self.constraints.len() as u32).to_le_bytes());
+
+        let mut constraints = Vec::new();
+        let mut push_lc = |lc: &LinearCombination| {
+            constraints.extend((lc.0.len() as u32).to_le_bytes());
+            for (wire, coeff) in &lc.0 {
+                constraints.extend((*wire as u32).to_le_bytes());
+                constraints.extend(element(coeff));
+            }
+        };
+        for constraint in &self.constraints {
+            push_lc(&constraint.a);
+            push_lc(&constraint.b);
+            push_lc(&constraint.c);
+        }
+
+        let labels: Vec<u8> = (0..self.num_wires() as u64)
+            .flat_map(u64::to_le_bytes)
+            .collect();
+
+        w.write_all(b"r1cs")?;
+        w.write_all(&1u32.to_le_bytes())?;
+        w.write_all(&3u32.to_le_bytes())?;
+
+        for (ty, section) in [
+            (HEADER_SECTION, header),
+            (CONSTRAINTS_SECTION, constraints),
+            (WIRE_TO_LABEL_SECTION, labels),
+        ] {
+            w.write_all(&ty.to_le_bytes())?;
+            w.write_all(&(section.len() as u64).to_le_bytes())?;
+            w.write_all(&section)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns this constraint system in the binary `.r1cs` format of circom.
+    pub fn to_circom(&self) -> Vec<u8> {
+        let mut res = Vec::new();
+        self.write_circom(&mut res)
+            .expect("writing to a vector never fails");
+        res
+    }
+}
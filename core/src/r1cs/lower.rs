@@ -0,0 +1,485 @@
+use std::collections::{HashMap, HashSet};
+
+use num::{BigInt, One, Zero};
+
+use crate::{
+    arith,
+    r1cs::{CompileError, Constraint, Hint, LinearCombination, ONE, R1cs},
+    types::{CircuitDefinition, OpCode, Operation, VariableType},
+};
+
+type Lc = LinearCombination;
+
+impl R1cs {
+    /// Compiles `circuit` into a rank-1 constraint system over the field of modulus `field`.
+    pub fn compile(circuit: &CircuitDefinition, field: &BigInt) -> Result<Self, CompileError> {
+        Ok(Self::compile_with_outputs(circuit, &[], field)?.0)
+    }
+
+    /// Compiles `circuit` like [`R1cs::compile`], and also returns the linear
+    /// combination of wires each variable of `outputs` evaluates to.
+    ///
+    /// Used for circuits whose results are not public inputs, such as the `z_out`
+    /// state of a [`StepDefinition`](crate::StepDefinition).
+    pub fn compile_with_outputs(
+        circuit: &CircuitDefinition,
+        outputs: &[VariableType],
+        field: &BigInt,
+    ) -> Result<(Self, Vec<LinearCombination>), CompileError> {
+        let mut lowering = Lowering {
+            p: field.clone(),
+            r1cs: R1cs {
+                field: field.clone(),
+                public_len: circuit.public_len as usize,
+                private_len: circuit.private_len as usize,
+                constraints: Vec::new(),
+                hints: Vec::new(),
+            },
+            locals: HashMap::new(),
+            booleans: HashSet::new(),
+            operation: 0,
+        };
+
+        for (idx, operation) in circuit.operations.iter().enumerate() {
+            lowering.operation = idx;
+            lowering.lower(operation)?;
+        }
+
+        let outputs = outputs
+            .iter()
+            .map(|output| lowering.resolve(output))
+            .collect::<Result<_, _>>()?;
+
+        Ok((lowering.r1cs, outputs))
+    }
+}
+
+struct Lowering {
+    p: BigInt,
+    r1cs: R1cs,
+    locals: HashMap<u64, Lc>,
+    /// Internal wires that are constrained to be boolean.
+    booleans: HashSet<usize>,
+    /// Index of the operation being lowered.
+    operation: usize,
+}
+
+impl Lowering {
+    fn lower(&mut self, operation: &Operation) -> Result<(), CompileError> {
+        let index = self.operation;
+        let error = |message: &str| CompileError::InvalidOperation {
+            operation: index,
+            op: operation.op,
+            message: message.to_string(),
+        };
+
+        let inputs = operation
+            .inputs
+            .iter()
+            .map(|input| self.resolve(input))
+            .collect::<Result<Vec<_>, _>>()?;
+        let arity = |n: usize| {
+            if inputs.len() == n {
+                Ok(())
+            } else {
+                Err(error(&format!("expected {n} inputs, got {}", inputs.len())))
+            }
+        };
+
+        let outputs = match operation.op {
+            OpCode::Add => {
+                let zero = Lc::default();
+                vec![inputs.iter().fold(zero, |acc, x| acc.add(x, &self.p))]
+            }
+            OpCode::Sub => {
+                let (first, rest) = inputs.split_first().ok_or_else(|| error("no inputs"))?;
+                vec![
+                    rest.iter()
+                        .fold(first.clone(), |acc, x| acc.sub(x, &self.p)),
+                ]
+            }
+            OpCode::Neg => {
+                arity(1)?;
+                vec![inputs[0].scale(&BigInt::from(-1), &self.p)]
+            }
+            OpCode::Mul => {
+                let (first, rest) = inputs.split_first().ok_or_else(|| error("no inputs"))?;
+                let mut acc = first.clone();
+                for x in rest {
+                    acc = self.mul(&acc, x);
+                }
+                vec![acc]
+            }
+            OpCode::MulAcc => {
+                arity(3)?;
+                let product = self.mul(&inputs[1], &inputs[2]);
+                vec![inputs[0].add(&product, &self.p)]
+            }
+            OpCode::Div | OpCode::DivUnchecked => {
+                arity(2)?;
+                vec![self.div(&inputs[0], &inputs[1], operation.op == OpCode::DivUnchecked)]
+            }
+            OpCode::Inverse => {
+                arity(1)?;
+                vec![self.inverse(&inputs[0])]
+            }
+            OpCode::ToBinary => {
+                arity(2)?;
+                let n = operation.outputs.len();
+                if inputs[1].as_constant() != Some(BigInt::from(n)) {
+                    return Err(error("the bit count must match the number of outputs"));
+                }
+                self.decompose(&inputs[0], n)
+            }
+            OpCode::FromBinary => {
+                let mut acc = Lc::default();
+                for (i, bit) in inputs.iter().enumerate() {
+                    self.assert_boolean(bit);
+                    acc = acc.add(&bit.scale(&(BigInt::one() << i), &self.p), &self.p);
+                }
+                vec![acc]
+            }
+            OpCode::Xor | OpCode::Or | OpCode::And => {
+                arity(2)?;
+                let (a, b) = (&inputs[0], &inputs[1]);
+                self.assert_boolean(a);
+                self.assert_boolean(b);
+                let res = match operation.op {
+                    OpCode::Xor => self.xor(a, b),
+                    OpCode::Or => self.or(a, b),
+                    _ => self.and(a, b),
+                };
+                vec![res]
+            }
+            OpCode::Select => {
+                arity(3)?;
+                self.assert_boolean(&inputs[0]);
+                vec![self.select(&inputs[0], &inputs[1], &inputs[2])]
+            }
+            OpCode::Lookup2 => {
+                arity(6)?;
+                let (b0, b1) = (&inputs[0], &inputs[1]);
+                self.assert_boolean(b0);
+                self.assert_boolean(b1);
+                let low = self.select(b0, &inputs[3], &inputs[2]);
+                let high = self.select(b0, &inputs[5], &inputs[4]);
+                vec![self.select(b1, &high, &low)]
+            }
+            OpCode::IsZero => {
+                arity(1)?;
+                vec![self.is_zero(&inputs[0])]
+            }
+            OpCode::Cmp => {
+                arity(2)?;
+                vec![self.cmp(&inputs[0], &inputs[1])]
+            }
+            OpCode::AssertIsEqual => {
+                arity(2)?;
+                let diff = inputs[0].sub(&inputs[1], &self.p);
+                self.constrain(diff, Lc::wire(ONE), Lc::default());
+                vec![]
+            }
+            OpCode::AssertIsDifferent => {
+                arity(2)?;
+                let diff = inputs[0].sub(&inputs[1], &self.p);
+                self.inverse(&diff);
+                vec![]
+            }
+            OpCode::AssertIsBoolean => {
+                arity(1)?;
+                self.assert_boolean(&inputs[0]);
+                vec![]
+            }
+            OpCode::AssertIsCrumb => {
+                arity(1)?;
+                self.decompose(&inputs[0], 2);
+                vec![]
+            }
+            OpCode::AssertIsLessOrEqual => {
+                arity(2)?;
+                // cmp is -1, 0 or 1, and must not be 1: cmp * (cmp + 1) = 0.
+                let c = self.cmp(&inputs[0], &inputs[1]);
+                let c_plus_one = c.add(&Lc::wire(ONE), &self.p);
+                self.constrain(c, c_plus_one, Lc::default());
+                vec![]
+            }
+            OpCode::AssertGroth16Proof | OpCode::AssertPlonkProof => {
+                return Err(error("only supported by the gnark backend"));
+            }
+            OpCode::Println => vec![],
+        };
+
+        if outputs.len() != operation.outputs.len() {
+            return Err(error(&format!(
+                "expected {} outputs, got {}",
+                outputs.len(),
+                operation.outputs.len()
+            )));
+        }
+
+        for (var, lc) in operation.outputs.iter().zip(outputs) {
+            match var {
+                VariableType::Local(idx) => {
+                    self.locals.insert(*idx, lc);
+                }
+                _ => return Err(error("outputs must be local variables")),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn resolve(&self, var: &VariableType) -> Result<Lc, CompileError> {
+        Ok(match var {
+            VariableType::Public(i) => Lc::wire(1 + *i as usize),
+            VariableType::Private(i) => Lc::wire(1 + self.r1cs.public_len + *i as usize),
+            VariableType::Constant(v) => Lc::constant(v, &self.p),
+            VariableType::Local(i) => {
+                self.locals
+                    .get(i)
+                    .cloned()
+                    .ok_or(CompileError::UndefinedLocal {
+                        operation: self.operation,
+                        index: *i,
+                    })?
+            }
+        })
+    }
+
+    fn constrain(&mut self, a: Lc, b: Lc, c: Lc) {
+        self.r1cs.constraints.push(Constraint {
+            a,
+            b,
+            c,
+            operation: self.operation,
+        });
+    }
+
+    fn new_wire(&mut self, hint: Hint) -> usize {
+        let wire = self.r1cs.num_wires();
+        self.r1cs.hints.push((hint, self.operation));
+        wire
+    }
+
+    fn constant(&self, v: impl Into<BigInt>) -> Lc {
+        Lc::constant(&v.into(), &self.p)
+    }
+
+    fn mul(&mut self, a: &Lc, b: &Lc) -> Lc {
+        if let Some(c) = a.as_constant() {
+            return b.scale(&c, &self.p);
+        }
+        if let Some(c) = b.as_constant() {
+            return a.scale(&c, &self.p);
+        }
+
+        let wire = self.new_wire(Hint::Mul(a.clone(), b.clone()));
+        self.constrain(a.clone(), b.clone(), Lc::wire(wire));
+
+        Lc::wire(wire)
+    }
+
+    fn div(&mut self, a: &Lc, b: &Lc, unchecked: bool) -> Lc {
+        if let Some(inv) = b.as_constant().and_then(|b| arith::inverse(&b, &self.p)) {
+            return a.scale(&inv, &self.p);
+        }
+        if !unchecked {
+            // b * inv = 1, otherwise any output satisfies b * w = a for a = b = 0.
+            self.inverse(b);
+        }
+
+        let wire = self.new_wire(Hint::Div {
+            a: a.clone(),
+            b: b.clone(),
+            unchecked,
+        });
+        self.constrain(b.clone(), Lc::wire(wire), a.clone());
+
+        Lc::wire(wire)
+    }
+
+    fn inverse(&mut self, x: &Lc) -> Lc {
+        if let Some(inv) = x.as_constant().and_then(|x| arith::inverse(&x, &self.p)) {
+            return self.constant(inv);
+        }
+
+        let wire = self.new_wire(Hint::Inverse(x.clone()));
+        self.constrain(x.clone(), Lc::wire(wire), Lc::wire(ONE));
+
+        Lc::wire(wire)
+    }
+
+    fn is_boolean(&self, x: &Lc) -> bool {
+        if let Some(c) = x.as_constant() {
+            return arith::is_bit(&c, &self.p);
+        }
+
+        let mut terms = x.0.iter();
+        match (terms.next(), terms.next()) {
+            (Some((wire, coeff)), None) => coeff.is_one() && self.booleans.contains(wire),
+            _ => false,
+        }
+    }
+
+    fn assert_boolean(&mut self, x: &Lc) {
+        if self.is_boolean(x) {
+            return;
+        }
+
+        // x * (x - 1) = 0
+        let x_minus_one = x.sub(&Lc::wire(ONE), &self.p);
+        self.constrain(x.clone(), x_minus_one, Lc::default());
+
+        if let Some((wire, _)) = x.0.iter().next()
+            && x.0.len() == 1
+            && *wire != ONE
+        {
+            self.booleans.insert(*wire);
+        }
+    }
+
+    fn decompose(&mut self, x: &Lc, n: usize) -> Vec<Lc> {
+        let mut bits = Vec::with_capacity(n);
+        let mut sum = Lc::default();
+
+        for i in 0..n {
+            let bit = match x.as_constant() {
+                Some(c) => self.constant(c.bit(i as u64) as u8),
+                None => {
+                    let wire = self.new_wire(Hint::Bit(x.clone(), i as u64));
+                    let bit = Lc::wire(wire);
+                    self.assert_boolean(&bit);
+                    bit
+                }
+            };
+
+            sum = sum.add(&bit.scale(&(BigInt::one() << i), &self.p), &self.p);
+            bits.push(bit);
+        }
+
+        // Also rejects constants that do not fit into `n` bits.
+        if x.as_constant().is_none() || sum != *x {
+            self.constrain(sum, Lc::wire(ONE), x.clone());
+        }
+
+        // Wide enough to also encode x + p, so the bits must be canonical.
+        if x.as_constant().is_none() && n as u64 >= self.p.bits() {
+            let bound = &self.p - 1;
+            self.assert_at_most(&bits, &bound);
+        }
+
+        bits
+    }
+
+    /// Constrains the little-endian boolean `bits` to encode an integer at most `bound`.
+    fn assert_at_most(&mut self, bits: &[Lc], bound: &BigInt) {
+        let n = bits.len();
+        // The low ones of the bound never constrain the bits.
+        let trailing_ones = (0..n).take_while(|i| bound.bit(*i as u64)).count();
+
+        // equal[i] is one if the bits from i up match the ones of the bound.
+        let mut equal = vec![Lc::wire(ONE); n + 1];
+        for i in (trailing_ones..n).rev() {
+            equal[i] = if bound.bit(i as u64) {
+                self.mul(&equal[i + 1], &bits[i])
+            } else {
+                equal[i + 1].clone()
+            };
+        }
+
+        // Where the bound has a zero, the bit must be zero if the higher ones match:
+        // (1 - equal[i + 1] - bit) * bit = 0.
+        for i in (trailing_ones..n).rev() {
+            if !bound.bit(i as u64) {
+                let l = self.not(&equal[i + 1]).sub(&bits[i], &self.p);
+                self.constrain(l, bits[i].clone(), Lc::default());
+            }
+        }
+    }
+
+    fn and(&mut self, a: &Lc, b: &Lc) -> Lc {
+        let res = self.mul(a, b);
+        self.mark_boolean(&res);
+        res
+    }
+
+    fn or(&mut self, a: &Lc, b: &Lc) -> Lc {
+        // a + b - a * b
+        let ab = self.mul(a, b);
+        let res = a.add(b, &self.p).sub(&ab, &self.p);
+        self.mark_boolean(&res);
+        res
+    }
+
+    fn xor(&mut self, a: &Lc, b: &Lc) -> Lc {
+        // a + b - 2 * a * b
+        let ab = self.mul(a, b);
+        let res = a
+            .add(b, &self.p)
+            .sub(&ab.scale(&BigInt::from(2), &self.p), &self.p);
+        self.mark_boolean(&res);
+        res
+    }
+
+    fn not(&self, a: &Lc) -> Lc {
+        Lc::wire(ONE).sub(a, &self.p)
+    }
+
+    /// Returns `x` if `cond` is one, `y` otherwise.
+    fn select(&mut self, cond: &Lc, x: &Lc, y: &Lc) -> Lc {
+        // y + cond * (x - y)
+        let diff = x.sub(y, &self.p);
+        let res = self.mul(cond, &diff);
+        y.add(&res, &self.p)
+    }
+
+    fn is_zero(&mut self, x: &Lc) -> Lc {
+        if let Some(c) = x.as_constant() {
+            return self.constant(c.is_zero() as u8);
+        }
+
+        // x * inv = 1 - res and x * res = 0
+        let res = self.new_wire(Hint::IsZero(x.clone()));
+        let inv = self.new_wire(Hint::InverseOrZero(x.clone()));
+        let one_minus_res = self.not(&Lc::wire(res));
+        self.constrain(x.clone(), Lc::wire(inv), one_minus_res);
+        self.constrain(x.clone(), Lc::wire(res), Lc::default());
+
+        self.booleans.insert(res);
+        Lc::wire(res)
+    }
+
+    /// Compares the integer representations of `a` and `b`, returning -1, 0 or 1.
+    fn cmp(&mut self, a: &Lc, b: &Lc) -> Lc {
+        let n = self.p.bits() as usize;
+        let bits_a = self.decompose(a, n);
+        let bits_b = self.decompose(b, n);
+
+        let minus_one = self.constant(arith::neg(&BigInt::one(), &self.p));
+        let mut res = Lc::default();
+
+        for i in (0..n).rev() {
+            let not_b = self.not(&bits_b[i]);
+            let not_a = self.not(&bits_a[i]);
+            let a_greater = self.and(&bits_a[i], &not_b);
+            let b_greater = self.and(&bits_b[i], &not_a);
+
+            let lower = self.select(&b_greater, &minus_one, &Lc::default());
+            let m = self.select(&a_greater, &Lc::wire(ONE), &lower);
+            let undecided = self.is_zero(&res);
+            res = self.select(&undecided, &m, &res);
+        }
+
+        res
+    }
+
+    fn mark_boolean(&mut self, x: &Lc) {
+        if let Some((wire, coeff)) = x.0.iter().next()
+            && x.0.len() == 1
+            && coeff.is_one()
+            && *wire != ONE
+        {
+            self.booleans.insert(*wire);
+        }
+    }
+}
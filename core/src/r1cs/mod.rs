@@ -0,0 +1,326 @@
+//! Native compilation of circuits to rank-1 constraint systems.
+//!
+//! [`R1cs::compile`] lowers a [`CircuitDefinition`] into constraints of the form
+//! `<A, w> * <B, w> = <C, w>` over the circuit field, without going through a
+//! backend. Each constraint is one row of the `A`, `B` and `C` matrices.
+//!
+//! The assignment vector `w` is laid out as:
+//!
+//! | Wire | Value |
+//! |------|-------|
+//! | `0` | the constant `1` |
+//! | `1..=public_len` | public inputs |
+//! | next `private_len` wires | private inputs |
+//! | remaining wires | internal wires, computed by the solver |
+//!
+//! Linear operations (`add`, `sub`, `neg`, multiplication by constants) do not
+//! produce constraints, they only combine wires. Every internal wire comes with a
+//! hint that [`R1cs::solve`] uses to compute its value from the inputs.
+//!
+//! ```rust,ignore
+//! let definition = Prover::<MockProverBackend>::new().define_circuit::<MyCircuit>();
+//! let r1cs = R1cs::compile(&definition, &BN254::field())?;
+//! r1cs.check(&r1cs.solve(&witness)?)?;
+//! std::fs::write("circuit.r1cs", r1cs.to_circom())?;
+//! ```
+
+mod lower;
+
+mod solver;
+pub use solver::*;
+
+mod circom;
+
+use std::collections::BTreeMap;
+
+use num::{BigInt, Zero};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    arith,
+    stats::{ConstraintStats, ConstraintSystemKind},
+    types::OpCode,
+};
+
+/// Index of the wire that is always `1`.
+pub const ONE: usize = 0;
+
+/// A linear combination of wires, mapping wire indices to non-zero coefficients.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LinearCombination(pub BTreeMap<usize, BigInt>);
+
+impl LinearCombination {
+    /// The linear combination of a single wire.
+    pub fn wire(wire: usize) -> Self {
+        Self(BTreeMap::from([(wire, BigInt::from(1))]))
+    }
+
+    /// The constant `value`, as a multiple of the [`ONE`] wire.
+    pub fn constant(value: &BigInt, p: &BigInt) -> Self {
+        let mut res = Self::default();
+        res.add_term(ONE, value, p);
+        res
+    }
+
+    /// Returns the value of this linear combination if it does not depend on any input.
+    pub fn as_constant(&self) -> Option<BigInt> {
+        match self.0.iter().next() {
+            None => Some(BigInt::zero()),
+            Some((&ONE, value)) if self.0.len() == 1 => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /// Evaluates this linear combination on a full assignment.
+    pub fn evaluate(&self, assignment: &[BigInt], p: &BigInt) -> BigInt {
+        let sum = self.0.iter().fold(BigInt::zero(), |acc, (wire, coeff)| {
+            acc + coeff * &assignment[*wire]
+        });
+
+        arith::reduce(&sum, p)
+    }
+
+    fn add_term(&mut self, wire: usize, coeff: &BigInt, p: &BigInt) {
+        let value = arith::add(self.0.get(&wire).unwrap_or(&BigInt::zero()), coeff, p);
+
+        if value.is_zero() {
+            self.0.remove(&wire);
+        } else {
+            self.0.insert(wire, value);
+        }
+    }
+
+    pub(crate) fn add(&self, rhs: &Self, p: &BigInt) -> Self {
+        let mut res = self.clone();
+        for (wire, coeff) in &rhs.0 {
+            res.add_term(*wire, coeff, p);
+        }
+        res
+    }
+
+    pub(crate) fn sub(&self, rhs: &Self, p: &BigInt) -> Self {
+        self.add(&rhs.scale(&BigInt::from(-1), p), p)
+    }
+
+    pub(crate) fn scale(&self, factor: &BigInt, p: &BigInt) -> Self {
+        let mut res = Self::default();
+        for (wire, coeff) in &self.0 {
+            res.add_term(*wire, &arith::mul(coeff, factor, p), p);
+        }
+        res
+    }
+}
+
+/// A single constraint `a * b = c`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constraint {
+    pub a: LinearCombination,
+    pub b: LinearCombination,
+    pub c: LinearCombination,
+    /// Index of the operation of the circuit definition this constraint comes from.
+    pub operation: usize,
+}
+
+/// How the solver computes an internal wire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum Hint {
+    /// `a * b`.
+    Mul(LinearCombination, LinearCombination),
+    /// `a / b`. Fails on a zero divisor, unless `unchecked` and `a` is zero too.
+    Div {
+        a: LinearCombination,
+        b: LinearCombination,
+        unchecked: bool,
+    },
+    /// `1 / x`, failing on zero.
+    Inverse(LinearCombination),
+    /// `1 / x`, or zero if `x` is zero.
+    InverseOrZero(LinearCombination),
+    /// `1` if `x` is zero, `0` otherwise.
+    IsZero(LinearCombination),
+    /// The bit of the given position in the canonical representation of `x`.
+    Bit(LinearCombination, u64),
+}
+
+/// A rank-1 constraint system, see the [module documentation](self).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct R1cs {
+    /// Modulus of the field the constraints are defined over.
+    pub field: BigInt,
+    pub public_len: usize,
+    pub private_len: usize,
+    pub constraints: Vec<Constraint>,
+    /// Hints of the internal wires, with the operation they come from.
+    pub(crate) hints: Vec<(Hint, usize)>,
+}
+
+impl R1cs {
+    /// Returns the total number of wires, including the constant [`ONE`] wire.
+    pub fn num_wires(&self) -> usize {
+        self.first_internal() + self.hints.len()
+    }
+
+    /// Returns the number of internal wires.
+    pub fn num_internal(&self) -> usize {
+        self.hints.len()
+    }
+
+    /// Returns the rows of the `A`, `B` and `C` matrices.
+    pub fn matrices(
+        &self,
+    ) -> (
+        Vec<&LinearCombination>,
+        Vec<&LinearCombination>,
+        Vec<&LinearCombination>,
+    ) {
+        (
+            self.constraints.iter().map(|c| &c.a).collect(),
+            self.constraints.iter().map(|c| &c.b).collect(),
+            self.constraints.iter().map(|c| &c.c).collect(),
+        )
+    }
+
+    /// Returns the size of this constraint system, counted like gnark does.
+    ///
+    /// The constant [`ONE`] wire is counted as a public variable.
+    pub fn stats(&self) -> ConstraintStats {
+        ConstraintStats {
+            system: ConstraintSystemKind::R1cs,
+            constraints: self.constraints.len() as u64,
+            internal_variables: self.num_internal() as u64,
+            public_variables: self.public_len as u64 + 1,
+            secret_variables: self.private_len as u64,
+        }
+    }
+
+    pub(crate) fn first_internal(&self) -> usize {
+        1 + self.public_len + self.private_len
+    }
+}
+
+/// An error while compiling a circuit definition to R1CS.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CompileError {
+    #[error("operation {operation} reads local variable {index} before it is written")]
+    UndefinedLocal { operation: usize, index: u64 },
+
+    #[error("operation {operation} ({op:?}): {message}")]
+    InvalidOperation {
+        operation: usize,
+        op: OpCode,
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use num::One;
+
+    use super::*;
+    use crate::{API, CurveId, ProvingSystem, curve::BN254, test_builder, types::Witness};
+
+    #[test]
+    fn test_compile_and_solve() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let x = api.variable_initer_mut().new_private();
+        let y = api.variable_initer_mut().new_private();
+        let out = api.variable_initer_mut().new_public(false);
+
+        // out = x * y + 3, with x < 16 and x < y
+        let xy = api.mul(&x, &y);
+        let sum = api.add(&xy, &3);
+        api.assert_is_equal(&sum, &out);
+        api.variable_to_binary(&x, 4);
+        api.assert_is_different(&x, &y);
+        let c = api.cmp(&x, &y);
+        api.assert_is_equal(&c, &-1);
+        let x_is_zero = api.is_zero(&x);
+        let selected = api.select(&x_is_zero, &1, &c);
+        api.println(&selected);
+
+        let r1cs = R1cs::compile(&api.build(), &BN254::field()).unwrap();
+        assert_eq!((r1cs.public_len, r1cs.private_len), (1, 2));
+
+        let witness = |x: i64, y: i64, out: i64| {
+            Witness::from((vec![BigInt::from(out)], vec![x.into(), y.into()]))
+        };
+
+        let mut assignment = r1cs.solve(&witness(5, 7, 38)).unwrap();
+        assert_eq!(r1cs.check(&assignment), Ok(()));
+        assert!(matches!(
+            r1cs.check(&assignment[1..]),
+            Err(SolveError::WrongAssignmentLength { .. })
+        ));
+        assignment[ONE] = BigInt::from(2);
+        assert_eq!(
+            r1cs.check(&assignment),
+            Err(SolveError::WrongConstantWire {
+                found: BigInt::from(2)
+            })
+        );
+
+        assert!(matches!(
+            r1cs.solve(&witness(5, 7, 39)).and_then(|a| r1cs.check(&a)),
+            Err(SolveError::Unsatisfied { operation: 2, .. })
+        ));
+        assert!(!r1cs.is_satisfied(&witness(17, 1, 20)));
+        assert!(!r1cs.is_satisfied(&witness(5, 5, 28)));
+        assert!(!r1cs.is_satisfied(&witness(7, 5, 38)));
+
+        let circom = r1cs.to_circom();
+        assert_eq!(&circom[0..4], b"r1cs");
+    }
+
+    #[test]
+    fn test_checked_division_by_zero() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let x = api.variable_initer_mut().new_private();
+        let y = api.variable_initer_mut().new_private();
+        let out = api.variable_initer_mut().new_public(false);
+
+        let quotient = api.div(&x, &y);
+        api.assert_is_equal(&quotient, &out);
+
+        let r1cs = R1cs::compile(&api.build(), &BN254::field()).unwrap();
+        let witness = |x: i64, y: i64, out: i64| {
+            Witness::from((vec![BigInt::from(out)], vec![x.into(), y.into()]))
+        };
+        assert!(r1cs.is_satisfied(&witness(6, 3, 2)));
+        assert!(!r1cs.is_satisfied(&witness(0, 0, 5)));
+
+        // 0 * 5 = 0 holds, so a prover skipping the solver could claim 0 / 0 = 5.
+        let mut assignment = vec![5, 0, 0]
+            .into_iter()
+            .map(BigInt::from)
+            .collect::<Vec<_>>();
+        assignment.insert(0, BigInt::one());
+        assignment.resize(r1cs.num_wires(), BigInt::from(5));
+        assert!(r1cs.check(&assignment).is_err());
+    }
+
+    #[test]
+    fn test_full_width_decomposition_is_canonical() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let x = api.variable_initer_mut().new_private();
+        api.variable_to_binary(&x, BN254::field().bits());
+
+        let r1cs = R1cs::compile(&api.build(), &BN254::field()).unwrap();
+        let witness = Witness::from((vec![], vec![BigInt::one()]));
+        let honest = r1cs.solve(&witness).unwrap();
+        assert_eq!(r1cs.check(&honest), Ok(()));
+
+        // The bits of 1 + p also fit and sum to 1 in the field.
+        let p = &r1cs.field;
+        let mut forged = honest[..r1cs.first_internal()].to_vec();
+        for (hint, _) in &r1cs.hints {
+            let value = match hint {
+                Hint::Bit(x, i) => BigInt::from((x.evaluate(&forged, p) + p).bit(*i) as u8),
+                Hint::Mul(a, b) => arith::mul(&a.evaluate(&forged, p), &b.evaluate(&forged, p), p),
+                _ => unreachable!(),
+            };
+            forged.push(value);
+        }
+        assert_ne!(forged, honest);
+        assert!(r1cs.check(&forged).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use num::{BigInt, One, Zero};
+
+use crate::{
+    arith,
+    r1cs::{Hint, ONE, R1cs},
+    types::Witness,
+};
+
+/// An error while solving or checking an R1CS assignment.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SolveError {
+    #[error("expected {expected} {kind} inputs, got {found}")]
+    WrongInputCount {
+        kind: &'static str,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("expected an assignment of {expected} wires, got {found}")]
+    WrongAssignmentLength { expected: usize, found: usize },
+
+    #[error("expected the constant wire to be 1, got {found}")]
+    WrongConstantWire { found: BigInt },
+
+    #[error("cannot compute wire {wire} of operation {operation}: {message}")]
+    HintFailed {
+        wire: usize,
+        operation: usize,
+        message: &'static str,
+    },
+
+    #[error("constraint {constraint} of operation {operation} is not satisfied")]
+    Unsatisfied { constraint: usize, operation: usize },
+}
+
+impl R1cs {
+    /// Computes the full assignment of all wires from the inputs of `witness`.
+    ///
+    /// The assignment is not checked against the constraints, see [`R1cs::check`].
+    pub fn solve(&self, witness: &Witness) -> Result<Vec<BigInt>, SolveError> {
+        self.solve_with_bits(witness, |x, i| x.bit(i))
+    }
+
+    /// Computes the full assignment like [`R1cs::solve`], with `bit(x, i)` as the
+    /// bit `i` of the decompositions of `x` instead of its canonical representation.
+    ///
+    /// Used to test that circuits reject the decompositions of a dishonest prover.
+    #[doc(hidden)]
+    pub fn solve_with_bits(
+        &self,
+        witness: &Witness,
+        bit: impl Fn(&BigInt, u64) -> bool,
+    ) -> Result<Vec<BigInt>, SolveError> {
+        let p = &self.field;
+
+        for (kind, expected, found) in [
+            ("public", self.public_len, witness.public().len()),
+            ("private", self.private_len, witness.private().len()),
+        ] {
+            if expected != found {
+                return Err(SolveError::WrongInputCount {
+                    kind,
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        let mut assignment = Vec::with_capacity(self.num_wires());
+        assignment.push(BigInt::one());
+        for input in witness.public().iter().chain(witness.private()) {
+            assignment.push(arith::reduce(input, p));
+        }
+
+        for (hint, operation) in &self.hints {
+            let wire = assignment.len();
+            let failed = |message| SolveError::HintFailed {
+                wire,
+                operation: *operation,
+                message,
+            };
+
+            let value = match hint {
+                Hint::Mul(a, b) => {
+                    arith::mul(&a.evaluate(&assignment, p), &b.evaluate(&assignment, p), p)
+                }
+                Hint::Div { a, b, unchecked } => {
+                    let (a, b) = (a.evaluate(&assignment, p), b.evaluate(&assignment, p));
+                    if *unchecked && a.is_zero() && b.is_zero() {
+                        BigInt::zero()
+                    } else {
+                        let inv =
+                            arith::inverse(&b, p).ok_or_else(|| failed("division by zero"))?;
+                        arith::mul(&a, &inv, p)
+                    }
+                }
+                Hint::Inverse(x) => arith::inverse(&x.evaluate(&assignment, p), p)
+                    .ok_or_else(|| failed("inverse of zero"))?,
+                Hint::InverseOrZero(x) => {
+                    arith::inverse(&x.evaluate(&assignment, p), p).unwrap_or_default()
+                }
+                Hint::IsZero(x) => BigInt::from(x.evaluate(&assignment, p).is_zero() as u8),
+                Hint::Bit(x, i) => BigInt::from(bit(&x.evaluate(&assignment, p), *i) as u8),
+            };
+
+            assignment.push(value);
+        }
+
+        Ok(assignment)
+    }
+
+    /// Checks that `assignment` satisfies every constraint.
+    pub fn check(&self, assignment: &[BigInt]) -> Result<(), SolveError> {
+        let p = &self.field;
+
+        if assignment.len() != self.num_wires() {
+            return Err(SolveError::WrongAssignmentLength {
+                expected: self.num_wires(),
+                found: assignment.len(),
+            });
+        }
+        if !assignment[ONE].is_one() {
+            return Err(SolveError::WrongConstantWire {
+                found: assignment[ONE].clone(),
+            });
+        }
+
+        for (idx, constraint) in self.constraints.iter().enumerate() {
+            let a = constraint.a.evaluate(assignment, p);
+            let b = constraint.b.evaluate(assignment, p);
+            let c = constraint.c.evaluate(assignment, p);
+
+            if arith::mul(&a, &b, p) != c {
+                return Err(SolveError::Unsatisfied {
+                    constraint: idx,
+                    operation: constraint.operation,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether `witness` can be extended to a satisfying assignment.
+    pub fn is_satisfied(&self, witness: &Witness) -> bool {
+        self.solve(witness)
+            .and_then(|assignment| self.check(&assignment))
+            .is_ok()
+    }
+}
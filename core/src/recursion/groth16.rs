@@ -0,0 +1,222 @@
+//! Groth16 proofs over BLS12-377, verified in BW6-761 circuits.
+//!
+//! The base field of BLS12-377 is the scalar field of BW6-761, so points of the
+//! inner proof are native elements of the outer circuit and pairings are cheap.
+//! Public inputs of the inner circuit belong to the scalar field of BLS12-377,
+//! which is smaller than the outer field.
+//!
+//! [`Proof`] and [`PublicInputs`] can be used as fields of a circuit, public or
+//! private. The gnark backend converts its proofs and keys over BLS12-377 into
+//! them.
+//!
+//! The [`VerifyingKey`] should be fixed when the outer circuit is defined, with
+//! [`VerifyingKeyVariable::constant`]. A private verifying key lets the prover
+//! pick any key, including one of a circuit it can prove anything with, so the
+//! outer proof would attest to nothing. A public one must be checked by the
+//! verifier of the outer proof.
+//!
+//! ```rust,ignore
+//! #[circuit]
+//! pub struct Recursive {
+//!     proof: Proof,
+//!     pub inputs: PublicInputs<1>,
+//! }
+//!
+//! impl Circuit for Recursive {
+//!     fn define(&self, api: &mut impl API) {
+//!         let vk = VerifyingKeyVariable::constant(vk());
+//!         groth16::assert_proof(api, &vk, &self.proof, &self.inputs);
+//!     }
+//! }
+//! ```
+//!
+//! Verifying keys with Pedersen commitments are not supported.
+
+use num::{BigInt, Integer};
+
+use crate::{
+    API, CircuitElement, CircuitPublicWitness, CircuitWitness, CurveId, CurveType, Metadata,
+    VariableIniter,
+    curve::BLS12_377,
+    types::{OpCode, VariableType},
+};
+
+/// Number of elements of a [`Proof`].
+///
+/// These are the coordinates of `Ar`, `Krs` and `Bs`, with the coordinates of
+/// `Bs` in the quadratic extension as `(A0, A1)`.
+pub const PROOF_LEN: usize = 8;
+
+/// Returns the number of elements of a [`VerifyingKey`] for `n` public inputs.
+///
+/// These are `e(α, β)` as 12 elements of the target group, the negated `γ` and
+/// `δ` in G2, and the `n + 1` points of `K` in G1.
+pub const fn verifying_key_len(n: usize) -> usize {
+    12 + 4 + 4 + 2 * (n + 1)
+}
+
+/// A Groth16 proof over BLS12-377, see [`PROOF_LEN`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proof {
+    values: Vec<BigInt>,
+}
+
+impl Proof {
+    /// Creates a proof from its [`PROOF_LEN`] elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have [`PROOF_LEN`] elements.
+    pub fn new(values: Vec<BigInt>) -> Self {
+        assert_eq!(values.len(), PROOF_LEN, "wrong number of proof elements");
+        Self { values }
+    }
+}
+
+define_recursion_witness!(Proof, ProofVariable, PROOF_LEN);
+
+/// A Groth16 verifying key over BLS12-377 for `N` public inputs, see
+/// [`verifying_key_len`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyingKey<const N: usize> {
+    values: Vec<BigInt>,
+}
+
+impl<const N: usize> VerifyingKey<N> {
+    /// Creates a verifying key from its [`verifying_key_len`] elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have `verifying_key_len(N)` elements.
+    pub fn new(values: Vec<BigInt>) -> Self {
+        assert_eq!(
+            values.len(),
+            verifying_key_len(N),
+            "wrong number of verifying key elements"
+        );
+        Self { values }
+    }
+}
+
+define_recursion_witness!(VerifyingKey, VerifyingKeyVariable, verifying_key_len(N), N);
+
+/// The `N` public inputs of a Groth16 proof over BLS12-377.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputs<const N: usize> {
+    values: Vec<BigInt>,
+}
+
+impl<const N: usize> PublicInputs<N> {
+    /// Creates public inputs, reduced modulo the scalar field of BLS12-377.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have `N` elements.
+    pub fn new(values: Vec<BigInt>) -> Self {
+        assert_eq!(values.len(), N, "wrong number of public inputs");
+        let r = BLS12_377::field();
+
+        Self {
+            values: values.iter().map(|value| value.mod_floor(&r)).collect(),
+        }
+    }
+
+    /// Returns the public inputs of the inner circuit, from its public witness.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the inner circuit does not have `N` public inputs.
+    pub fn from_public_witness(witness: &impl CircuitPublicWitness) -> Self {
+        let mut values = Vec::new();
+        witness.append_public_witness(&mut values, false);
+
+        Self::new(values)
+    }
+}
+
+define_recursion_witness!(PublicInputs, PublicInputsVariable, N, N);
+
+/// Asserts that `proof` is a valid proof for `public` under `vk`.
+///
+/// `vk` should be a [constant](VerifyingKeyVariable::constant), see the
+/// [module documentation](self).
+///
+/// # Panics
+///
+/// Panics if the circuit is not defined over BW6-761.
+#[track_caller]
+pub fn assert_proof<const N: usize>(
+    api: &mut impl API,
+    vk: &VerifyingKeyVariable<N>,
+    proof: &ProofVariable,
+    public: &PublicInputsVariable<N>,
+) {
+    assert!(
+        matches!(api.metadata().curve(), CurveType::BW6_761),
+        "Groth16 proofs over BLS12-377 can only be verified in BW6-761 circuits"
+    );
+
+    let inputs = [&proof.variables, &vk.variables, &public.variables]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+    api.append_operation(OpCode::AssertGroth16Proof, inputs, vec![]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProvingSystem, curve::BW6_761, test_builder};
+
+    #[test]
+    fn test_assert_proof() {
+        let mut api = test_builder::<BW6_761>(ProvingSystem::Groth16);
+        let public = PublicInputs::<2>::create_public(api.variable_initer_mut(), false);
+        let proof = Proof::create_private(api.variable_initer_mut());
+        let vk = VerifyingKeyVariable::constant(&VerifyingKey::<2>::new(vec![
+            BigInt::from(7);
+            verifying_key_len(2)
+        ]));
+        assert_proof(&mut api, &vk, &proof, &public);
+
+        let circuit = api.build();
+        assert_eq!(circuit.public_len, 2);
+        assert_eq!(circuit.private_len, PROOF_LEN as u64);
+
+        let operation = &circuit.operations[0];
+        assert_eq!(operation.op, OpCode::AssertGroth16Proof);
+        assert_eq!(operation.inputs.len(), PROOF_LEN + verifying_key_len(2) + 2);
+        assert_eq!(operation.inputs[0], VariableType::Private(0));
+        assert_eq!(
+            operation.inputs[PROOF_LEN],
+            VariableType::Constant(7.into())
+        );
+        assert_eq!(operation.inputs[30 + 2 * 2], VariableType::Public(0));
+    }
+
+    #[test]
+    fn test_public_inputs() {
+        let r = BLS12_377::field();
+        let inputs = PublicInputs::<2>::new(vec![(-1).into(), &r + 3]);
+        assert_eq!(inputs.values(), [&r - 1, 3.into()]);
+        let inputs = PublicInputs::<1>::from_public_witness(&-2_i64);
+        assert_eq!(inputs.values(), [&r - 2]);
+
+        let mut public = Vec::new();
+        let mut private = Vec::new();
+        inputs.append_witness(&mut public, &mut private, true);
+        assert!(public.is_empty());
+        assert_eq!(private, [&r - 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "only be verified in BW6-761 circuits")]
+    fn test_wrong_curve() {
+        let mut api = test_builder::<BLS12_377>(ProvingSystem::Groth16);
+        let public = PublicInputs::<1>::create_private(api.variable_initer_mut());
+        let proof = Proof::create_private(api.variable_initer_mut());
+        let vk = VerifyingKey::<1>::create_private(api.variable_initer_mut());
+        assert_proof(&mut api, &vk, &proof, &public);
+    }
+}
@@ -0,0 +1,98 @@
+//! Verification of proofs inside circuits.
+//!
+//! Recursion verifies a proof of an inner circuit as part of an outer circuit,
+//! so the outer proof attests to both. The inner proof, its verifying key and
+//! its public inputs are inputs of the outer circuit, flattened into elements of
+//! the outer field.
+//!
+//! - [`groth16`]: Groth16 proofs over BLS12-377, verified in BW6-761 circuits.
+//! - [`plonk`]: PLONK proofs over BN254, verified in BN254 circuits.
+//!
+//! Verification is lowered by the backend, so recursive circuits are only
+//! supported by the gnark backend.
+
+/// Implements the circuit traits for a witness holding its elements in `values`,
+/// and defines `$variable` holding their variables.
+macro_rules! define_recursion_witness {
+    ($witness:ident, $variable:ident, $len:expr $(, $n:ident)?) => {
+        impl<$(const $n: usize)?> $witness<$($n)?> {
+            /// Returns the elements.
+            pub fn values(&self) -> &[BigInt] {
+                &self.values
+            }
+        }
+
+        impl<$(const $n: usize)?> CircuitWitness for $witness<$($n)?> {
+            type CircuitElement = $variable<$($n)?>;
+            type PublicWitness = Self;
+
+            fn create_public(initer: &mut VariableIniter, is_private: bool) -> Self::CircuitElement {
+                $variable {
+                    variables: (0..$len).map(|_| initer.new_public(is_private)).collect(),
+                }
+            }
+
+            fn create_private(initer: &mut VariableIniter) -> Self::CircuitElement {
+                $variable {
+                    variables: (0..$len).map(|_| initer.new_private()).collect(),
+                }
+            }
+
+            fn into_public_witness(self) -> Self::PublicWitness {
+                self
+            }
+
+            fn append_witness(
+                &self,
+                public: &mut Vec<BigInt>,
+                private: &mut Vec<BigInt>,
+                is_private: bool,
+            ) {
+                if is_private {
+                    private.extend_from_slice(&self.values);
+                } else {
+                    public.extend_from_slice(&self.values);
+                }
+            }
+        }
+
+        impl<$(const $n: usize)?> CircuitPublicWitness for $witness<$($n)?> {
+            fn append_public_witness(&self, witness: &mut Vec<BigInt>, is_private: bool) {
+                if !is_private {
+                    witness.extend_from_slice(&self.values);
+                }
+            }
+        }
+
+        impl<$(const $n: usize)?> CircuitElement for $witness<$($n)?> {
+            type CircuitWitness = Self;
+        }
+
+        /// Variables of the elements in a circuit.
+        #[derive(Debug, Clone)]
+        pub struct $variable<$(const $n: usize)?> {
+            variables: Vec<VariableType>,
+        }
+
+        impl<$(const $n: usize)?> $variable<$($n)?> {
+            /// Uses `value` as a constant of the circuit.
+            pub fn constant(value: &$witness<$($n)?>) -> Self {
+                Self {
+                    variables: value.values.iter().cloned().map(VariableType::Constant).collect(),
+                }
+            }
+
+            pub fn variables(&self) -> &[VariableType] {
+                &self.variables
+            }
+        }
+
+        impl<$(const $n: usize)?> CircuitElement for $variable<$($n)?> {
+            type CircuitWitness = $witness<$($n)?>;
+        }
+    };
+}
+
+pub mod groth16;
+
+pub mod plonk;
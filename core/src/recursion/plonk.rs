@@ -0,0 +1,211 @@
+//! PLONK proofs over BN254, verified in BN254 circuits.
+//!
+//! The inner and the outer circuit share the curve, so points of the inner proof
+//! are emulated in the outer circuit, each coordinate as 4 limbs of 64 bits.
+//! Public inputs of the inner circuit are native elements of the outer circuit.
+//! This is how many proofs of the same circuit are aggregated into one.
+//!
+//! The [`VerifyingKey`] is fixed when the outer circuit is defined and becomes a
+//! constant of it. [`Proof`] can be used as a field of a circuit, public or
+//! private. The gnark backend converts its proofs and keys over BN254 into them;
+//! inner proofs must be generated with `PlonkBackend::with_recursion`, which
+//! selects the transcript hash of the verifier circuit.
+//!
+//! ```rust,ignore
+//! #[circuit]
+//! pub struct Aggregate {
+//!     proofs: [Proof; 2],
+//!     pub inputs: [u32; 2],
+//! }
+//!
+//! impl Circuit for Aggregate {
+//!     fn define(&self, api: &mut impl API) {
+//!         for (proof, input) in self.proofs.iter().zip(&self.inputs) {
+//!             plonk::assert_proof(api, vk(), proof, &[input]);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Verifying keys with BSB22 commitments are not supported.
+
+use num::{BigInt, bigint::Sign};
+
+use crate::{
+    API, CircuitElement, CircuitPublicWitness, CircuitWitness, CurveType, Metadata, Variable,
+    VariableIniter,
+    types::{OpCode, VariableType},
+};
+
+/// Number of limbs of an emulated element.
+const LIMBS: usize = 4;
+
+/// Number of bits of a limb.
+const LIMB_BITS: u64 = 64;
+
+/// Number of bytes of the serialized verifying key in each constant.
+const KEY_CHUNK_BYTES: usize = 31;
+
+/// Number of emulated elements of a [`Proof`].
+///
+/// These are the coordinates of the 9 commitments `L`, `R`, `O`, `Z`, the 3
+/// parts of `H` and the 2 opening quotients, then the 6 batched claimed values
+/// and the claimed value of `Z` at the shifted point.
+pub const PROOF_ELEMENTS: usize = 9 * 2 + 7;
+
+/// Number of elements of a [`Proof`], see [`PROOF_ELEMENTS`].
+pub const PROOF_LEN: usize = PROOF_ELEMENTS * LIMBS;
+
+/// A PLONK proof over BN254, see [`PROOF_LEN`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proof {
+    values: Vec<BigInt>,
+}
+
+impl Proof {
+    /// Creates a proof from its [`PROOF_ELEMENTS`] elements, split into limbs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have [`PROOF_ELEMENTS`] elements, or if one
+    /// of them does not fit in 256 bits.
+    pub fn new(values: Vec<BigInt>) -> Self {
+        assert_eq!(
+            values.len(),
+            PROOF_ELEMENTS,
+            "wrong number of proof elements"
+        );
+
+        let mask = &BigInt::from(u64::MAX);
+        let values = values
+            .iter()
+            .flat_map(|value| {
+                assert!(
+                    value.sign() != Sign::Minus && value.bits() <= LIMB_BITS * LIMBS as u64,
+                    "proof element out of range"
+                );
+                (0..LIMBS).map(move |i| (value >> (LIMB_BITS * i as u64)) & mask)
+            })
+            .collect();
+
+        Self { values }
+    }
+}
+
+define_recursion_witness!(Proof, ProofVariable, PROOF_LEN);
+
+/// A PLONK verifying key over BN254, used as a constant of the outer circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyingKey {
+    values: Vec<BigInt>,
+}
+
+impl VerifyingKey {
+    /// Creates a verifying key from its serialization by gnark.
+    ///
+    /// The bytes are packed into elements as their length, then big-endian
+    /// chunks of 31 bytes, which fit in the field of BN254.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let values = std::iter::once(BigInt::from(bytes.len()))
+            .chain(
+                bytes
+                    .chunks(KEY_CHUNK_BYTES)
+                    .map(|chunk| BigInt::from_bytes_be(Sign::Plus, chunk)),
+            )
+            .collect();
+
+        Self { values }
+    }
+
+    /// Returns the elements.
+    pub fn values(&self) -> &[BigInt] {
+        &self.values
+    }
+}
+
+/// Asserts that `proof` is a valid proof for `public` under `vk`.
+///
+/// # Panics
+///
+/// Panics if the circuit is not defined over BN254.
+#[track_caller]
+pub fn assert_proof(
+    api: &mut impl API,
+    vk: &VerifyingKey,
+    proof: &ProofVariable,
+    public: &[impl Variable],
+) {
+    assert!(
+        matches!(api.metadata().curve(), CurveType::BN254),
+        "PLONK proofs over BN254 can only be verified in BN254 circuits"
+    );
+
+    let inputs = vk
+        .values
+        .iter()
+        .cloned()
+        .map(VariableType::Constant)
+        .chain(proof.variables.iter().cloned())
+        .chain(public.iter().map(Variable::ty))
+        .collect();
+    api.append_operation(OpCode::AssertPlonkProof, inputs, vec![]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ProvingSystem,
+        curve::{BLS12_381, BN254},
+        test_builder,
+    };
+
+    #[test]
+    fn test_assert_proof() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let public = u32::create_public(api.variable_initer_mut(), false);
+        let proofs = <[Proof; 2]>::create_private(api.variable_initer_mut());
+        let vk = VerifyingKey::from_bytes(&[1; 40]);
+        for proof in &proofs {
+            assert_proof(&mut api, &vk, proof, &[&public, &public]);
+        }
+
+        let circuit = api.build();
+        assert_eq!(circuit.public_len, 1);
+        assert_eq!(circuit.private_len, 2 * PROOF_LEN as u64);
+        assert_eq!(circuit.operations.len(), 2);
+
+        let operation = &circuit.operations[1];
+        assert_eq!(operation.op, OpCode::AssertPlonkProof);
+        assert_eq!(operation.inputs.len(), 3 + PROOF_LEN + 2);
+        assert_eq!(operation.inputs[0], VariableType::Constant(40.into()));
+        assert_eq!(operation.inputs[3], VariableType::Private(PROOF_LEN as u64));
+        assert_eq!(operation.inputs[3 + PROOF_LEN], VariableType::Public(0));
+    }
+
+    #[test]
+    fn test_values() {
+        let vk = VerifyingKey::from_bytes(&[0xff; 33]);
+        assert_eq!(
+            vk.values(),
+            [33.into(), (BigInt::from(1) << 248) - 1, 0xffff.into()]
+        );
+
+        let mut values = vec![BigInt::from(0); PROOF_ELEMENTS];
+        values[0] = (BigInt::from(3) << 192) + 5;
+        let proof = Proof::new(values);
+        assert_eq!(proof.values().len(), PROOF_LEN);
+        assert_eq!(
+            proof.values()[..LIMBS],
+            [5.into(), 0.into(), 0.into(), 3.into()]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "only be verified in BN254 circuits")]
+    fn test_wrong_curve() {
+        let mut api = test_builder::<BLS12_381>(ProvingSystem::Groth16);
+        let proof = Proof::create_private(api.variable_initer_mut());
+        assert_proof(&mut api, &VerifyingKey::from_bytes(&[]), &proof, &[1_u32]);
+    }
+}
@@ -0,0 +1,67 @@
+//! Versioning of the serialized [`CircuitDefinition`].
+//!
+//! Every definition records the [`SCHEMA_VERSION`] it was built with.
+//! [`from_json`] accepts definitions of older versions and migrates them to the
+//! current one, and refuses definitions written by a newer version of rsnark.
+//!
+//! | Version | Changes |
+//! |---------|---------|
+//! | 0 | Definitions without a `schema_version` field |
+//! | 1 | Adds `schema_version`, and optional `span` and `ns` on operations |
+
+use crate::types::CircuitDefinition;
+
+/// The schema version of definitions built by this version of rsnark.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// An error while loading a serialized [`CircuitDefinition`].
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("unsupported circuit schema version {0}, the newest supported is {SCHEMA_VERSION}")]
+    UnsupportedVersion(u32),
+
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// Deserializes a JSON circuit definition and migrates it to [`SCHEMA_VERSION`].
+pub fn from_json(data: &[u8]) -> Result<CircuitDefinition, SchemaError> {
+    let definition: CircuitDefinition = serde_json::from_slice(data)?;
+
+    migrate(definition)
+}
+
+/// Migrates `definition` to [`SCHEMA_VERSION`].
+pub fn migrate(mut definition: CircuitDefinition) -> Result<CircuitDefinition, SchemaError> {
+    if definition.schema_version > SCHEMA_VERSION {
+        return Err(SchemaError::UnsupportedVersion(definition.schema_version));
+    }
+
+    // Version 1 only added optional fields, which deserialize to their defaults.
+    if definition.schema_version == 0 {
+        definition.schema_version = 1;
+    }
+
+    Ok(definition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate() {
+        let legacy = br#"{"private_len":1,"public_len":0,"local_len":0,"operations":[
+            {"op":"assert_is_boolean","in":[{"t":"private","v":0}],"out":[]}]}"#;
+
+        let definition = from_json(legacy).unwrap();
+        assert_eq!(definition.schema_version, SCHEMA_VERSION);
+        assert_eq!(definition.operations.len(), 1);
+
+        let future = br#"{"schema_version":99,"private_len":0,"public_len":0,"local_len":0,"operations":[]}"#;
+        assert!(matches!(
+            from_json(future),
+            Err(SchemaError::UnsupportedVersion(99))
+        ));
+    }
+}
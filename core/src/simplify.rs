@@ -0,0 +1,282 @@
+//! Constant folding and algebraic simplification of single operations.
+//!
+//! Simplification never removes a constraint that can fail: operations which
+//! implicitly constrain their inputs (such as `Xor` asserting booleanity, or
+//! `Div` asserting a non-zero divisor) are only folded when their constant
+//! inputs are known to satisfy that constraint. Assertions on constants are
+//! only dropped when they hold, so unsatisfiable circuits stay unsatisfiable.
+
+use num::{BigInt, One, Zero};
+
+use crate::{
+    arith,
+    types::{OpCode, VariableType},
+};
+
+/// Result of simplifying a single operation.
+pub(crate) enum Simplified {
+    /// The operation must be recorded, with the given (possibly rewritten) inputs.
+    Keep {
+        inputs: Vec<VariableType>,
+        rewritten: bool,
+    },
+    /// The operation is removed, and its outputs are replaced by `outputs`.
+    Replace {
+        outputs: Vec<VariableType>,
+        folded: bool,
+    },
+    /// The operation is an assertion that always holds.
+    Drop,
+}
+
+fn constant(x: BigInt) -> VariableType {
+    VariableType::Constant(x)
+}
+
+fn as_constant(x: &VariableType) -> Option<&BigInt> {
+    match x {
+        VariableType::Constant(v) => Some(v),
+        _ => None,
+    }
+}
+
+/// Simplifies `op` applied to `inputs`, in the field of modulus `p`.
+pub(crate) fn simplify(
+    op: OpCode,
+    inputs: Vec<VariableType>,
+    outputs_len: usize,
+    p: &BigInt,
+) -> Simplified {
+    let constants: Option<Vec<BigInt>> = inputs
+        .iter()
+        .map(|x| as_constant(x).map(|v| arith::reduce(v, p)))
+        .collect();
+
+    if let Some(values) = constants
+        && let Some(res) = fold(op, &values, outputs_len, p)
+    {
+        return res;
+    }
+
+    simplify_identities(op, inputs, p)
+}
+
+/// Evaluates an operation whose inputs are all constants.
+fn fold(op: OpCode, v: &[BigInt], outputs_len: usize, p: &BigInt) -> Option<Simplified> {
+    let replace = |x: BigInt| {
+        Some(Simplified::Replace {
+            outputs: vec![constant(x)],
+            folded: true,
+        })
+    };
+    let check = |holds: bool| if holds { Some(Simplified::Drop) } else { None };
+
+    match op {
+        OpCode::Add => replace(
+            v.iter()
+                .fold(BigInt::zero(), |acc, x| arith::add(&acc, x, p)),
+        ),
+        OpCode::Sub => {
+            let (first, rest) = v.split_first()?;
+            replace(
+                rest.iter()
+                    .fold(first.clone(), |acc, x| arith::sub(&acc, x, p)),
+            )
+        }
+        OpCode::Mul => replace(
+            v.iter()
+                .fold(BigInt::one(), |acc, x| arith::mul(&acc, x, p)),
+        ),
+        OpCode::MulAcc => {
+            let [a, b, c] = v else { return None };
+            replace(arith::add(a, &arith::mul(b, c, p), p))
+        }
+        OpCode::Neg => replace(arith::neg(v.first()?, p)),
+        OpCode::Div => {
+            let [a, b] = v else { return None };
+            replace(arith::mul(a, &arith::inverse(b, p)?, p))
+        }
+        OpCode::DivUnchecked => {
+            let [a, b] = v else { return None };
+            if a.is_zero() && b.is_zero() {
+                replace(BigInt::zero())
+            } else {
+                replace(arith::mul(a, &arith::inverse(b, p)?, p))
+            }
+        }
+        OpCode::Inverse => replace(arith::inverse(v.first()?, p)?),
+        OpCode::IsZero => replace(BigInt::from(v.first()?.is_zero() as u8)),
+        OpCode::Cmp => {
+            let [a, b] = v else { return None };
+            replace(match a.cmp(b) {
+                std::cmp::Ordering::Less => arith::neg(&BigInt::one(), p),
+                std::cmp::Ordering::Equal => BigInt::zero(),
+                std::cmp::Ordering::Greater => BigInt::one(),
+            })
+        }
+        OpCode::Xor | OpCode::Or | OpCode::And => {
+            let [a, b] = v else { return None };
+            if !arith::is_bit(a, p) || !arith::is_bit(b, p) {
+                return None;
+            }
+            let (a, b) = (a.is_one(), b.is_one());
+            let res = match op {
+                OpCode::Xor => a ^ b,
+                OpCode::Or => a | b,
+                _ => a & b,
+            };
+            replace(BigInt::from(res as u8))
+        }
+        OpCode::ToBinary => {
+            let [x, n] = v else { return None };
+            if n != &BigInt::from(outputs_len) || x.bits() > outputs_len as u64 {
+                return None;
+            }
+            let outputs = (0..outputs_len as u64)
+                .map(|i| constant(BigInt::from(x.bit(i) as u8)))
+                .collect();
+            Some(Simplified::Replace {
+                outputs,
+                folded: true,
+            })
+        }
+        OpCode::FromBinary => {
+            if !v.iter().all(|b| arith::is_bit(b, p)) {
+                return None;
+            }
+            let res = v.iter().rev().fold(BigInt::zero(), |acc, b| (acc << 1) + b);
+            replace(arith::reduce(&res, p))
+        }
+        OpCode::AssertIsEqual => check(v.first()? == v.get(1)?),
+        OpCode::AssertIsDifferent => check(v.first()? != v.get(1)?),
+        OpCode::AssertIsBoolean => check(arith::is_bit(v.first()?, p)),
+        OpCode::AssertIsCrumb => check(v.first()? <= &BigInt::from(3)),
+        OpCode::AssertIsLessOrEqual => check(v.first()? <= v.get(1)?),
+        OpCode::Select
+        | OpCode::Lookup2
+        | OpCode::AssertGroth16Proof
+        | OpCode::AssertPlonkProof
+        | OpCode::Println => None,
+    }
+}
+
+/// Applies algebraic identities to an operation with at least one variable input.
+fn simplify_identities(op: OpCode, inputs: Vec<VariableType>, p: &BigInt) -> Simplified {
+    let is = |x: &VariableType, v: u8| {
+        as_constant(x).is_some_and(|c| arith::reduce(c, p) == BigInt::from(v))
+    };
+    let replace = |x: VariableType| Simplified::Replace {
+        outputs: vec![x],
+        folded: false,
+    };
+
+    match op {
+        OpCode::Add => {
+            let (vars, sum) = split_constants(&inputs, p, BigInt::zero(), arith::add);
+            merge(inputs, vars, sum, BigInt::zero())
+        }
+        OpCode::Mul => {
+            let (vars, product) = split_constants(&inputs, p, BigInt::one(), arith::mul);
+            if product.is_zero() {
+                return replace(constant(product));
+            }
+            merge(inputs, vars, product, BigInt::one())
+        }
+        OpCode::Sub if inputs.len() == 2 && inputs[0] == inputs[1] => {
+            replace(constant(BigInt::zero()))
+        }
+        OpCode::Sub if inputs.len() >= 2 => {
+            let mut new_inputs = vec![inputs[0].clone()];
+            new_inputs.extend(inputs[1..].iter().filter(|x| !is(x, 0)).cloned());
+
+            if new_inputs.len() == 1 {
+                replace(new_inputs.remove(0))
+            } else if new_inputs.len() == inputs.len() {
+                keep(inputs)
+            } else {
+                Simplified::Keep {
+                    inputs: new_inputs,
+                    rewritten: true,
+                }
+            }
+        }
+        OpCode::MulAcc if inputs.len() == 3 => {
+            let (a, b, c) = (&inputs[0], &inputs[1], &inputs[2]);
+            if is(b, 0) || is(c, 0) {
+                replace(a.clone())
+            } else if is(a, 0) && is(b, 1) {
+                replace(c.clone())
+            } else if is(a, 0) && is(c, 1) {
+                replace(b.clone())
+            } else {
+                keep(inputs)
+            }
+        }
+        OpCode::Div | OpCode::DivUnchecked if inputs.len() == 2 && is(&inputs[1], 1) => {
+            replace(inputs[0].clone())
+        }
+        OpCode::Select if inputs.len() == 3 && (is(&inputs[0], 0) || is(&inputs[0], 1)) => {
+            let idx = if is(&inputs[0], 1) { 1 } else { 2 };
+            replace(inputs[idx].clone())
+        }
+        OpCode::AssertIsEqual if inputs.len() == 2 && inputs[0] == inputs[1] => Simplified::Drop,
+        _ => keep(inputs),
+    }
+}
+
+fn keep(inputs: Vec<VariableType>) -> Simplified {
+    Simplified::Keep {
+        inputs,
+        rewritten: false,
+    }
+}
+
+/// Splits `inputs` into variables and the combination of all constant inputs.
+fn split_constants(
+    inputs: &[VariableType],
+    p: &BigInt,
+    init: BigInt,
+    f: fn(&BigInt, &BigInt, &BigInt) -> BigInt,
+) -> (Vec<VariableType>, BigInt) {
+    let mut vars = Vec::with_capacity(inputs.len());
+    let mut acc = init;
+
+    for x in inputs {
+        match as_constant(x) {
+            Some(v) => acc = f(&acc, v, p),
+            None => vars.push(x.clone()),
+        }
+    }
+
+    (vars, acc)
+}
+
+/// Rebuilds an `Add` or `Mul` from its variable inputs and merged constant.
+fn merge(
+    inputs: Vec<VariableType>,
+    mut vars: Vec<VariableType>,
+    acc: BigInt,
+    identity: BigInt,
+) -> Simplified {
+    let constants = inputs.len() - vars.len();
+
+    if acc == identity {
+        if vars.len() == 1 {
+            return Simplified::Replace {
+                outputs: vars,
+                folded: false,
+            };
+        }
+    } else {
+        vars.push(constant(acc));
+    }
+
+    if constants == 0 || (constants == 1 && vars.len() == inputs.len()) {
+        return keep(inputs);
+    }
+
+    Simplified::Keep {
+        inputs: vars,
+        rewritten: true,
+    }
+}
@@ -0,0 +1,177 @@
+//! Size statistics of circuits.
+//!
+//! [`CircuitStats`] counts the operations of a [`CircuitDefinition`] per
+//! [`OpCode`] and per namespace. Backends that compile into a constraint system
+//! can additionally report its real size as [`ConstraintStats`].
+//!
+//! ```rust,ignore
+//! let stats = Prover::<Groth16Backend<BN254>>::new().circuit_stats::<MyCircuit>()?;
+//! println!("{stats}");
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CircuitDefinition, OpCode};
+
+/// The kind of constraint system a circuit was compiled into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConstraintSystemKind {
+    /// Rank-1 constraint system, as used by Groth16.
+    R1cs,
+    /// Sparse constraint system, as used by PLONK.
+    Scs,
+}
+
+impl fmt::Display for ConstraintSystemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintSystemKind::R1cs => write!(f, "R1CS"),
+            ConstraintSystemKind::Scs => write!(f, "SCS"),
+        }
+    }
+}
+
+/// Size of a compiled constraint system, as reported by the backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstraintStats {
+    pub system: ConstraintSystemKind,
+    /// Number of R1CS constraints or SCS gates.
+    pub constraints: u64,
+    pub internal_variables: u64,
+    pub public_variables: u64,
+    pub secret_variables: u64,
+}
+
+/// Operation and variable counts of a circuit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitStats {
+    pub public_inputs: u64,
+    pub private_inputs: u64,
+    pub locals: u64,
+    pub operations: usize,
+    pub by_op: BTreeMap<OpCode, usize>,
+    /// Operations per namespace path, such as `outer/inner`. Operations outside
+    /// of any namespace are counted under the empty path.
+    pub by_namespace: BTreeMap<String, usize>,
+    /// Only present once the circuit has been compiled by a backend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<ConstraintStats>,
+}
+
+impl CircuitStats {
+    /// Collects the statistics of `circuit`.
+    pub fn from_definition(circuit: &CircuitDefinition) -> Self {
+        let mut by_op = BTreeMap::new();
+        let mut by_namespace = BTreeMap::new();
+
+        for operation in &circuit.operations {
+            *by_op.entry(operation.op).or_insert(0) += 1;
+            *by_namespace
+                .entry(operation.namespace.join("/"))
+                .or_insert(0) += 1;
+        }
+
+        Self {
+            public_inputs: circuit.public_len,
+            private_inputs: circuit.private_len,
+            locals: circuit.local_len,
+            operations: circuit.operations.len(),
+            by_op,
+            by_namespace,
+            constraints: None,
+        }
+    }
+
+    /// Attaches the constraint counts reported by a backend.
+    pub fn with_constraints(mut self, constraints: Option<ConstraintStats>) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Renders the statistics as pretty printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("circuit stats are always serializable")
+    }
+}
+
+impl fmt::Display for CircuitStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rows = vec![
+            ("variables".to_string(), None),
+            ("  public".to_string(), Some(self.public_inputs)),
+            ("  private".to_string(), Some(self.private_inputs)),
+            ("  local".to_string(), Some(self.locals)),
+            ("operations".to_string(), Some(self.operations as u64)),
+        ];
+
+        for (op, count) in &self.by_op {
+            rows.push((format!("  {op:?}"), Some(*count as u64)));
+        }
+
+        rows.push(("namespaces".to_string(), None));
+        for (namespace, count) in &self.by_namespace {
+            let name = if namespace.is_empty() {
+                "(root)"
+            } else {
+                namespace
+            };
+            rows.push((format!("  {name}"), Some(*count as u64)));
+        }
+
+        if let Some(cs) = &self.constraints {
+            rows.push((format!("constraint system ({})", cs.system), None));
+            rows.push(("  constraints".to_string(), Some(cs.constraints)));
+            rows.push((
+                "  internal variables".to_string(),
+                Some(cs.internal_variables),
+            ));
+            rows.push(("  public variables".to_string(), Some(cs.public_variables)));
+            rows.push(("  secret variables".to_string(), Some(cs.secret_variables)));
+        }
+
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (name, count) in rows {
+            match count {
+                Some(count) => writeln!(f, "{name:<width$}  {count:>8}")?,
+                None => writeln!(f, "{name}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{API, ProvingSystem, curve::BN254, test_builder};
+
+    #[test]
+    fn test_stats() {
+        let mut api = test_builder::<BN254>(ProvingSystem::Groth16);
+        let a = api.variable_initer_mut().new_private();
+        let b = api.variable_initer_mut().new_public(false);
+
+        let s = api.add(&a, &b);
+        api.scope("inner", |api| {
+            let m = api.mul(&s, &a);
+            api.assert_is_equal(&m, &b);
+        });
+
+        let stats = CircuitStats::from_definition(&api.build());
+
+        assert_eq!((stats.public_inputs, stats.private_inputs), (1, 1));
+        assert_eq!(stats.operations, 3);
+        assert_eq!(stats.by_op[&OpCode::Add], 1);
+        assert_eq!(stats.by_op[&OpCode::AssertIsEqual], 1);
+        assert_eq!(stats.by_namespace[""], 1);
+        assert_eq!(stats.by_namespace["inner"], 2);
+
+        let json: serde_json::Value = serde_json::from_str(&stats.to_json()).unwrap();
+        assert_eq!(json["by_op"]["mul"], 1);
+        assert!(stats.to_string().contains("(root)"));
+    }
+}
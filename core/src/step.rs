@@ -0,0 +1,99 @@
+use num::BigInt;
+
+use crate::{
+    API, CircuitBuilder, CircuitElement, CircuitElementInner, CircuitWitness, MetadataInfo,
+    Witness,
+    types::{self, CircuitDefinition, VariableType},
+};
+
+/// Defines one step of an incremental computation, for folding backends.
+///
+/// A step maps the state `z_in` to the next state `z_out`, both of
+/// [`ARITY`](Self::ARITY) elements. The fields of the circuit structure are the
+/// inputs of a single step, and are always private, even if declared `pub`.
+///
+/// ```rust,ignore
+/// #[circuit]
+/// pub struct Fibonacci {
+///     increment: u32,
+/// }
+///
+/// impl StepCircuit for Fibonacci {
+///     const ARITY: usize = 2;
+///
+///     fn step(&self, api: &mut impl API, z_in: &[VariableType]) -> Vec<VariableType> {
+///         let sum = api.add(&z_in[0], &z_in[1]);
+///         vec![z_in[1].clone(), api.add(&sum, &self.increment)]
+///     }
+/// }
+/// ```
+pub trait StepCircuit {
+    /// Number of elements of the state.
+    const ARITY: usize;
+
+    /// Returns `z_out`, computed from `z_in` and the inputs of the step.
+    fn step(&self, api: &mut impl API, z_in: &[VariableType]) -> Vec<VariableType>;
+}
+
+/// The circuit definition of a [`StepCircuit`].
+///
+/// The public inputs of the definition are `z_in`, and the inputs of the step
+/// follow as private inputs. `z_out` is not an input: its variables are kept
+/// in [`z_out`](Self::z_out), see [`R1cs::compile_with_outputs`](crate::r1cs::R1cs::compile_with_outputs).
+///
+/// The definition is not optimized, since the passes would remove the
+/// operations that only contribute to `z_out`.
+#[derive(Debug, Clone)]
+pub struct StepDefinition {
+    pub definition: CircuitDefinition,
+    pub z_out: Vec<VariableType>,
+}
+
+impl StepDefinition {
+    /// Builds the step definition of `C`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step does not return [`ARITY`](StepCircuit::ARITY) outputs.
+    pub fn new<C>(metadata: MetadataInfo) -> Self
+    where
+        C: CircuitElement,
+        CircuitElementInner<C>: StepCircuit,
+    {
+        let arity = <CircuitElementInner<C> as StepCircuit>::ARITY;
+
+        let mut builder = CircuitBuilder::new(metadata);
+        let z_in = (0..arity)
+            .map(|_| builder.variable_initer_mut().new_public(false))
+            .collect::<Vec<_>>();
+        let circuit = C::CircuitWitness::create_public(builder.variable_initer_mut(), true);
+        let z_out = circuit.step(&mut builder, &z_in);
+        assert_eq!(
+            z_out.len(),
+            arity,
+            "step circuit returned {} outputs, expected {arity}",
+            z_out.len()
+        );
+
+        Self {
+            definition: builder.build(),
+            z_out,
+        }
+    }
+
+    /// Returns the number of elements of the state.
+    pub fn arity(&self) -> usize {
+        self.z_out.len()
+    }
+
+    /// Returns the witness of the step from `z_in` with the given inputs.
+    pub fn witness<C>(z_in: &[BigInt], inputs: &Witness<C>) -> types::Witness
+    where
+        C: CircuitElement,
+    {
+        let mut private = Vec::new();
+        inputs.append_witness(&mut Vec::new(), &mut private, true);
+
+        types::Witness::from((z_in.to_vec(), private))
+    }
+}
@@ -0,0 +1,152 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::{
+    Variable,
+    typed::{Field, Recorder},
+    types::{OpCode, VariableType},
+};
+
+/// A typed boolean, known to be either 0 or 1.
+///
+/// Booleans are created through [`Context::boolean`](crate::typed::Context::boolean),
+/// or as the result of comparisons and bit decompositions. Logical operations are
+/// available through the `&`, `|`, `^` and `!` operators.
+///
+/// Results of these operations are known to be boolean, so they never need an
+/// additional `assert_is_boolean` constraint.
+#[derive(Clone)]
+pub struct Boolean<'c> {
+    ctx: &'c dyn Recorder,
+    var: VariableType,
+}
+
+impl<'c> Boolean<'c> {
+    pub(crate) fn new(ctx: &'c dyn Recorder, var: VariableType) -> Self {
+        Self { ctx, var }
+    }
+
+    #[track_caller]
+    fn op(&self, op: OpCode, inputs: Vec<VariableType>) -> Self {
+        let res = self.ctx.op(op, inputs);
+        self.ctx.mark_boolean(res.clone());
+
+        Self::new(self.ctx, res)
+    }
+
+    /// Returns the underlying variable.
+    pub fn variable(&self) -> &VariableType {
+        &self.var
+    }
+
+    /// Returns this boolean as a field element.
+    pub fn as_field(&self) -> Field<'c> {
+        Field::new(self.ctx, self.var.clone())
+    }
+
+    /// Returns `if_true` if this boolean is 1, `if_false` otherwise.
+    #[track_caller]
+    pub fn select(&self, if_true: &impl Variable, if_false: &impl Variable) -> Field<'c> {
+        let res = self.ctx.op(
+            OpCode::Select,
+            vec![self.var.clone(), if_true.ty(), if_false.ty()],
+        );
+
+        Field::new(self.ctx, res)
+    }
+
+    /// Asserts that this boolean is equal to `rhs`.
+    #[track_caller]
+    pub fn assert_eq(&self, rhs: &Boolean<'_>) {
+        self.ctx
+            .assert(OpCode::AssertIsEqual, vec![self.var.clone(), rhs.ty()]);
+    }
+
+    /// Asserts that this boolean is 1.
+    #[track_caller]
+    pub fn assert_true(&self) {
+        self.ctx.assert(
+            OpCode::AssertIsEqual,
+            vec![self.var.clone(), VariableType::Constant(1.into())],
+        );
+    }
+
+    /// Asserts that this boolean is 0.
+    #[track_caller]
+    pub fn assert_false(&self) {
+        self.ctx.assert(
+            OpCode::AssertIsEqual,
+            vec![self.var.clone(), VariableType::Constant(0.into())],
+        );
+    }
+}
+
+impl Variable for Boolean<'_> {
+    fn ty(&self) -> VariableType {
+        self.var.clone()
+    }
+}
+
+macro_rules! impl_boolean_binary_op {
+    ($trait:ident, $method:ident, $op:expr) => {
+        impl<'c> $trait<&Boolean<'c>> for &Boolean<'c> {
+            type Output = Boolean<'c>;
+
+            #[track_caller]
+            fn $method(self, rhs: &Boolean<'c>) -> Boolean<'c> {
+                self.op($op, vec![self.var.clone(), rhs.var.clone()])
+            }
+        }
+
+        impl<'c> $trait<Boolean<'c>> for &Boolean<'c> {
+            type Output = Boolean<'c>;
+
+            #[track_caller]
+            fn $method(self, rhs: Boolean<'c>) -> Boolean<'c> {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<'c> $trait<&Boolean<'c>> for Boolean<'c> {
+            type Output = Boolean<'c>;
+
+            #[track_caller]
+            fn $method(self, rhs: &Boolean<'c>) -> Boolean<'c> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<'c> $trait<Boolean<'c>> for Boolean<'c> {
+            type Output = Boolean<'c>;
+
+            #[track_caller]
+            fn $method(self, rhs: Boolean<'c>) -> Boolean<'c> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_boolean_binary_op!(BitAnd, bitand, OpCode::And);
+impl_boolean_binary_op!(BitOr, bitor, OpCode::Or);
+impl_boolean_binary_op!(BitXor, bitxor, OpCode::Xor);
+
+impl<'c> Not for &Boolean<'c> {
+    type Output = Boolean<'c>;
+
+    #[track_caller]
+    fn not(self) -> Boolean<'c> {
+        self.op(
+            OpCode::Sub,
+            vec![VariableType::Constant(1.into()), self.var.clone()],
+        )
+    }
+}
+
+impl<'c> Not for Boolean<'c> {
+    type Output = Boolean<'c>;
+
+    #[track_caller]
+    fn not(self) -> Boolean<'c> {
+        !&self
+    }
+}
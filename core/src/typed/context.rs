@@ -0,0 +1,274 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashSet,
+};
+
+use num::{BigInt, One, Zero};
+use ruint::aliases::U256;
+
+use crate::{
+    API, Fr, Metadata, Variable,
+    typed::{Boolean, Field, UInt},
+    types::{OpCode, VariableType},
+    variable::CircuitVariable,
+};
+
+/// Recording interface shared by all typed variables.
+///
+/// Typed variables only hold a reference to this trait object, so they stay
+/// independent of the concrete [`API`] implementation they were created from.
+pub(crate) trait Recorder {
+    /// Records an operation with a single freshly allocated output.
+    #[track_caller]
+    fn op(&self, op: OpCode, inputs: Vec<VariableType>) -> VariableType;
+
+    /// Records an operation without outputs, such as an assertion.
+    #[track_caller]
+    fn assert(&self, op: OpCode, inputs: Vec<VariableType>);
+
+    /// Decomposes `x` into `n` bits, least significant bit first.
+    #[track_caller]
+    fn to_binary(&self, x: VariableType, n: usize) -> Vec<VariableType>;
+
+    /// Returns the number of bits of the field modulus.
+    fn field_bits(&self) -> u64;
+
+    /// Returns `true` if `x` is known to be 0 or 1.
+    fn is_boolean(&self, x: &VariableType) -> bool;
+
+    /// Records that `x` is known to be 0 or 1.
+    fn mark_boolean(&self, x: VariableType);
+}
+
+/// The active circuit context of the typed front end.
+///
+/// A `Context` wraps the [`API`] passed to [`Circuit::define`](crate::Circuit::define)
+/// and hands out typed variables ([`Boolean`], [`UInt`] and [`Field`]). Every method
+/// and overloaded operator on these variables records its operation into the
+/// wrapped API.
+///
+/// The context also tracks which variables are known to be boolean, so that
+/// redundant `assert_is_boolean` constraints are never emitted.
+///
+/// ```rust,ignore
+/// impl Circuit for MyCircuit {
+///     fn define(&self, api: &mut impl API) {
+///         let ctx = Context::new(api);
+///
+///         let a = ctx.typed(&self.a); // UInt<32>
+///         let b = ctx.typed(&self.b); // UInt<32>
+///
+///         let c = (&a ^ &b).as_field() * 3;
+///         c.assert_eq(&self.c);
+///     }
+/// }
+/// ```
+pub struct Context<'a, A> {
+    api: RefCell<&'a mut A>,
+    booleans: RefCell<HashSet<VariableType>>,
+    field_bits: Cell<Option<u64>>,
+}
+
+impl<'a, A> Context<'a, A>
+where
+    A: API,
+{
+    /// Creates a new context recording into `api`.
+    pub fn new(api: &'a mut A) -> Self {
+        Self {
+            api: RefCell::new(api),
+            booleans: RefCell::new(HashSet::new()),
+            field_bits: Cell::new(None),
+        }
+    }
+
+    /// Runs `f` with direct access to the wrapped API.
+    ///
+    /// Typed variables implement [`Variable`], so they can be passed to the
+    /// untyped API inside `f`.
+    pub fn with_api<R>(&self, f: impl FnOnce(&mut A) -> R) -> R {
+        let mut api = self.api.borrow_mut();
+        f(&mut api)
+    }
+
+    /// Runs `f` inside the namespace `name`, see [`API::scope`].
+    pub fn scope<R>(&self, name: &str, f: impl FnOnce() -> R) -> R {
+        self.api.borrow_mut().push_namespace(name);
+        let res = f();
+        self.api.borrow_mut().pop_namespace();
+        res
+    }
+
+    /// Lifts a circuit input into its natural typed variable.
+    ///
+    /// `bool` inputs become [`Boolean`], unsigned integers become [`UInt`] of the
+    /// same width, and every other input becomes a [`Field`].
+    #[track_caller]
+    pub fn typed<T: TypedVariable>(&self, x: &T) -> T::Typed<'_> {
+        x.typed(self)
+    }
+
+    /// Wraps `x` as a field element without adding any constraint.
+    pub fn field(&self, x: &impl Variable) -> Field<'_> {
+        Field::new(self, x.ty())
+    }
+
+    /// Creates a constant field element.
+    pub fn constant(&self, x: impl Into<BigInt>) -> Field<'_> {
+        Field::new(self, VariableType::Constant(x.into()))
+    }
+
+    /// Wraps `x` as a boolean.
+    ///
+    /// A booleanity constraint is added unless `x` is already known to be boolean.
+    #[track_caller]
+    pub fn boolean(&self, x: &impl Variable) -> Boolean<'_> {
+        let x = x.ty();
+        self.assert_is_boolean(&x);
+        Boolean::new(self, x)
+    }
+
+    /// Wraps `x` as an `N`-bit unsigned integer.
+    ///
+    /// This decomposes `x` into `N` bits, which also constrains `x` to `[0, 2^N)`.
+    #[track_caller]
+    pub fn uint<const N: usize>(&self, x: &impl Variable) -> UInt<'_, N> {
+        UInt::from_field(&self.field(x))
+    }
+
+    /// Asserts that `x` is boolean, unless this is already known.
+    #[track_caller]
+    pub fn assert_is_boolean(&self, x: &impl Variable) {
+        let x = x.ty();
+
+        if !Recorder::is_boolean(self, &x) {
+            Recorder::assert(self, OpCode::AssertIsBoolean, vec![x.clone()]);
+            self.mark_boolean(x);
+        }
+    }
+}
+
+impl<A> Recorder for Context<'_, A>
+where
+    A: API,
+{
+    #[track_caller]
+    fn op(&self, op: OpCode, inputs: Vec<VariableType>) -> VariableType {
+        let mut api = self.api.borrow_mut();
+
+        let res = api.allocate_local_variable();
+        api.append_operation(op, inputs, vec![res.clone()]);
+
+        res
+    }
+
+    #[track_caller]
+    fn assert(&self, op: OpCode, inputs: Vec<VariableType>) {
+        self.api.borrow_mut().append_operation(op, inputs, vec![]);
+    }
+
+    #[track_caller]
+    fn to_binary(&self, x: VariableType, n: usize) -> Vec<VariableType> {
+        let bits = self.api.borrow_mut().variable_to_binary(&x, n as u64);
+
+        for bit in &bits {
+            self.mark_boolean(bit.clone());
+        }
+
+        bits
+    }
+
+    fn field_bits(&self) -> u64 {
+        if let Some(bits) = self.field_bits.get() {
+            return bits;
+        }
+
+        let bits = self.api.borrow().metadata().field_bits();
+        self.field_bits.set(Some(bits));
+        bits
+    }
+
+    fn is_boolean(&self, x: &VariableType) -> bool {
+        match x {
+            VariableType::Constant(v) => v.is_zero() || v.is_one(),
+            _ => self.booleans.borrow().contains(x),
+        }
+    }
+
+    fn mark_boolean(&self, x: VariableType) {
+        if !matches!(x, VariableType::Constant(_)) {
+            self.booleans.borrow_mut().insert(x);
+        }
+    }
+}
+
+/// Circuit inputs that can be lifted into a typed variable.
+///
+/// This is implemented for [`CircuitVariable<T>`] of all supported witness types,
+/// see [`Context::typed`].
+pub trait TypedVariable {
+    /// The typed variable this input is lifted into.
+    type Typed<'c>;
+
+    /// Lifts this input into a typed variable recording into `ctx`.
+    #[track_caller]
+    fn typed<'c, A: API>(&self, ctx: &'c Context<'_, A>) -> Self::Typed<'c>;
+}
+
+impl TypedVariable for CircuitVariable<bool> {
+    type Typed<'c> = Boolean<'c>;
+
+    #[track_caller]
+    fn typed<'c, A: API>(&self, ctx: &'c Context<'_, A>) -> Self::Typed<'c> {
+        ctx.boolean(self)
+    }
+}
+
+macro_rules! define_typed_for_uint {
+    ($t:ty, $n:expr) => {
+        impl TypedVariable for CircuitVariable<$t> {
+            type Typed<'c> = UInt<'c, $n>;
+
+            #[track_caller]
+            fn typed<'c, A: API>(&self, ctx: &'c Context<'_, A>) -> Self::Typed<'c> {
+                ctx.uint::<$n>(self)
+            }
+        }
+    };
+}
+
+define_typed_for_uint!(u8, 8);
+define_typed_for_uint!(u16, 16);
+define_typed_for_uint!(u32, 32);
+define_typed_for_uint!(u64, 64);
+define_typed_for_uint!(u128, 128);
+
+macro_rules! define_typed_for_field {
+    ($t:ty) => {
+        impl TypedVariable for CircuitVariable<$t> {
+            type Typed<'c> = Field<'c>;
+
+            #[track_caller]
+            fn typed<'c, A: API>(&self, ctx: &'c Context<'_, A>) -> Self::Typed<'c> {
+                ctx.field(self)
+            }
+        }
+    };
+}
+
+define_typed_for_field!(i8);
+define_typed_for_field!(i16);
+define_typed_for_field!(i32);
+define_typed_for_field!(i64);
+define_typed_for_field!(i128);
+define_typed_for_field!(U256);
+define_typed_for_field!(BigInt);
+
+impl<C> TypedVariable for CircuitVariable<Fr<C>> {
+    type Typed<'c> = Field<'c>;
+
+    #[track_caller]
+    fn typed<'c, A: API>(&self, ctx: &'c Context<'_, A>) -> Self::Typed<'c> {
+        ctx.field(self)
+    }
+}
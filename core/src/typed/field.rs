@@ -0,0 +1,182 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{
+    Variable,
+    typed::{Boolean, Recorder, UInt},
+    types::{OpCode, VariableType},
+};
+
+/// A typed field element.
+///
+/// `Field` supports arithmetic through overloaded operators. The right-hand side
+/// of an operator can be any [`Variable`], including constants and other typed
+/// variables:
+///
+/// ```rust,ignore
+/// let x = ctx.field(&self.x);
+/// let y = &x * &x + 3;
+/// let z = (y - &x) / 2;
+/// ```
+///
+/// Division through `/` is checked, see [`Field::div_unchecked`] for the unchecked
+/// variant. Bit-level operations are only available on [`UInt`] and [`Boolean`].
+#[derive(Clone)]
+pub struct Field<'c> {
+    ctx: &'c dyn Recorder,
+    var: VariableType,
+}
+
+impl<'c> Field<'c> {
+    pub(crate) fn new(ctx: &'c dyn Recorder, var: VariableType) -> Self {
+        Self { ctx, var }
+    }
+
+    pub(crate) fn ctx(&self) -> &'c dyn Recorder {
+        self.ctx
+    }
+
+    #[track_caller]
+    fn op(&self, op: OpCode, inputs: Vec<VariableType>) -> Self {
+        Self::new(self.ctx, self.ctx.op(op, inputs))
+    }
+
+    /// Returns the underlying variable.
+    pub fn variable(&self) -> &VariableType {
+        &self.var
+    }
+
+    /// Computes `self + (b * c)` in a single operation.
+    #[track_caller]
+    pub fn mul_acc(&self, b: &impl Variable, c: &impl Variable) -> Self {
+        self.op(OpCode::MulAcc, vec![self.var.clone(), b.ty(), c.ty()])
+    }
+
+    /// Computes `self * self`.
+    #[track_caller]
+    pub fn square(&self) -> Self {
+        self.op(OpCode::Mul, vec![self.var.clone(), self.var.clone()])
+    }
+
+    /// Computes `self / rhs` without checking that `rhs` is non-zero.
+    ///
+    /// If both values are zero, the result is zero.
+    #[track_caller]
+    pub fn div_unchecked(&self, rhs: &impl Variable) -> Self {
+        self.op(OpCode::DivUnchecked, vec![self.var.clone(), rhs.ty()])
+    }
+
+    /// Computes the multiplicative inverse. The circuit is unsatisfiable if `self` is zero.
+    #[track_caller]
+    pub fn inverse(&self) -> Self {
+        self.op(OpCode::Inverse, vec![self.var.clone()])
+    }
+
+    /// Returns whether this element is zero.
+    #[track_caller]
+    pub fn is_zero(&self) -> Boolean<'c> {
+        let res = self.ctx.op(OpCode::IsZero, vec![self.var.clone()]);
+        self.ctx.mark_boolean(res.clone());
+
+        Boolean::new(self.ctx, res)
+    }
+
+    /// Returns whether this element is equal to `rhs`.
+    #[track_caller]
+    pub fn is_equal(&self, rhs: &impl Variable) -> Boolean<'c> {
+        (self - rhs).is_zero()
+    }
+
+    /// Decomposes this element into `n` bits, least significant bit first.
+    ///
+    /// This also constrains the element to `[0, 2^n)`.
+    #[track_caller]
+    pub fn to_bits(&self, n: usize) -> Vec<Boolean<'c>> {
+        self.ctx
+            .to_binary(self.var.clone(), n)
+            .into_iter()
+            .map(|bit| Boolean::new(self.ctx, bit))
+            .collect()
+    }
+
+    /// Converts this element into an `N`-bit unsigned integer.
+    ///
+    /// This constrains the element to `[0, 2^N)`.
+    #[track_caller]
+    pub fn to_uint<const N: usize>(&self) -> UInt<'c, N> {
+        UInt::from_field(self)
+    }
+
+    /// Asserts that this element is equal to `rhs`.
+    #[track_caller]
+    pub fn assert_eq(&self, rhs: &impl Variable) {
+        self.ctx
+            .assert(OpCode::AssertIsEqual, vec![self.var.clone(), rhs.ty()]);
+    }
+
+    /// Asserts that this element is different from `rhs`.
+    #[track_caller]
+    pub fn assert_ne(&self, rhs: &impl Variable) {
+        self.ctx
+            .assert(OpCode::AssertIsDifferent, vec![self.var.clone(), rhs.ty()]);
+    }
+
+    /// Asserts that this element is less than or equal to `bound`.
+    #[track_caller]
+    pub fn assert_le(&self, bound: &impl Variable) {
+        self.ctx.assert(
+            OpCode::AssertIsLessOrEqual,
+            vec![self.var.clone(), bound.ty()],
+        );
+    }
+}
+
+impl Variable for Field<'_> {
+    fn ty(&self) -> VariableType {
+        self.var.clone()
+    }
+}
+
+macro_rules! impl_field_binary_op {
+    ($trait:ident, $method:ident, $op:expr) => {
+        impl<'c, T: Variable> $trait<T> for &Field<'c> {
+            type Output = Field<'c>;
+
+            #[track_caller]
+            fn $method(self, rhs: T) -> Field<'c> {
+                self.op($op, vec![self.var.clone(), rhs.ty()])
+            }
+        }
+
+        impl<'c, T: Variable> $trait<T> for Field<'c> {
+            type Output = Field<'c>;
+
+            #[track_caller]
+            fn $method(self, rhs: T) -> Field<'c> {
+                (&self).$method(rhs)
+            }
+        }
+    };
+}
+
+impl_field_binary_op!(Add, add, OpCode::Add);
+impl_field_binary_op!(Sub, sub, OpCode::Sub);
+impl_field_binary_op!(Mul, mul, OpCode::Mul);
+impl_field_binary_op!(Div, div, OpCode::Div);
+
+impl<'c> Neg for &Field<'c> {
+    type Output = Field<'c>;
+
+    #[track_caller]
+    fn neg(self) -> Field<'c> {
+        self.op(OpCode::Neg, vec![self.var.clone()])
+    }
+}
+
+impl<'c> Neg for Field<'c> {
+    type Output = Field<'c>;
+
+    #[track_caller]
+    fn neg(self) -> Field<'c> {
+        -&self
+    }
+}
@@ -0,0 +1,83 @@
+//! Typed front end for writing circuits.
+//!
+//! Instead of calling [`API`](crate::API) methods on untyped
+//! [`VariableType`](crate::types::VariableType)s, circuits can wrap their inputs
+//! into [`Boolean`], [`UInt`] and [`Field`] through a [`Context`] and use
+//! overloaded operators:
+//!
+//! - [`Field`] supports `+`, `-`, `*`, `/` and unary `-`.
+//! - [`UInt`] supports wrapping `+`, `-`, `*`, bitwise `&`, `|`, `^`, `!`, and
+//!   shifts by a constant amount.
+//! - [`Boolean`] supports logical `&`, `|`, `^` and `!`.
+//!
+//! All typed variables implement [`Variable`](crate::Variable), so they can be
+//! mixed freely with the untyped API.
+
+mod context;
+pub use context::*;
+
+mod boolean;
+pub use boolean::*;
+
+mod field;
+pub use field::*;
+
+mod uint;
+pub use uint::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ProvingSystem, Variable,
+        curve::BN254,
+        test_builder,
+        types::{OpCode, VariableType},
+    };
+
+    #[test]
+    fn test_boolean_tracking() {
+        let mut builder = test_builder::<BN254>(ProvingSystem::Groth16);
+        let x = builder.variable_initer_mut().new_private();
+
+        {
+            let ctx = Context::new(&mut builder);
+
+            let a = ctx.uint::<8>(&x);
+            let b = ctx.uint::<8>(&0x0fu8);
+
+            for bit in (&a ^ &b).to_bits() {
+                ctx.assert_is_boolean(&bit);
+            }
+            ctx.boolean(&x).assert_true();
+            ctx.assert_is_boolean(&x);
+        }
+
+        let ops = builder.build().operations;
+        let count = |op: OpCode| ops.iter().filter(|o| o.op == op).count();
+
+        assert_eq!(count(OpCode::ToBinary), 1);
+        assert_eq!(count(OpCode::Xor), 8);
+        assert_eq!(count(OpCode::FromBinary), 1);
+        assert_eq!(count(OpCode::AssertIsBoolean), 1);
+    }
+
+    #[test]
+    fn test_constant_uint() {
+        let mut builder = test_builder::<BN254>(ProvingSystem::Groth16);
+
+        {
+            let ctx = Context::new(&mut builder);
+            let a = ctx.uint::<8>(&0b1001u8);
+
+            assert_eq!((&a << 2).ty(), VariableType::Constant(0b100100.into()));
+            assert_eq!((&a >> 3).ty(), VariableType::Constant(1.into()));
+            assert_eq!(
+                a.rotate_right(1).ty(),
+                VariableType::Constant(0b10000100.into())
+            );
+        }
+
+        assert!(builder.build().operations.is_empty());
+    }
+}
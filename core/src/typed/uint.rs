@@ -0,0 +1,318 @@
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Not, Shl, Shr, Sub};
+
+use num::{BigInt, One, Signed, Zero};
+
+use crate::{
+    Variable,
+    typed::{Boolean, Field, Recorder},
+    types::{OpCode, VariableType},
+};
+
+/// A typed `N`-bit unsigned integer.
+///
+/// A `UInt` always carries its bit decomposition, so bit-level operations
+/// (`&`, `|`, `^`, `!`, `<<`, `>>` and rotations) only record per-bit operations and
+/// a single recomposition. Arithmetic through `+`, `-` and `*` wraps around at `2^N`,
+/// like the `wrapping_*` methods of Rust integers.
+///
+/// Wrapping multiplication decomposes a `2N`-bit product, so it requires `2N` to be
+/// smaller than the bit length of the field modulus.
+#[derive(Clone)]
+pub struct UInt<'c, const N: usize> {
+    ctx: &'c dyn Recorder,
+    var: VariableType,
+    bits: Vec<VariableType>,
+}
+
+impl<'c, const N: usize> UInt<'c, N> {
+    /// Converts a field element, constraining it to `[0, 2^N)`.
+    ///
+    /// # Panics
+    /// Panics if the element is a constant that does not fit into `N` bits.
+    #[track_caller]
+    pub(crate) fn from_field(x: &Field<'c>) -> Self {
+        let ctx = x.ctx();
+        let var = x.variable().clone();
+
+        let bits = if let VariableType::Constant(v) = &var {
+            constant_bits(v, N).unwrap_or_else(|| panic!("constant {v} does not fit into {N} bits"))
+        } else {
+            ctx.to_binary(var.clone(), N)
+        };
+
+        Self { ctx, var, bits }
+    }
+
+    #[track_caller]
+    fn from_bits(ctx: &'c dyn Recorder, bits: Vec<VariableType>) -> Self {
+        let constant = bits
+            .iter()
+            .rev()
+            .try_fold(BigInt::zero(), |acc, bit| match bit {
+                VariableType::Constant(v) => Some((acc << 1) + v),
+                _ => None,
+            });
+
+        let var = match constant {
+            Some(v) => VariableType::Constant(v),
+            None => ctx.op(OpCode::FromBinary, bits.clone()),
+        };
+
+        Self { ctx, var, bits }
+    }
+
+    /// Keeps the lowest `N` bits of `x`, which must fit into `width` bits.
+    #[track_caller]
+    fn truncate(ctx: &'c dyn Recorder, x: VariableType, width: usize) -> Self {
+        assert!(
+            (width as u64) < ctx.field_bits(),
+            "UInt<{N}> needs {width} bits, which exceeds the capacity of the field"
+        );
+
+        let mut bits = ctx.to_binary(x, width);
+        bits.truncate(N);
+
+        Self::from_bits(ctx, bits)
+    }
+
+    /// Returns the underlying variable.
+    pub fn variable(&self) -> &VariableType {
+        &self.var
+    }
+
+    /// Returns this integer as a field element.
+    pub fn as_field(&self) -> Field<'c> {
+        Field::new(self.ctx, self.var.clone())
+    }
+
+    /// Returns the bits of this integer, least significant bit first.
+    pub fn to_bits(&self) -> Vec<Boolean<'c>> {
+        self.bits
+            .iter()
+            .map(|bit| Boolean::new(self.ctx, bit.clone()))
+            .collect()
+    }
+
+    /// Computes `self + rhs` modulo `2^N`.
+    #[track_caller]
+    pub fn wrapping_add(&self, rhs: &UInt<'c, N>) -> Self {
+        let sum = self
+            .ctx
+            .op(OpCode::Add, vec![self.var.clone(), rhs.var.clone()]);
+
+        Self::truncate(self.ctx, sum, N + 1)
+    }
+
+    /// Computes `self - rhs` modulo `2^N`.
+    #[track_caller]
+    pub fn wrapping_sub(&self, rhs: &UInt<'c, N>) -> Self {
+        let shifted = self.ctx.op(
+            OpCode::Add,
+            vec![self.var.clone(), VariableType::Constant(BigInt::one() << N)],
+        );
+        let diff = self.ctx.op(OpCode::Sub, vec![shifted, rhs.var.clone()]);
+
+        Self::truncate(self.ctx, diff, N + 1)
+    }
+
+    /// Computes `self * rhs` modulo `2^N`.
+    #[track_caller]
+    pub fn wrapping_mul(&self, rhs: &UInt<'c, N>) -> Self {
+        let product = self
+            .ctx
+            .op(OpCode::Mul, vec![self.var.clone(), rhs.var.clone()]);
+
+        Self::truncate(self.ctx, product, 2 * N)
+    }
+
+    /// Rotates the bits to the left by `n` positions.
+    #[track_caller]
+    pub fn rotate_left(&self, n: usize) -> Self {
+        let mut bits = self.bits.clone();
+        bits.rotate_right(n % N);
+
+        Self::from_bits(self.ctx, bits)
+    }
+
+    /// Rotates the bits to the right by `n` positions.
+    #[track_caller]
+    pub fn rotate_right(&self, n: usize) -> Self {
+        let mut bits = self.bits.clone();
+        bits.rotate_left(n % N);
+
+        Self::from_bits(self.ctx, bits)
+    }
+
+    /// Shifts the bits to the left by `n` positions, filling with zeros.
+    #[track_caller]
+    pub fn shift_left(&self, n: usize) -> Self {
+        let n = n.min(N);
+
+        let mut bits = vec![VariableType::Constant(BigInt::zero()); n];
+        bits.extend_from_slice(&self.bits[..N - n]);
+
+        Self::from_bits(self.ctx, bits)
+    }
+
+    /// Shifts the bits to the right by `n` positions, filling with zeros.
+    #[track_caller]
+    pub fn shift_right(&self, n: usize) -> Self {
+        let n = n.min(N);
+
+        let mut bits = self.bits[n..].to_vec();
+        bits.resize(N, VariableType::Constant(BigInt::zero()));
+
+        Self::from_bits(self.ctx, bits)
+    }
+
+    /// Returns whether this integer is equal to `rhs`.
+    #[track_caller]
+    pub fn is_equal(&self, rhs: &UInt<'c, N>) -> Boolean<'c> {
+        self.as_field().is_equal(rhs)
+    }
+
+    /// Asserts that this integer is equal to `rhs`.
+    #[track_caller]
+    pub fn assert_eq(&self, rhs: &impl Variable) {
+        self.as_field().assert_eq(rhs)
+    }
+
+    #[track_caller]
+    fn bitwise(&self, rhs: &UInt<'c, N>, op: OpCode) -> Self {
+        let mut bits = Vec::with_capacity(N);
+
+        // A loop instead of an iterator, so that `#[track_caller]` reaches the ops.
+        for (a, b) in self.bits.iter().zip(&rhs.bits) {
+            let res = self.ctx.op(op, vec![a.clone(), b.clone()]);
+            self.ctx.mark_boolean(res.clone());
+            bits.push(res);
+        }
+
+        Self::from_bits(self.ctx, bits)
+    }
+}
+
+impl<const N: usize> Variable for UInt<'_, N> {
+    fn ty(&self) -> VariableType {
+        self.var.clone()
+    }
+}
+
+fn constant_bits(v: &BigInt, n: usize) -> Option<Vec<VariableType>> {
+    if v.is_negative() || v.bits() > n as u64 {
+        return None;
+    }
+
+    let bits = (0..n)
+        .map(|i| VariableType::Constant(BigInt::from(v.bit(i as u64) as u8)))
+        .collect();
+
+    Some(bits)
+}
+
+macro_rules! impl_uint_binary_op {
+    ($trait:ident, $method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<'c, const N: usize> $trait<&UInt<'c, N>> for &UInt<'c, N> {
+            type Output = UInt<'c, N>;
+
+            #[track_caller]
+            fn $method(self, rhs: &UInt<'c, N>) -> UInt<'c, N> {
+                let ($a, $b) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<'c, const N: usize> $trait<UInt<'c, N>> for &UInt<'c, N> {
+            type Output = UInt<'c, N>;
+
+            #[track_caller]
+            fn $method(self, rhs: UInt<'c, N>) -> UInt<'c, N> {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<'c, const N: usize> $trait<&UInt<'c, N>> for UInt<'c, N> {
+            type Output = UInt<'c, N>;
+
+            #[track_caller]
+            fn $method(self, rhs: &UInt<'c, N>) -> UInt<'c, N> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<'c, const N: usize> $trait<UInt<'c, N>> for UInt<'c, N> {
+            type Output = UInt<'c, N>;
+
+            #[track_caller]
+            fn $method(self, rhs: UInt<'c, N>) -> UInt<'c, N> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_uint_binary_op!(Add, add, |a, b| a.wrapping_add(b));
+impl_uint_binary_op!(Sub, sub, |a, b| a.wrapping_sub(b));
+impl_uint_binary_op!(Mul, mul, |a, b| a.wrapping_mul(b));
+impl_uint_binary_op!(BitAnd, bitand, |a, b| a.bitwise(b, OpCode::And));
+impl_uint_binary_op!(BitOr, bitor, |a, b| a.bitwise(b, OpCode::Or));
+impl_uint_binary_op!(BitXor, bitxor, |a, b| a.bitwise(b, OpCode::Xor));
+
+impl<'c, const N: usize> Not for &UInt<'c, N> {
+    type Output = UInt<'c, N>;
+
+    #[track_caller]
+    fn not(self) -> UInt<'c, N> {
+        let mut bits = Vec::with_capacity(N);
+        for bit in self.to_bits() {
+            bits.push((!bit).ty());
+        }
+
+        UInt::from_bits(self.ctx, bits)
+    }
+}
+
+impl<'c, const N: usize> Not for UInt<'c, N> {
+    type Output = UInt<'c, N>;
+
+    #[track_caller]
+    fn not(self) -> UInt<'c, N> {
+        !&self
+    }
+}
+
+impl<'c, const N: usize> Shl<usize> for &UInt<'c, N> {
+    type Output = UInt<'c, N>;
+
+    #[track_caller]
+    fn shl(self, n: usize) -> UInt<'c, N> {
+        self.shift_left(n)
+    }
+}
+
+impl<'c, const N: usize> Shl<usize> for UInt<'c, N> {
+    type Output = UInt<'c, N>;
+
+    #[track_caller]
+    fn shl(self, n: usize) -> UInt<'c, N> {
+        &self << n
+    }
+}
+
+impl<'c, const N: usize> Shr<usize> for &UInt<'c, N> {
+    type Output = UInt<'c, N>;
+
+    #[track_caller]
+    fn shr(self, n: usize) -> UInt<'c, N> {
+        self.shift_right(n)
+    }
+}
+
+impl<'c, const N: usize> Shr<usize> for UInt<'c, N> {
+    type Output = UInt<'c, N>;
+
+    #[track_caller]
+    fn shr(self, n: usize) -> UInt<'c, N> {
+        &self >> n
+    }
+}
@@ -2,18 +2,60 @@
 //!
 //! Note: These types are used for build prover, so they are not part of the public API.
 
-use num::BigInt;
+use std::{collections::HashMap, fmt, panic::Location};
+
+use num::{BigInt, Integer};
 use serde::{Deserialize, Serialize};
 use serde_with::{DisplayFromStr, serde_as};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CircuitDefinition {
+    /// Version of the serialized form, see [`crate::schema`].
+    ///
+    /// Definitions serialized before versioning was introduced deserialize as version 0.
+    #[serde(default)]
+    pub schema_version: u32,
     pub private_len: u64,
     pub public_len: u64,
     pub local_len: u64,
     pub operations: Vec<Operation>,
 }
 
+impl CircuitDefinition {
+    /// Renumbers local variables so that they are dense, in order of definition.
+    ///
+    /// Locals that are never written by an operation are removed, and
+    /// `local_len` is updated accordingly.
+    pub fn compact_locals(&mut self) {
+        let mut mapping = HashMap::new();
+
+        for operation in &self.operations {
+            for output in &operation.outputs {
+                if let VariableType::Local(idx) = output {
+                    let next = mapping.len() as u64;
+                    mapping.entry(*idx).or_insert(next);
+                }
+            }
+        }
+
+        for operation in &mut self.operations {
+            for var in operation
+                .inputs
+                .iter_mut()
+                .chain(operation.outputs.iter_mut())
+            {
+                if let VariableType::Local(idx) = var
+                    && let Some(new_idx) = mapping.get(idx)
+                {
+                    *idx = *new_idx;
+                }
+            }
+        }
+
+        self.local_len = mapping.len() as u64;
+    }
+}
+
 /// Enumeration of all supported circuit operations.
 ///
 /// This enum defines the complete set of operations that can be performed
@@ -54,9 +96,15 @@ pub struct CircuitDefinition {
 /// - [`AssertIsCrumb`](OpCode::AssertIsCrumb): Assert value is a 2-bit value (0, 1, 2, or 3)
 /// - [`AssertIsLessOrEqual`](OpCode::AssertIsLessOrEqual): Assert first value ≤ second value
 ///
+/// ## Recursion
+/// - [`AssertGroth16Proof`](OpCode::AssertGroth16Proof): Assert a Groth16 proof is valid,
+///   see [`recursion::groth16`](crate::recursion::groth16)
+/// - [`AssertPlonkProof`](OpCode::AssertPlonkProof): Assert a PLONK proof is valid,
+///   see [`recursion::plonk`](crate::recursion::plonk)
+///
 /// ## Debug Operations
 /// - [`Println`](OpCode::Println): Print value for debugging (backend-dependent)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum OpCode {
     Add,
@@ -81,9 +129,98 @@ pub enum OpCode {
     AssertIsBoolean,
     AssertIsCrumb,
     AssertIsLessOrEqual,
+    AssertGroth16Proof,
+    AssertPlonkProof,
     Println,
 }
 
+impl OpCode {
+    /// Returns `true` if this operation is an explicit assertion.
+    pub fn is_assertion(&self) -> bool {
+        matches!(
+            self,
+            OpCode::AssertIsEqual
+                | OpCode::AssertIsDifferent
+                | OpCode::AssertIsBoolean
+                | OpCode::AssertIsCrumb
+                | OpCode::AssertIsLessOrEqual
+                | OpCode::AssertGroth16Proof
+                | OpCode::AssertPlonkProof
+        )
+    }
+
+    /// All operations, in declaration order.
+    pub const ALL: [OpCode; 25] = [
+        OpCode::Add,
+        OpCode::MulAcc,
+        OpCode::Neg,
+        OpCode::Sub,
+        OpCode::Mul,
+        OpCode::DivUnchecked,
+        OpCode::Div,
+        OpCode::Inverse,
+        OpCode::ToBinary,
+        OpCode::FromBinary,
+        OpCode::Xor,
+        OpCode::Or,
+        OpCode::And,
+        OpCode::Select,
+        OpCode::Lookup2,
+        OpCode::IsZero,
+        OpCode::Cmp,
+        OpCode::AssertIsEqual,
+        OpCode::AssertIsDifferent,
+        OpCode::AssertIsBoolean,
+        OpCode::AssertIsCrumb,
+        OpCode::AssertIsLessOrEqual,
+        OpCode::AssertGroth16Proof,
+        OpCode::AssertPlonkProof,
+        OpCode::Println,
+    ];
+
+    /// Returns the name of this operation, as used by serde and the textual IR.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpCode::Add => "add",
+            OpCode::MulAcc => "mul_acc",
+            OpCode::Neg => "neg",
+            OpCode::Sub => "sub",
+            OpCode::Mul => "mul",
+            OpCode::DivUnchecked => "div_unchecked",
+            OpCode::Div => "div",
+            OpCode::Inverse => "inverse",
+            OpCode::ToBinary => "to_binary",
+            OpCode::FromBinary => "from_binary",
+            OpCode::Xor => "xor",
+            OpCode::Or => "or",
+            OpCode::And => "and",
+            OpCode::Select => "select",
+            OpCode::Lookup2 => "lookup2",
+            OpCode::IsZero => "is_zero",
+            OpCode::Cmp => "cmp",
+            OpCode::AssertIsEqual => "assert_is_equal",
+            OpCode::AssertIsDifferent => "assert_is_different",
+            OpCode::AssertIsBoolean => "assert_is_boolean",
+            OpCode::AssertIsCrumb => "assert_is_crumb",
+            OpCode::AssertIsLessOrEqual => "assert_is_less_or_equal",
+            OpCode::AssertGroth16Proof => "assert_groth16_proof",
+            OpCode::AssertPlonkProof => "assert_plonk_proof",
+            OpCode::Println => "println",
+        }
+    }
+}
+
+impl std::str::FromStr for OpCode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OpCode::ALL
+            .into_iter()
+            .find(|op| op.as_str() == s)
+            .ok_or(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Operation {
     pub op: OpCode,
@@ -91,10 +228,67 @@ pub struct Operation {
     pub inputs: Vec<VariableType>,
     #[serde(rename = "out")]
     pub outputs: Vec<VariableType>,
+    /// The circuit code that recorded this operation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+    /// The namespaces active when this operation was recorded, outermost first.
+    #[serde(rename = "ns", default, skip_serializing_if = "Vec::is_empty")]
+    pub namespace: Vec<String>,
+}
+
+impl Operation {
+    /// Creates an operation without source location or namespace.
+    pub fn new(op: OpCode, inputs: Vec<VariableType>, outputs: Vec<VariableType>) -> Self {
+        Self {
+            op,
+            inputs,
+            outputs,
+            span: None,
+            namespace: Vec::new(),
+        }
+    }
+
+    /// Returns a human readable label of where this operation comes from.
+    ///
+    /// The label has the form `outer/inner at file:line:column`. Either part is
+    /// omitted when unknown, so the label may be empty.
+    pub fn label(&self) -> String {
+        let namespace = self.namespace.join("/");
+
+        match (&self.span, namespace.is_empty()) {
+            (Some(span), true) => span.to_string(),
+            (Some(span), false) => format!("{namespace} at {span}"),
+            (None, _) => namespace,
+        }
+    }
+}
+
+/// A location in the circuit source code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Span {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl From<&Location<'_>> for Span {
+    fn from(location: &Location<'_>) -> Self {
+        Self {
+            file: location.file().to_string(),
+            line: location.line(),
+            column: location.column(),
+        }
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
 }
 
 #[serde_as]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "t", content = "v")]
 pub enum VariableType {
@@ -154,6 +348,17 @@ impl Witness {
     pub fn private(&self) -> &[BigInt] {
         &self.private
     }
+
+    /// Reduces every value into `[0, p)`, like [`Fr::new`](crate::Fr::new).
+    ///
+    /// Witness values are pushed as given by the circuit types, so `U256`
+    /// values may exceed the field and `BigInt` or signed integers may be
+    /// negative. Provers reduce them modulo the field of their backend.
+    pub fn reduce(&mut self, p: &BigInt) {
+        for value in self.public.iter_mut().chain(&mut self.private) {
+            *value = value.mod_floor(p);
+        }
+    }
 }
 
 #[serde_as]
@@ -177,4 +382,11 @@ impl PublicWitness {
     pub fn public_mut(&mut self) -> &mut Vec<BigInt> {
         &mut self.public
     }
+
+    /// Reduces every value into `[0, p)`, see [`Witness::reduce`].
+    pub fn reduce(&mut self, p: &BigInt) {
+        for value in &mut self.public {
+            *value = value.mod_floor(p);
+        }
+    }
 }
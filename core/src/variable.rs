@@ -16,6 +16,11 @@
 //! - Unsigned integers: `u8`, `u16`, `u32`, `u64`, `u128`
 //! - Signed integers: `i8`, `i16`, `i32`, `i64`, `i128`  
 //! - Boolean: `bool`
+//! - Large integers: [`U256`], [`BigInt`]
+//! - Field elements: [`Fr<C>`](crate::Fr)
+//!
+//! Constants are passed to the backend as-is and reduced modulo the field of the
+//! backend. Use [`Fr<C>`](crate::Fr) when the value must be reduced explicitly.
 //!
 //! # Usage in Circuits
 //!
@@ -33,8 +38,9 @@
 use std::marker::PhantomData;
 
 use num::BigInt;
+use ruint::aliases::U256;
 
-use crate::types::VariableType;
+use crate::{Fr, types::VariableType};
 
 /// A trait that defines types that can be used as API parameters in circuit construction.
 ///
@@ -53,6 +59,12 @@ impl Variable for VariableType {
     }
 }
 
+impl<T: Variable + ?Sized> Variable for &T {
+    fn ty(&self) -> VariableType {
+        (**self).ty()
+    }
+}
+
 pub struct CircuitVariable<T> {
     variable: VariableType,
     marker: PhantomData<T>,
@@ -112,3 +124,16 @@ define_variable_for_from_u256!(i32);
 define_variable_for_from_u256!(i16);
 define_variable_for_from_u256!(i8);
 define_variable_for_from_u256!(bool);
+define_variable_for_from_u256!(U256);
+
+impl Variable for BigInt {
+    fn ty(&self) -> VariableType {
+        VariableType::Constant(self.clone())
+    }
+}
+
+impl<C> Variable for Fr<C> {
+    fn ty(&self) -> VariableType {
+        VariableType::Constant(self.value().clone())
+    }
+}
@@ -5,6 +5,7 @@ use syn::{ItemStruct, Visibility};
 pub fn generate_circuit_impl(input: &ItemStruct) -> syn::Result<TokenStream> {
     let name = &input.ident;
     let name_str = name.to_string().to_lowercase();
+    let name_ident_str = name.to_string();
 
     let generics = &input.generics;
 
@@ -165,6 +166,17 @@ pub fn generate_circuit_impl(input: &ItemStruct) -> syn::Result<TokenStream> {
                         #(#field_names,)*
                     }
                 }
+
+                /// Defines this circuit inside a namespace named after the struct.
+                ///
+                /// This shadows [`Circuit::define`](::rsnark_core::Circuit::define), so
+                /// operations of sub-circuits are labeled with their struct name.
+                pub fn define<__RsnarkApi: ::rsnark_core::API>(&self, api: &mut __RsnarkApi)
+                where
+                    Self: ::rsnark_core::DefineInScope<__RsnarkApi>,
+                {
+                    ::rsnark_core::DefineInScope::define_in_scope(self, #name_ident_str, api)
+                }
             }
 
             impl #generics CircuitElement for #name #generics #where_clause_tokens {
@@ -0,0 +1,39 @@
+use rsnark_core::{
+    API, Circuit, CircuitBuilder, CircuitElement, CircuitWitness, CurveId, MetadataInfo,
+    ProvingSystem, circuit, curve::BN254, variable::CircuitVariable,
+};
+
+// The parameter is named like the API parameter of the generated `define`.
+#[circuit]
+pub struct Pair<A> {
+    a: A,
+    pub b: A,
+}
+
+impl<A> Circuit for Pair<A>
+where
+    A: CircuitElement,
+    A::CircuitWitness: CircuitWitness<CircuitElement = CircuitVariable<A>>,
+{
+    fn define(&self, api: &mut impl API) {
+        api.assert_is_equal(&self.a, &self.b);
+    }
+}
+
+#[test]
+fn test_generic_named_like_api() {
+    let mut builder = CircuitBuilder::new(MetadataInfo {
+        field: BN254::field(),
+        curve: BN254::curve_type(),
+        proving_system: ProvingSystem::Groth16,
+    });
+    let circuit = <Pair<u32> as CircuitElement>::CircuitWitness::create_public(
+        builder.variable_initer_mut(),
+        false,
+    );
+    circuit.define(&mut builder);
+
+    let define = builder.build();
+    assert_eq!(define.operations.len(), 1);
+    assert_eq!(define.operations[0].namespace, ["Pair"]);
+}
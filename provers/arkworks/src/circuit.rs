@@ -0,0 +1,49 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystemRef, LinearCombination, SynthesisError, Variable,
+};
+use rsnark_core::{BigInt, r1cs::R1cs};
+
+use crate::curve::to_field;
+
+/// Feeds a native [`R1cs`] to arkworks.
+///
+/// Wire `0` maps to [`Variable::One`], public inputs to instance variables and
+/// all other wires to witness variables, in order. Without an assignment, only
+/// the shape of the constraint system is generated, as needed by the setup.
+pub(crate) struct R1csCircuit<'a> {
+    pub r1cs: &'a R1cs,
+    pub assignment: Option<&'a [BigInt]>,
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for R1csCircuit<'_> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let value = |wire: usize| {
+            self.assignment
+                .map(|assignment| to_field(&assignment[wire]))
+                .ok_or(SynthesisError::AssignmentMissing)
+        };
+
+        let mut variables = vec![Variable::One];
+        for wire in 1..=self.r1cs.public_len {
+            variables.push(cs.new_input_variable(|| value(wire))?);
+        }
+        for wire in 1 + self.r1cs.public_len..self.r1cs.num_wires() {
+            variables.push(cs.new_witness_variable(|| value(wire))?);
+        }
+
+        let lc = |lc: &rsnark_core::r1cs::LinearCombination| {
+            LinearCombination(
+                lc.0.iter()
+                    .map(|(wire, coeff)| (to_field(coeff), variables[*wire]))
+                    .collect(),
+            )
+        };
+
+        for constraint in &self.r1cs.constraints {
+            cs.enforce_constraint(lc(&constraint.a), lc(&constraint.b), lc(&constraint.c))?;
+        }
+
+        Ok(())
+    }
+}
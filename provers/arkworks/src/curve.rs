@@ -0,0 +1,43 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use num::{BigInt, bigint::Sign};
+use rsnark_core::{
+    CurveId,
+    curve::{BLS12_381, BN254},
+};
+
+/// A curve supported by the arkworks backend.
+///
+/// The scalar field of [`ArkCurve::Engine`] must be the field of the circuit,
+/// as returned by [`CurveId::field`].
+pub trait ArkCurve: CurveId {
+    /// The arkworks pairing engine of this curve.
+    type Engine: Pairing;
+}
+
+impl ArkCurve for BN254 {
+    type Engine = ark_bn254::Bn254;
+}
+
+impl ArkCurve for BLS12_381 {
+    type Engine = ark_bls12_381::Bls12_381;
+}
+
+/// The scalar field of the pairing engine of `C`.
+pub(crate) type ScalarField<C> = <<C as ArkCurve>::Engine as Pairing>::ScalarField;
+
+/// Converts `value` into a field element, reducing it modulo the field.
+pub(crate) fn to_field<F: PrimeField>(value: &BigInt) -> F {
+    let (sign, bytes) = value.to_bytes_le();
+    let res = F::from_le_bytes_mod_order(&bytes);
+
+    if sign == Sign::Minus { -res } else { res }
+}
+
+/// Returns the modulus of the field `F`.
+#[cfg(test)]
+pub(crate) fn modulus<F: PrimeField>() -> BigInt {
+    use ark_ff::BigInteger;
+
+    BigInt::from_bytes_le(Sign::Plus, &F::MODULUS.to_bytes_le())
+}
@@ -0,0 +1,26 @@
+use rsnark_core::{
+    fingerprint::FingerprintError,
+    r1cs::{CompileError, SolveError},
+};
+
+/// Error types for arkworks backend operations.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SolveError(#[from] SolveError),
+
+    #[error(transparent)]
+    SynthesisError(#[from] ark_relations::r1cs::SynthesisError),
+
+    #[error(transparent)]
+    SerializationError(#[from] ark_serialize::SerializationError),
+
+    #[error(transparent)]
+    FingerprintError(#[from] FingerprintError),
+}
+
+/// Convenience type alias for Results with arkworks Error.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,408 @@
+use std::marker::PhantomData;
+
+use ark_groth16::Groth16;
+use ark_snark::{CircuitSpecificSetupSNARK, SNARK};
+use ark_std::rand::rngs::OsRng;
+use rsnark_core::{
+    MetadataInfo, ProvingSystem,
+    r1cs::R1cs,
+    stats::ConstraintStats,
+    types::{CircuitDefinition, PublicWitness, Witness},
+};
+use rsnark_provers_core::Backend;
+
+use crate::{
+    ArkCurve, Error, Result,
+    circuit::R1csCircuit,
+    curve::{ScalarField, to_field},
+    types::{Groth16Proof, Groth16ProvingKey, Groth16VerifyingKey},
+};
+
+/// Groth16 backend implementation using arkworks.
+///
+/// Circuits are compiled to a native [`R1cs`], which is also used to compute
+/// the full assignment of a witness. Proving fails with [`Error::SolveError`]
+/// if the witness does not satisfy the circuit.
+///
+/// # Type Parameters
+///
+/// * `C` - The elliptic curve type that implements [`ArkCurve`]
+pub struct Groth16Backend<C> {
+    marker: PhantomData<C>,
+}
+
+impl<C> Clone for Groth16Backend<C> {
+    fn clone(&self) -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Backend for Groth16Backend<C>
+where
+    C: ArkCurve,
+{
+    type CircuitConstraint = R1cs;
+    type ProvingKey = Groth16ProvingKey<C>;
+    type VerifyingKey = Groth16VerifyingKey<C>;
+    type Proof = Groth16Proof<C>;
+
+    type Config = ();
+
+    type Error = Error;
+
+    fn with_config(_config: ()) -> Result<Self> {
+        Ok(Self {
+            marker: PhantomData,
+        })
+    }
+
+    fn metadata(&self) -> MetadataInfo {
+        MetadataInfo {
+            field: C::field(),
+            curve: C::curve_type(),
+            proving_system: ProvingSystem::Groth16,
+        }
+    }
+
+    fn compile(&self, circuit: &CircuitDefinition) -> Result<Self::CircuitConstraint> {
+        Ok(R1cs::compile(circuit, &C::field())?)
+    }
+
+    fn constraint_stats(&self, r1cs: &Self::CircuitConstraint) -> Result<Option<ConstraintStats>> {
+        Ok(Some(r1cs.stats()))
+    }
+
+    fn setup(
+        &self,
+        r1cs: &Self::CircuitConstraint,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let circuit = R1csCircuit {
+            r1cs,
+            assignment: None,
+        };
+        let (pk, vk) = Groth16::<C::Engine>::setup(circuit, &mut OsRng)?;
+
+        Ok((Groth16ProvingKey(pk), Groth16VerifyingKey(vk)))
+    }
+
+    fn prove(
+        &self,
+        r1cs: &Self::CircuitConstraint,
+        pk: &Self::ProvingKey,
+        witness: &Witness,
+    ) -> Result<Self::Proof> {
+        let assignment = r1cs.solve(witness)?;
+        r1cs.check(&assignment)?;
+
+        let circuit = R1csCircuit {
+            r1cs,
+            assignment: Some(&assignment),
+        };
+        let proof = Groth16::<C::Engine>::prove(&pk.0, circuit, &mut OsRng)?;
+
+        Ok(Groth16Proof(proof))
+    }
+
+    fn verify(
+        &self,
+        vk: &Self::VerifyingKey,
+        proof: &Self::Proof,
+        public_witness: &PublicWitness,
+    ) -> Result<bool> {
+        let inputs: Vec<ScalarField<C>> = public_witness.public.iter().map(to_field).collect();
+
+        Ok(Groth16::<C::Engine>::verify(&vk.0, &inputs, &proof.0)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnark_core::{
+        API, BigInt, Circuit, CircuitWitness, CurveId, Fr, U256, Witness, circuit,
+        curve::{BLS12_381, BN254},
+        r1cs::SolveError,
+        typed::Context,
+        types,
+    };
+    use rsnark_provers_core::{
+        InvalidProof, Prover,
+        test_circuits::{RangeCircuit, TestCircuit},
+    };
+
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+
+    use crate::curve::modulus;
+
+    fn run<C: ArkCurve>() {
+        assert_eq!(modulus::<ScalarField<C>>(), C::field());
+
+        let prover: Prover<Groth16Backend<C>> = Prover::new();
+
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<TestCircuit> {
+            a: 3,
+            b: 4,
+            c: 7, // 3 + 4 = 7
+        };
+
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+
+        let public_witness = circuit_witness.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+
+        let mut wrong_public = types::PublicWitness::new();
+        wrong_public.public_mut().push(8.into());
+        let backend = Groth16Backend::<C>::new();
+        assert!(!backend.verify(&vk, &proof, &wrong_public).unwrap());
+
+        let wrong = Witness::<TestCircuit> { a: 3, b: 4, c: 8 };
+        let err = circuit_prover.prove(&pk, &wrong).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::SolveError(SolveError::Unsatisfied { .. }))
+        ));
+
+        let err = circuit_prover
+            .verify(&vk, &proof, wrong.into_public_witness())
+            .unwrap_err();
+        assert!(err.is::<InvalidProof>());
+    }
+
+    #[circuit]
+    pub struct WideCircuit {
+        a: U256,
+        b: BigInt,
+        pub c: Fr<BN254>,
+    }
+
+    impl Circuit for WideCircuit {
+        fn define(&self, api: &mut impl API) {
+            let c = api.add(&self.a, &self.b);
+            api.assert_is_equal(&c, &self.c);
+        }
+    }
+
+    #[test]
+    fn test_wide_witness_types() {
+        let prover: Prover<Groth16Backend<BN254>> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<WideCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        // `a` exceeds the field and `b` is negative, both are reduced before proving
+        let p = U256::try_from(BN254::field()).unwrap();
+        let circuit_witness = Witness::<WideCircuit> {
+            a: p + U256::from(5),
+            b: BigInt::from(-2),
+            c: Fr::new(3),
+        };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+
+        let mut public = types::PublicWitness::new();
+        public.public_mut().push(3.into());
+        let backend = Groth16Backend::<BN254>::new();
+        assert!(backend.verify(&vk, &proof, &public).unwrap());
+    }
+
+    #[circuit]
+    pub struct UIntCircuit {
+        a: u8,
+        b: u8,
+        pub c: u8,
+    }
+
+    impl Circuit for UIntCircuit {
+        fn define(&self, api: &mut impl API) {
+            let ctx = Context::new(api);
+            let a = ctx.uint::<8>(&self.a);
+            let b = ctx.uint::<8>(&self.b);
+
+            (&(&a + &b) ^ &b).assert_eq(&self.c);
+        }
+    }
+
+    #[test]
+    fn test_typed_uint() {
+        let prover: Prover<Groth16Backend<BN254>> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<UIntCircuit>().unwrap();
+        let (pk, _) = circuit_prover.setup().unwrap();
+
+        // (200 + 100) mod 256 = 44, and 44 ^ 100 = 72
+        let witness = Witness::<UIntCircuit> {
+            a: 200,
+            b: 100,
+            c: 72,
+        };
+        circuit_prover.prove(&pk, &witness).unwrap();
+
+        let wrong = Witness::<UIntCircuit> {
+            a: 200,
+            b: 100,
+            c: 44,
+        };
+        assert!(circuit_prover.prove(&pk, &wrong).is_err());
+    }
+
+    #[test]
+    fn test_groth16_with_core_prover() {
+        run::<BN254>();
+        run::<BLS12_381>();
+    }
+
+    #[test]
+    fn test_internal_wires() {
+        let prover: Prover<Groth16Backend<BLS12_381>> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<RangeCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<RangeCircuit> { x: 6, y: 5, z: 15 };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+        circuit_prover
+            .verify(&vk, &proof, circuit_witness.into_public_witness())
+            .unwrap();
+
+        let out_of_range = Witness::<RangeCircuit> {
+            x: 256,
+            y: 1,
+            z: 128,
+        };
+        assert!(circuit_prover.prove(&pk, &out_of_range).is_err());
+    }
+
+    #[circuit]
+    pub struct BoundCircuit {
+        x: u32,
+        pub bound: u32,
+    }
+
+    impl Circuit for BoundCircuit {
+        fn define(&self, api: &mut impl API) {
+            api.assert_is_less_or_equal(&self.x, &self.bound);
+        }
+    }
+
+    #[circuit]
+    pub struct DivCircuit {
+        x: u32,
+        y: u32,
+        pub quotient: u32,
+    }
+
+    impl Circuit for DivCircuit {
+        fn define(&self, api: &mut impl API) {
+            let quotient = api.div(&self.x, &self.y);
+            api.assert_is_equal(&quotient, &self.quotient);
+        }
+    }
+
+    /// Whether arkworks accepts `assignment`, as its prover asserts in debug builds.
+    fn ark_satisfied(r1cs: &R1cs, assignment: &[BigInt]) -> bool {
+        let cs = ConstraintSystem::<ScalarField<BN254>>::new_ref();
+        let circuit = R1csCircuit {
+            r1cs,
+            assignment: Some(assignment),
+        };
+        circuit.generate_constraints(cs.clone()).unwrap();
+        cs.is_satisfied().unwrap()
+    }
+
+    #[test]
+    fn test_forged_decomposition() {
+        let prover: Prover<Groth16Backend<BN254>> = Prover::new();
+        let r1cs =
+            R1cs::compile(&prover.define_circuit::<BoundCircuit>(), &BN254::field()).unwrap();
+        let witness = types::Witness::from((vec![4.into()], vec![12.into()]));
+        assert!(!r1cs.is_satisfied(&witness));
+
+        // 12 <= 4 + p, but the bits of 4 + p are not canonical.
+        let p = BN254::field();
+        let forged = r1cs
+            .solve_with_bits(&witness, |x, i| {
+                if *x == BigInt::from(4) {
+                    (x + &p).bit(i)
+                } else {
+                    x.bit(i)
+                }
+            })
+            .unwrap();
+        assert!(!ark_satisfied(&r1cs, &forged));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let prover: Prover<Groth16Backend<BN254>> = Prover::new();
+        let r1cs = R1cs::compile(&prover.define_circuit::<DivCircuit>(), &BN254::field()).unwrap();
+        let circuit_prover = prover.compile_circuit::<DivCircuit>().unwrap();
+        let (pk, _) = circuit_prover.setup().unwrap();
+
+        let zero = Witness::<DivCircuit> {
+            x: 0,
+            y: 0,
+            quotient: 5,
+        };
+        assert!(circuit_prover.prove(&pk, &zero).is_err());
+
+        // 0 * 5 = 0 holds, so the quotient and the inverse of the divisor are forged as 5.
+        let mut forged = [1, 5, 0, 0].map(BigInt::from).to_vec();
+        forged.resize(r1cs.num_wires(), 5.into());
+        assert!(!ark_satisfied(&r1cs, &forged));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let prover: Prover<Groth16Backend<BN254>> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+        let fingerprint = circuit_prover.fingerprint();
+
+        let proof = circuit_prover
+            .prove(&pk, &Witness::<TestCircuit> { a: 3, b: 4, c: 7 })
+            .unwrap();
+
+        let data = vk.serialize_with_fingerprint(fingerprint).unwrap();
+        let vk = Groth16VerifyingKey::deserialize_with_fingerprint(fingerprint, &data).unwrap();
+        let proof = Groth16Proof::deserialize(proof.serialize().unwrap()).unwrap();
+        assert_eq!(
+            Groth16ProvingKey::deserialize(pk.serialize().unwrap()).unwrap(),
+            pk
+        );
+
+        let public_witness = Witness::<TestCircuit> { a: 3, b: 4, c: 7 }.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+    }
+
+    #[test]
+    fn test_dyn_backend() {
+        use rsnark_provers_core::DynBackend;
+
+        let backends: Vec<Box<dyn DynBackend>> = vec![
+            Box::new(Groth16Backend::<BN254>::new()),
+            Box::new(Groth16Backend::<BLS12_381>::new()),
+        ];
+
+        for backend in backends {
+            let definition = rsnark_provers_core::define_circuit::<TestCircuit>(
+                backend.metadata(),
+                Default::default(),
+            );
+            let cs = backend.compile(&definition).unwrap();
+            let (pk, vk) = backend.setup(&cs).unwrap();
+
+            let witness = types::Witness::from((vec![7.into()], vec![3.into(), 4.into()]));
+            let proof = backend.prove(&cs, &pk, &witness).unwrap();
+
+            let mut public = types::PublicWitness::new();
+            public.public_mut().push(7.into());
+            assert!(backend.verify(&vk, &proof, &public).unwrap());
+
+            let mut wrong = types::PublicWitness::new();
+            wrong.public_mut().push(8.into());
+            assert!(!backend.verify(&vk, &proof, &wrong).unwrap());
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! # rsnark-provers-arkworks
+//!
+//! Pure-Rust backend implementation based on [arkworks](https://arkworks.rs).
+//!
+//! This crate proves circuits with `ark-groth16`, without any Go runtime. Circuit
+//! definitions are compiled with the native [`R1cs`](rsnark_core::r1cs::R1cs) lowering
+//! of `rsnark-core`, and the witness is computed by its solver.
+//!
+//! ## Key Components
+//!
+//! - [`Groth16Backend`]: Backend implementation using arkworks' Groth16 prover
+//! - [`ArkCurve`]: Curves supported by this backend, with their arkworks pairing engine
+//! - [`Error`]: Error types for compilation, solving and arkworks operations
+//! - [`types`]: Typed proving keys, verifying keys and proofs with canonical serialization
+//!
+//! ## Supported Curves
+//!
+//! - BN254
+//! - BLS12-381
+
+mod circuit;
+
+mod curve;
+pub use curve::*;
+
+pub mod types;
+
+mod error;
+pub use error::*;
+
+mod groth16;
+pub use groth16::*;
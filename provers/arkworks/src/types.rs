@@ -0,0 +1,98 @@
+//! Typed keys and proofs of the arkworks backend.
+//!
+//! All types serialize with arkworks' compressed canonical encoding, and
+//! deserialization validates that points are on the curve and in the right
+//! subgroup.
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use rsnark_core::fingerprint::{self, Fingerprint};
+use rsnark_provers_core::Artifact;
+
+use crate::{ArkCurve, Error, Result};
+
+macro_rules! impl_ark_object {
+    ($name:ident, $inner:ident) => {
+        impl<C: ArkCurve> $name<C> {
+            /// Returns the underlying arkworks object.
+            pub fn inner(&self) -> &ark_groth16::$inner<C::Engine> {
+                &self.0
+            }
+
+            pub fn into_inner(self) -> ark_groth16::$inner<C::Engine> {
+                self.0
+            }
+
+            pub fn serialize(&self) -> Result<Vec<u8>> {
+                let mut data = Vec::new();
+                self.0.serialize_compressed(&mut data)?;
+                Ok(data)
+            }
+
+            pub fn deserialize(data: Vec<u8>) -> Result<Self> {
+                Ok(Self(CanonicalDeserialize::deserialize_compressed(
+                    data.as_slice(),
+                )?))
+            }
+
+            /// Serializes this object, sealed with the fingerprint of its circuit.
+            pub fn serialize_with_fingerprint(&self, fingerprint: &Fingerprint) -> Result<Vec<u8>> {
+                Ok(fingerprint::seal(fingerprint, &self.serialize()?))
+            }
+
+            /// Deserializes an object sealed by `serialize_with_fingerprint`.
+            ///
+            /// Fails with [`Error::FingerprintError`](crate::Error::FingerprintError)
+            /// if it was sealed for another circuit.
+            pub fn deserialize_with_fingerprint(
+                fingerprint: &Fingerprint,
+                data: &[u8],
+            ) -> Result<Self> {
+                Self::deserialize(fingerprint::open(fingerprint, data)?.to_vec())
+            }
+        }
+
+        impl<C: ArkCurve> Artifact for $name<C> {
+            type Error = Error;
+
+            fn to_bytes(&self) -> Result<Vec<u8>> {
+                self.serialize()
+            }
+
+            fn from_bytes(data: &[u8]) -> Result<Self> {
+                Self::deserialize(data.to_vec())
+            }
+        }
+
+        impl<C: ArkCurve> Clone for $name<C> {
+            fn clone(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+
+        impl<C: ArkCurve> std::fmt::Debug for $name<C> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
+        }
+
+        impl<C: ArkCurve> PartialEq for $name<C> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<C: ArkCurve> Eq for $name<C> {}
+    };
+}
+
+/// Groth16 proving key generated by the arkworks backend.
+pub struct Groth16ProvingKey<C: ArkCurve>(pub(crate) ark_groth16::ProvingKey<C::Engine>);
+impl_ark_object!(Groth16ProvingKey, ProvingKey);
+
+/// Groth16 verifying key generated by the arkworks backend.
+pub struct Groth16VerifyingKey<C: ArkCurve>(pub(crate) ark_groth16::VerifyingKey<C::Engine>);
+impl_ark_object!(Groth16VerifyingKey, VerifyingKey);
+
+/// Groth16 proof generated by the arkworks backend.
+pub struct Groth16Proof<C: ArkCurve>(pub(crate) ark_groth16::Proof<C::Engine>);
+impl_ark_object!(Groth16Proof, Proof);
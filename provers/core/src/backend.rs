@@ -1,5 +1,6 @@
 use rsnark_core::{
     MetadataInfo,
+    stats::ConstraintStats,
     types::{CircuitDefinition, PublicWitness, Witness},
 };
 
@@ -14,12 +15,13 @@ use rsnark_core::{
 /// - `CircuitConstraint`: The internal representation of compiled circuit constraints
 /// - `ProvingKey`: The proving key generated during the trusted setup phase
 /// - `VerifyingKey`: The verifying key generated during the trusted setup phase  
+/// - `Config`: The options of the backend, such as parallelism or setup parameters
 /// - `Error`: The error type for backend-specific operations
 ///
 /// ## Workflow
 ///
 /// The typical workflow when using a backend implementation:
-/// 1. Create a new backend instance with [`Backend::new()`]
+/// 1. Create a new backend instance with [`Backend::new()`] or [`Backend::with_config()`]
 /// 2. Compile the circuit definition with [`Backend::compile()`]
 /// 3. Perform trusted setup with [`Backend::setup()`] to generate keys
 /// 4. Generate proofs with [`Backend::prove()`]
@@ -34,10 +36,29 @@ pub trait Backend {
     /// Error type for backend operations that must implement standard error traits.
     type Error: std::error::Error + Send + Sync + 'static;
 
-    /// Creates a new instance of the backend.
+    /// Options of the backend, see [`Backend::with_config()`].
+    type Config: Default;
+
+    /// Creates a new instance of the backend with the default configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backend rejects its default configuration.
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self::with_config(Self::Config::default())
+            .expect("the backend rejected its default configuration")
+    }
+
+    /// Creates a new instance of the backend with the given configuration.
     ///
-    /// This is typically used to initialize any backend-specific state or configuration.
-    fn new() -> Self;
+    /// This is used to initialize any backend-specific state or configuration,
+    /// and fails if the backend cannot apply `config`.
+    fn with_config(config: Self::Config) -> Result<Self, Self::Error>
+    where
+        Self: Sized;
 
     fn metadata(&self) -> MetadataInfo;
 
@@ -62,6 +83,18 @@ pub trait Backend {
     /// - Internal compilation errors occur
     fn compile(&self, circuit: &CircuitDefinition) -> Result<Self::CircuitConstraint, Self::Error>;
 
+    /// Returns the size of a compiled constraint system.
+    ///
+    /// Backends that do not compile into a constraint system return `None`,
+    /// which is the default.
+    fn constraint_stats(
+        &self,
+        cs: &Self::CircuitConstraint,
+    ) -> Result<Option<ConstraintStats>, Self::Error> {
+        let _ = cs;
+        Ok(None)
+    }
+
     /// Performs the trusted setup phase to generate proving and verifying keys.
     ///
     /// This is a critical phase in ZK-SNARK systems that generates the cryptographic keys
@@ -1,10 +1,44 @@
 use std::marker::PhantomData;
 
 use anyhow::Result;
-use rsnark_core::{CircuitPublicWitness, CircuitWitness, types};
+use rsnark_core::{
+    BigInt, CircuitPublicWitness, CircuitWitness, Metadata, fingerprint::Fingerprint,
+    stats::ConstraintStats, types,
+};
 
 use crate::Backend;
 
+/// Error of [`CircuitProver::verify`] for a proof that the backend rejects.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid proof")]
+pub struct InvalidProof;
+
+/// Flattens a circuit witness into the witness passed to backends, with its
+/// values reduced modulo `field`.
+pub fn flatten_witness<C: CircuitWitness>(circuit_witness: &C, field: &BigInt) -> types::Witness {
+    let mut public = Vec::new();
+    let mut private = Vec::new();
+
+    circuit_witness.append_witness(&mut public, &mut private, false);
+
+    let mut witness = types::Witness::from((public, private));
+    witness.reduce(field);
+    witness
+}
+
+/// Flattens a public witness into the public witness passed to backends, with
+/// its values reduced modulo `field`.
+pub fn flatten_public_witness<P: CircuitPublicWitness>(
+    public_witness: &P,
+    field: &BigInt,
+) -> types::PublicWitness {
+    let mut witness = types::PublicWitness::new();
+
+    public_witness.append_public_witness(witness.public_mut(), false);
+    witness.reduce(field);
+    witness
+}
+
 /// Circuit-specific prover for generating and verifying zero-knowledge proofs.
 ///
 /// The `CircuitProver` is created by compiling a specific circuit with a [`crate::Prover`] and
@@ -29,6 +63,7 @@ where
 {
     pub(crate) backend: B,
     pub(crate) constraint: B::CircuitConstraint,
+    pub(crate) fingerprint: Fingerprint,
     pub(crate) marker: PhantomData<C>,
 }
 
@@ -37,6 +72,25 @@ where
     B: Backend,
     C: CircuitWitness,
 {
+    /// Returns the fingerprint of the compiled circuit.
+    ///
+    /// Artifacts sealed with this fingerprint, see [`rsnark_core::fingerprint::seal`],
+    /// can only be opened by a prover of the same circuit and backend.
+    pub fn fingerprint(&self) -> &Fingerprint {
+        &self.fingerprint
+    }
+
+    /// Returns the compiled circuit constraints, for backend-specific operations
+    /// such as a multi-party setup.
+    pub fn constraint(&self) -> &B::CircuitConstraint {
+        &self.constraint
+    }
+
+    /// Returns the size of the compiled constraint system, if the backend reports it.
+    pub fn constraint_stats(&self) -> Result<Option<ConstraintStats>> {
+        Ok(self.backend.constraint_stats(&self.constraint)?)
+    }
+
     /// Performs the trusted setup phase for this compiled circuit.
     ///
     /// This generates the proving and verifying keys that are specific to the compiled
@@ -90,12 +144,8 @@ where
     /// - The witness values are malformed or invalid
     ///
     pub fn prove(&self, proving_key: &B::ProvingKey, circuit_witness: &C) -> Result<B::Proof> {
-        let mut public = Vec::new();
-        let mut private = Vec::new();
+        let witness = flatten_witness(circuit_witness, self.backend.metadata().field());
 
-        circuit_witness.append_witness(&mut public, &mut private, false);
-
-        let witness = types::Witness::from((public, private));
         let proof = self
             .backend
             .prove(&self.constraint, proving_key, &witness)?;
@@ -122,7 +172,8 @@ where
     /// # Errors
     ///
     /// This function may return an error if:
-    /// - The proof is invalid or malformed
+    /// - The proof is invalid, in which case the error is [`InvalidProof`]
+    /// - The proof is malformed
     /// - The public witness does not match the proof
     /// - The verifying key is incompatible with the circuit
     /// - Cryptographic verification operations fail
@@ -141,11 +192,11 @@ where
     where
         C::PublicWitness: CircuitPublicWitness,
     {
-        let mut witness = types::PublicWitness::new();
-
-        public_witness.append_public_witness(witness.public_mut(), false);
+        let witness = flatten_public_witness(&public_witness, self.backend.metadata().field());
 
-        self.backend.verify(verifying_key, proof, &witness)?;
+        if !self.backend.verify(verifying_key, proof, &witness)? {
+            return Err(InvalidProof.into());
+        }
 
         Ok(())
     }
@@ -0,0 +1,173 @@
+use std::convert::Infallible;
+
+use rsnark_core::{
+    BigInt, MetadataInfo,
+    plonkish::Plonkish,
+    r1cs::R1cs,
+    schema::{self, SchemaError},
+    stats::ConstraintStats,
+    types::{CircuitDefinition, PublicWitness, Witness},
+};
+
+use crate::Backend;
+
+/// Error type of [`DynBackend`], boxing the errors of the backend and of the
+/// serialization of its artifacts.
+pub type DynError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A compiled circuit, key or proof of a backend that can be serialized to bytes.
+///
+/// Backends whose artifacts all implement this trait are [`DynBackend`]s.
+pub trait Artifact: Sized {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    fn to_bytes(&self) -> Result<Vec<u8>, Self::Error>;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, Self::Error>;
+}
+
+/// Object-safe version of [`Backend`], with compiled circuits, keys and proofs
+/// serialized to bytes.
+///
+/// It is implemented by every [`Backend`] whose artifacts implement
+/// [`Artifact`], so that backends of different types can be stored as
+/// `Box<dyn DynBackend>`:
+///
+/// ```rust,ignore
+/// let backends: Vec<Box<dyn DynBackend>> = vec![
+///     Box::new(MockProverBackend::new()),
+///     Box::new(Plonky3Backend::new()),
+/// ];
+/// for backend in &backends {
+///     let cs = backend.compile(&definition)?;
+///     let (pk, vk) = backend.setup(&cs)?;
+///     let proof = backend.prove(&cs, &pk, &witness)?;
+///     assert!(backend.verify(&vk, &proof, &public_witness)?);
+/// }
+/// ```
+///
+/// The methods share their names with the ones of [`Backend`]. When both traits
+/// are in scope, call them as `DynBackend::compile(&backend, ..)`.
+pub trait DynBackend {
+    /// See [`Backend::metadata`].
+    fn metadata(&self) -> MetadataInfo;
+
+    /// Compiles a circuit definition, and returns the serialized constraints.
+    fn compile(&self, circuit: &CircuitDefinition) -> Result<Vec<u8>, DynError>;
+
+    /// See [`Backend::constraint_stats`].
+    fn constraint_stats(&self, cs: &[u8]) -> Result<Option<ConstraintStats>, DynError>;
+
+    /// Performs the setup of serialized constraints, and returns the
+    /// serialized proving and verifying keys.
+    fn setup(&self, cs: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DynError>;
+
+    /// Generates a proof with serialized constraints and proving key, and
+    /// returns it serialized.
+    fn prove(&self, cs: &[u8], pk: &[u8], witness: &Witness) -> Result<Vec<u8>, DynError>;
+
+    /// Verifies a serialized proof with a serialized verifying key.
+    fn verify(
+        &self,
+        vk: &[u8],
+        proof: &[u8],
+        public_witness: &PublicWitness,
+    ) -> Result<bool, DynError>;
+}
+
+impl<B> DynBackend for B
+where
+    B: Backend,
+    B::CircuitConstraint: Artifact,
+    B::ProvingKey: Artifact,
+    B::VerifyingKey: Artifact,
+    B::Proof: Artifact,
+{
+    fn metadata(&self) -> MetadataInfo {
+        Backend::metadata(self)
+    }
+
+    fn compile(&self, circuit: &CircuitDefinition) -> Result<Vec<u8>, DynError> {
+        let cs = Backend::compile(self, circuit)?;
+        Ok(cs.to_bytes()?)
+    }
+
+    fn constraint_stats(&self, cs: &[u8]) -> Result<Option<ConstraintStats>, DynError> {
+        let cs = B::CircuitConstraint::from_bytes(cs)?;
+        Ok(Backend::constraint_stats(self, &cs)?)
+    }
+
+    fn setup(&self, cs: &[u8]) -> Result<(Vec<u8>, Vec<u8>), DynError> {
+        let cs = B::CircuitConstraint::from_bytes(cs)?;
+        let (pk, vk) = Backend::setup(self, &cs)?;
+        Ok((pk.to_bytes()?, vk.to_bytes()?))
+    }
+
+    fn prove(&self, cs: &[u8], pk: &[u8], witness: &Witness) -> Result<Vec<u8>, DynError> {
+        let cs = B::CircuitConstraint::from_bytes(cs)?;
+        let pk = B::ProvingKey::from_bytes(pk)?;
+        let proof = Backend::prove(self, &cs, &pk, witness)?;
+        Ok(proof.to_bytes()?)
+    }
+
+    fn verify(
+        &self,
+        vk: &[u8],
+        proof: &[u8],
+        public_witness: &PublicWitness,
+    ) -> Result<bool, DynError> {
+        let vk = B::VerifyingKey::from_bytes(vk)?;
+        let proof = B::Proof::from_bytes(proof)?;
+        Ok(Backend::verify(self, &vk, &proof, public_witness)?)
+    }
+}
+
+/// Serialized as JSON, and migrated to the current schema when deserialized.
+impl Artifact for CircuitDefinition {
+    type Error = SchemaError;
+
+    fn to_bytes(&self) -> Result<Vec<u8>, SchemaError> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, SchemaError> {
+        schema::from_json(data)
+    }
+}
+
+impl Artifact for R1cs {
+    type Error = serde_json::Error;
+
+    fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(self)
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(data)
+    }
+}
+
+impl Artifact for Plonkish {
+    type Error = serde_json::Error;
+
+    fn to_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(self)
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(data)
+    }
+}
+
+/// Serialized as signed big-endian bytes.
+impl Artifact for BigInt {
+    type Error = Infallible;
+
+    fn to_bytes(&self) -> Result<Vec<u8>, Infallible> {
+        Ok(self.to_signed_bytes_be())
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, Infallible> {
+        Ok(BigInt::from_signed_bytes_be(data))
+    }
+}
@@ -11,6 +11,7 @@
 //! - [`Backend`]: The core trait that defines the interface for ZK-SNARK backends
 //! - [`Prover`]: High-level prover that orchestrates the proof generation process
 //! - [`CircuitProver`]: Circuit-specific prover for generating and verifying proofs
+//! - [`DynBackend`]: Object-safe backend over serialized circuits, keys and proofs
 //!
 
 mod backend;
@@ -21,3 +22,9 @@ pub use prover::*;
 
 mod circuit_prover;
 pub use circuit_prover::*;
+
+mod dyn_backend;
+pub use dyn_backend::*;
+
+#[cfg(feature = "test-circuits")]
+pub mod test_circuits;
@@ -1,10 +1,37 @@
 use std::marker::PhantomData;
 
 use anyhow::Result;
-use rsnark_core::{Circuit, CircuitBuilder, CircuitElement, CircuitWitness};
+use rsnark_core::{
+    Circuit, CircuitBuilder, CircuitElement, CircuitWitness, MetadataInfo,
+    analysis::{self, AnalysisReport},
+    fingerprint::Fingerprint,
+    passes::OptimizationLevel,
+    stats::CircuitStats,
+    types::CircuitDefinition,
+};
 
 use crate::{Backend, CircuitProver};
 
+/// Builds the circuit definition of `C` for a backend with `metadata`,
+/// optimized at `level`.
+///
+/// This is [`Prover::define_circuit`] for backends that are only known at
+/// runtime.
+pub fn define_circuit<C>(metadata: MetadataInfo, level: OptimizationLevel) -> CircuitDefinition
+where
+    C: CircuitElement,
+    <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+{
+    let mut builder = CircuitBuilder::new(metadata).with_simplify(level.simplify());
+    let circuit = C::CircuitWitness::create_public(builder.variable_initer_mut(), false);
+    circuit.define(&mut builder);
+
+    let mut define = builder.build();
+    level.run(&mut define);
+
+    define
+}
+
 /// High-level prover that orchestrates the zero-knowledge proof generation process.
 ///
 /// The `Prover` serves as the main entry point for creating zero-knowledge proofs using
@@ -17,6 +44,7 @@ use crate::{Backend, CircuitProver};
 ///
 pub struct Prover<B> {
     pub(crate) backend: B,
+    pub(crate) optimization_level: OptimizationLevel,
 }
 
 impl<B: Backend> Default for Prover<B> {
@@ -39,9 +67,83 @@ where
     /// A new `Prover` instance ready for circuit compilation.
     ///
     pub fn new() -> Self {
-        let backend = B::new();
+        Self::from_backend(B::new())
+    }
+
+    /// Creates a new prover instance with a configured backend, created by
+    /// [`Backend::with_config()`].
+    pub fn with_config(config: B::Config) -> Result<Self, B::Error> {
+        Ok(Self::from_backend(B::with_config(config)?))
+    }
 
-        Self { backend }
+    /// Creates a new prover instance from an already configured backend.
+    pub fn from_backend(backend: B) -> Self {
+        Self {
+            backend,
+            optimization_level: OptimizationLevel::default(),
+        }
+    }
+
+    /// Sets the optimization level applied to circuits before compilation.
+    ///
+    /// Defaults to [`OptimizationLevel::None`].
+    pub fn with_optimization_level(mut self, level: OptimizationLevel) -> Self {
+        self.optimization_level = level;
+        self
+    }
+
+    /// Builds the circuit definition of `C` without compiling it.
+    ///
+    /// The definition is optimized according to the configured
+    /// [`OptimizationLevel`], exactly as in [`compile_circuit`](Self::compile_circuit).
+    pub fn define_circuit<C>(&self) -> CircuitDefinition
+    where
+        C: CircuitElement,
+        <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+    {
+        define_circuit::<C>(self.backend.metadata(), self.optimization_level)
+    }
+
+    /// Computes the fingerprint of `C` for this backend, without compiling it.
+    ///
+    /// Equal to [`CircuitProver::fingerprint`] of the compiled circuit.
+    pub fn fingerprint<C>(&self) -> Fingerprint
+    where
+        C: CircuitElement,
+        <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+    {
+        Fingerprint::new(&self.define_circuit::<C>(), &self.backend.metadata())
+    }
+
+    /// Analyzes the circuit definition of `C` for underconstrained variables.
+    ///
+    /// The definition is built exactly as for [`compile_circuit`](Self::compile_circuit),
+    /// so the analysis also covers the effects of the configured [`OptimizationLevel`].
+    /// See [`analysis`] for the reported issues.
+    pub fn analyze_circuit<C>(&self) -> AnalysisReport
+    where
+        C: CircuitElement,
+        <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+    {
+        analysis::analyze(&self.define_circuit::<C>())
+    }
+
+    /// Collects operation and variable counts of `C`.
+    ///
+    /// The circuit is also compiled, so that the report includes the size of the
+    /// constraint system when the backend provides it, see
+    /// [`Backend::constraint_stats`].
+    pub fn circuit_stats<C>(&self) -> Result<CircuitStats>
+    where
+        C: CircuitElement,
+        <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+    {
+        let define = self.define_circuit::<C>();
+
+        let cs = self.backend.compile(&define)?;
+        let constraints = self.backend.constraint_stats(&cs)?;
+
+        Ok(CircuitStats::from_definition(&define).with_constraints(constraints))
     }
 
     /// Compiles a circuit and creates a circuit-specific prover.
@@ -71,18 +173,15 @@ where
         C: CircuitElement,
         <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
     {
-        let metadata = self.backend.metadata();
-        let mut builder = CircuitBuilder::new(metadata);
-        let circuit = C::CircuitWitness::create_public(builder.variable_initer_mut(), false);
-        circuit.define(&mut builder);
-
-        let define = builder.build();
+        let define = self.define_circuit::<C>();
+        let fingerprint = Fingerprint::new(&define, &self.backend.metadata());
 
         let cs = self.backend.compile(&define)?;
 
         Ok(CircuitProver {
             backend: self.backend,
             constraint: cs,
+            fingerprint,
             marker: PhantomData,
         })
     }
@@ -0,0 +1,39 @@
+//! Circuits shared by the tests of the backends.
+//!
+//! Enabled by the `test-circuits` feature, which backends only turn on for
+//! their dev-dependencies.
+
+use rsnark_core::{API, Circuit, circuit};
+
+/// Checks `a + b == c`, with `c` public.
+#[circuit]
+pub struct TestCircuit {
+    a: u32,
+    b: u32,
+    pub c: u32,
+}
+
+impl Circuit for TestCircuit {
+    fn define(&self, api: &mut impl API) {
+        let c = api.add(&self.a, &self.b);
+        api.assert_is_equal(&c, &self.c);
+    }
+}
+
+/// Checks `x * y / 2 == z` with `x < 256`, so that proving needs internal
+/// wires and a range check.
+#[circuit]
+pub struct RangeCircuit {
+    x: u32,
+    y: u32,
+    pub z: u32,
+}
+
+impl Circuit for RangeCircuit {
+    fn define(&self, api: &mut impl API) {
+        let xy = api.mul(&self.x, &self.y);
+        let z = api.div(&xy, &2);
+        api.assert_is_equal(&z, &self.z);
+        api.variable_to_binary(&self.x, 8);
+    }
+}
@@ -95,23 +95,38 @@ extern "C" {
 #endif
 
 extern void CGroth16Prover_groth16_create(uint64_t curve_id, void* slot, void* cb);
-extern void CGroth16Prover_groth16_compile(uint64_t curve_id, ListRef circuit, void* slot, void* cb);
+extern void CGroth16Prover_groth16_compile(uint64_t prover, ListRef circuit, void* slot, void* cb);
 extern void CGroth16Prover_groth16_setup(uint64_t prover, int64_t compiled_circuit, void* slot, void* cb);
 extern void CGroth16Prover_groth16_prove(uint64_t prover, int64_t compiled_circuit, int64_t pk, ListRef witness, void* slot, void* cb);
 extern void CGroth16Prover_groth16_verify(uint64_t prover, int64_t vk, int64_t proof, ListRef public_witness, void* slot, void* cb);
 extern void CGroth16Prover_groth16_remove_prover(uint64_t prover);
+extern void CGroth16Prover_groth16_set_seed(uint64_t prover, uint64_t seed, void* slot, void* cb);
+
+extern void CGroth16Prover_groth16_set_config(uint64_t prover, ListRef config, void* slot, void* cb);
+extern void CGroth16Prover_groth16_phase1_insecure(int64_t compiled_circuit, void* slot, void* cb);
+extern void CGroth16Prover_groth16_phase2_initialize(int64_t phase1, int64_t compiled_circuit, void* slot, void* cb);
+extern void CGroth16Prover_groth16_phase2_contribute(int64_t phase2, void* slot, void* cb);
+extern void CGroth16Prover_groth16_phase2_verify(int64_t phase2, int64_t next, void* slot, void* cb);
+extern void CGroth16Prover_groth16_phase2_seal(int64_t phase1, int64_t evaluations, int64_t phase2, ListRef beacon, void* slot, void* cb);
 extern void CPlonkProver_plonk_create(uint64_t curve_id, void* slot, void* cb);
-extern void CPlonkProver_plonk_compile(uint64_t curve_id, ListRef circuit, void* slot, void* cb);
+extern void CPlonkProver_plonk_compile(uint64_t prover, ListRef circuit, void* slot, void* cb);
 extern void CPlonkProver_plonk_setup(uint64_t prover, int64_t compiled_circuit, void* slot, void* cb);
 extern void CPlonkProver_plonk_prove(uint64_t prover, int64_t compiled_circuit, int64_t pk, ListRef witness, void* slot, void* cb);
 extern void CPlonkProver_plonk_verify(uint64_t prover, int64_t vk, int64_t proof, ListRef public_witness, void* slot, void* cb);
 extern void CPlonkProver_plonk_remove_prover(uint64_t prover);
+extern void CPlonkProver_plonk_set_recursion(uint64_t prover, void* slot, void* cb);
+extern void CPlonkProver_plonk_set_srs(uint64_t prover, uint64_t format, StringRef path, void* slot, void* cb);
+extern void CPlonkProver_plonk_set_seed(uint64_t prover, uint64_t seed, void* slot, void* cb);
+
+extern void CPlonkProver_plonk_set_config(uint64_t prover, ListRef config, void* slot, void* cb);
 extern void CObject_serialize(int64_t object_id, void* slot, void* cb);
 extern void CObject_deserialize(uint64_t ty, uint64_t curve_id, ListRef data, void* slot, void* cb);
 extern void CObject_write_to_file(int64_t object_id, StringRef path, void* slot, void* cb);
 extern void CObject_read_from_file(uint64_t ty, uint64_t curve_id, StringRef path, void* slot, void* cb);
 extern void CObject_remove_object(int64_t object_id);
 extern void CObject_export_solidity(int64_t object_id, uint64_t type_id, void* slot, void* cb);
+extern void CObject_constraint_stats(int64_t object_id, void* slot, void* cb);
+extern void CObject_recursion_values(int64_t object_id, void* slot, void* cb);
 
 #ifdef __cplusplus
 }
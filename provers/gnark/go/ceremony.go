@@ -0,0 +1,119 @@
+package main
+
+import (
+	"log"
+
+	"github.com/tiannian/rsnark/provers-gnark/prover"
+	"github.com/tiannian/rsnark/provers-gnark/prover/types"
+)
+
+// getObject returns the object with the given id, if it has type T
+func getObject[T types.SerializableObject](object_id int64) (T, int64) {
+	objectMutex.Lock()
+	defer objectMutex.Unlock()
+
+	var zero T
+	object, exists := objects[object_id]
+	if !exists {
+		log.Printf("object with id %d not found", object_id)
+		return zero, -20012
+	}
+
+	typed, ok := object.(T)
+	if !ok {
+		log.Printf("object with id %d has type %T, expected %T", object_id, object, zero)
+		return zero, -10011
+	}
+
+	return typed, 0
+}
+
+func (p Groth16ProverCall) groth16_phase1_insecure(compiled_circuit_id *int64) int64 {
+	compiled, code := getObject[*types.Groth16CompiledCircuit](*compiled_circuit_id)
+	if code != 0 {
+		return code
+	}
+
+	phase1, err := prover.InsecurePhase1(compiled)
+	if err != nil {
+		log.Printf("failed to run phase 1: %v", err)
+		return -10011
+	}
+
+	return addObject(phase1)
+}
+
+func (p Groth16ProverCall) groth16_phase2_initialize(phase1_id *int64, compiled_circuit_id *int64) []byte {
+	phase1, code := getObject[*types.Groth16Phase1](*phase1_id)
+	if code != 0 {
+		return int64ToBytes2(code, 0)
+	}
+	compiled, code := getObject[*types.Groth16CompiledCircuit](*compiled_circuit_id)
+	if code != 0 {
+		return int64ToBytes2(code, 0)
+	}
+
+	phase2, evaluations, err := prover.InitializePhase2(phase1, compiled)
+	if err != nil {
+		log.Printf("failed to initialize phase 2: %v", err)
+		return int64ToBytes2(-10011, 0)
+	}
+
+	return int64ToBytes2(addObject(phase2), addObject(evaluations))
+}
+
+func (p Groth16ProverCall) groth16_phase2_contribute(phase2_id *int64) int64 {
+	phase2, code := getObject[*types.Groth16Phase2](*phase2_id)
+	if code != 0 {
+		return code
+	}
+
+	next, err := prover.ContributePhase2(phase2)
+	if err != nil {
+		log.Printf("failed to contribute to phase 2: %v", err)
+		return -10011
+	}
+
+	return addObject(next)
+}
+
+func (p Groth16ProverCall) groth16_phase2_verify(phase2_id *int64, next_id *int64) int64 {
+	phase2, code := getObject[*types.Groth16Phase2](*phase2_id)
+	if code != 0 {
+		return code
+	}
+	next, code := getObject[*types.Groth16Phase2](*next_id)
+	if code != 0 {
+		return code
+	}
+
+	if err := prover.VerifyPhase2(phase2, next); err != nil {
+		log.Printf("failed to verify phase 2 contribution: %v", err)
+		return -10011
+	}
+
+	return 0
+}
+
+func (p Groth16ProverCall) groth16_phase2_seal(phase1_id *int64, evaluations_id *int64, phase2_id *int64, beacon *[]byte) []byte {
+	phase1, code := getObject[*types.Groth16Phase1](*phase1_id)
+	if code != 0 {
+		return int64ToBytes2(code, 0)
+	}
+	evaluations, code := getObject[*types.Groth16Phase2Evaluations](*evaluations_id)
+	if code != 0 {
+		return int64ToBytes2(code, 0)
+	}
+	phase2, code := getObject[*types.Groth16Phase2](*phase2_id)
+	if code != 0 {
+		return int64ToBytes2(code, 0)
+	}
+
+	pk, vk, err := prover.SealPhase2(phase1, evaluations, phase2, *beacon)
+	if err != nil {
+		log.Printf("failed to seal phase 2: %v", err)
+		return int64ToBytes2(-10011, 0)
+	}
+
+	return int64ToBytes2(addObject(pk), addObject(vk))
+}
@@ -27,6 +27,9 @@ func (circuit *TemplateCircuit) Define(api frontend.API) error {
 	// Execute operations in order
 	for i, operation := range circuit.circuitDefinition.Operations {
 		if err := executeOperation(api, operation, circuit.PublicVariables, circuit.PrivateVariables, &localVariables); err != nil {
+			if label := operation.Label(); label != "" {
+				return fmt.Errorf("failed to execute operation %d (%s) in %s: %w", i, operation.Op, label, err)
+			}
 			return fmt.Errorf("failed to execute operation %d (%s): %w", i, operation.Op, err)
 		}
 	}
@@ -125,15 +128,21 @@ func executeOperation(api frontend.API, op Operation, publicVars, privateVars []
 		results = []frontend.Variable{result}
 
 	case OpToBinary:
-		if len(inputs) != 1 {
-			return fmt.Errorf("to_binary operation requires exactly 1 input, got %d", len(inputs))
+		// The inputs are the variable and, optionally, the constant number of
+		// bits, which is also the number of outputs
+		if len(inputs) != 1 && len(inputs) != 2 {
+			return fmt.Errorf("to_binary operation requires 1 or 2 inputs, got %d", len(inputs))
 		}
-		// ToBinary can have multiple outputs based on the number of bits
-		// We need to determine the number of output bits from op.Outputs length
 		numBits := len(op.Outputs)
 		if numBits == 0 {
 			return fmt.Errorf("to_binary operation requires at least 1 output bit")
 		}
+		if len(inputs) == 2 {
+			n, ok := api.Compiler().ConstantValue(inputs[1])
+			if !ok || !n.IsInt64() || n.Int64() != int64(numBits) {
+				return fmt.Errorf("to_binary bit count must be the constant %d", numBits)
+			}
+		}
 		// Convert to binary with specified number of bits
 		bits := api.ToBinary(inputs[0], numBits)
 		results = bits
@@ -222,6 +231,26 @@ func executeOperation(api frontend.API, op Operation, publicVars, privateVars []
 		api.AssertIsBoolean(inputs[0])
 		results = nil // No output
 
+	case OpAssertGroth16Proof:
+		if err := assertGroth16Proof(api, inputs); err != nil {
+			return err
+		}
+		results = nil // No output
+
+	case OpAssertPlonkProof:
+		if err := assertPlonkProof(api, inputs); err != nil {
+			return err
+		}
+		results = nil // No output
+
+	case OpPrintln:
+		args := make([]frontend.Variable, 0, len(inputs)+1)
+		if label := op.Label(); label != "" {
+			args = append(args, label+":")
+		}
+		api.Println(append(args, inputs...)...)
+		results = nil // No output
+
 	default:
 		return fmt.Errorf("unsupported operation: %s", op.Op)
 	}
@@ -0,0 +1,280 @@
+package circuit
+
+import (
+	"bytes"
+	"fmt"
+	"math/big"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark/backend/plonk"
+	bn254plonk "github.com/consensys/gnark/backend/plonk/bn254"
+	"github.com/consensys/gnark/frontend"
+	"github.com/consensys/gnark/std/algebra/emulated/sw_bn254"
+	"github.com/consensys/gnark/std/algebra/native/fields_bls12377"
+	"github.com/consensys/gnark/std/algebra/native/sw_bls12377"
+	"github.com/consensys/gnark/std/commitments/kzg"
+	"github.com/consensys/gnark/std/math/emulated"
+	"github.com/consensys/gnark/std/math/emulated/emparams"
+	stdgroth16 "github.com/consensys/gnark/std/recursion/groth16"
+	stdplonk "github.com/consensys/gnark/std/recursion/plonk"
+)
+
+const (
+	// groth16ProofLen is the number of elements of a BLS12-377 proof
+	groth16ProofLen = 8
+	// groth16VerifyingKeyLen is the number of elements of a BLS12-377 verifying
+	// key without public inputs, which add 2 elements each to K
+	groth16VerifyingKeyLen = 22
+
+	// plonkLimbs is the number of limbs of an emulated BN254 element
+	plonkLimbs = 4
+	// plonkProofLen is the number of elements of a BN254 proof: 9 points and 7
+	// scalars, as limbs
+	plonkProofLen = (9*2 + 7) * plonkLimbs
+	// plonkKeyChunkBytes is the number of bytes of the serialized verifying key
+	// in each constant
+	plonkKeyChunkBytes = 31
+)
+
+type (
+	groth16Proof        = stdgroth16.Proof[sw_bls12377.G1Affine, sw_bls12377.G2Affine]
+	groth16VerifyingKey = stdgroth16.VerifyingKey[sw_bls12377.G1Affine, sw_bls12377.G2Affine, sw_bls12377.GT]
+	groth16Witness      = stdgroth16.Witness[sw_bls12377.ScalarField]
+
+	plonkProof        = stdplonk.Proof[sw_bn254.ScalarField, sw_bn254.G1Affine, sw_bn254.G2Affine]
+	plonkVerifyingKey = stdplonk.VerifyingKey[sw_bn254.ScalarField, sw_bn254.G1Affine, sw_bn254.G2Affine]
+	plonkWitness      = stdplonk.Witness[sw_bn254.ScalarField]
+)
+
+// assertGroth16Proof verifies a Groth16 proof over BLS12-377 in a BW6-761 circuit.
+// The inputs are the proof, the verifying key and the public inputs, flattened as
+// in rsnark_core::recursion::groth16
+func assertGroth16Proof(api frontend.API, inputs []frontend.Variable) error {
+	if api.Compiler().Field().Cmp(ecc.BW6_761.ScalarField()) != 0 {
+		return fmt.Errorf("groth16 proofs over BLS12-377 can only be verified in BW6-761 circuits")
+	}
+
+	rest := len(inputs) - groth16ProofLen - groth16VerifyingKeyLen
+	if rest < 0 || rest%3 != 0 {
+		return fmt.Errorf("assert_groth16_proof operation got %d inputs, expected %d + 3 per public input", len(inputs), groth16ProofLen+groth16VerifyingKeyLen)
+	}
+	nbPublic := rest / 3
+
+	proofVars := inputs[:groth16ProofLen]
+	vkVars := inputs[groth16ProofLen : len(inputs)-nbPublic]
+	publicVars := inputs[len(inputs)-nbPublic:]
+
+	proof := groth16Proof{
+		Ar:  newG1(proofVars[0:2]),
+		Krs: newG1(proofVars[2:4]),
+		Bs:  newG2(proofVars[4:8]),
+	}
+
+	vk := groth16VerifyingKey{
+		E: fields_bls12377.E12{
+			C0: newE6(vkVars[0:6]),
+			C1: newE6(vkVars[6:12]),
+		},
+	}
+	vk.G2.GammaNeg = newG2(vkVars[12:16])
+	vk.G2.DeltaNeg = newG2(vkVars[16:20])
+	vk.G1.K = make([]sw_bls12377.G1Affine, nbPublic+1)
+	for i := range vk.G1.K {
+		vk.G1.K[i] = newG1(vkVars[20+2*i : 22+2*i])
+	}
+
+	witness, err := newGroth16Witness(api, publicVars)
+	if err != nil {
+		return err
+	}
+
+	verifier, err := stdgroth16.NewVerifier[sw_bls12377.ScalarField, sw_bls12377.G1Affine, sw_bls12377.G2Affine, sw_bls12377.GT](api)
+	if err != nil {
+		return fmt.Errorf("failed to create groth16 verifier: %w", err)
+	}
+
+	return verifier.AssertProof(vk, proof, witness)
+}
+
+// newGroth16Witness converts native public inputs into elements of the BLS12-377
+// scalar field, asserting that they are reduced
+func newGroth16Witness(api frontend.API, publicVars []frontend.Variable) (groth16Witness, error) {
+	scalars, err := emulated.NewField[sw_bls12377.ScalarField](api)
+	if err != nil {
+		return groth16Witness{}, fmt.Errorf("failed to create scalar field: %w", err)
+	}
+
+	r := ecc.BLS12_377.ScalarField()
+	bound := new(big.Int).Sub(r, big.NewInt(1))
+
+	witness := groth16Witness{Public: make([]emulated.Element[sw_bls12377.ScalarField], len(publicVars))}
+	for i, v := range publicVars {
+		api.AssertIsLessOrEqual(v, bound)
+		witness.Public[i] = *scalars.FromBits(api.ToBinary(v, r.BitLen())...)
+	}
+
+	return witness, nil
+}
+
+func newG1(v []frontend.Variable) sw_bls12377.G1Affine {
+	return sw_bls12377.G1Affine{X: v[0], Y: v[1]}
+}
+
+func newE2(v []frontend.Variable) fields_bls12377.E2 {
+	return fields_bls12377.E2{A0: v[0], A1: v[1]}
+}
+
+func newE6(v []frontend.Variable) fields_bls12377.E6 {
+	return fields_bls12377.E6{B0: newE2(v[0:2]), B1: newE2(v[2:4]), B2: newE2(v[4:6])}
+}
+
+func newG2(v []frontend.Variable) sw_bls12377.G2Affine {
+	var p sw_bls12377.G2Affine
+	p.P.X = newE2(v[0:2])
+	p.P.Y = newE2(v[2:4])
+	return p
+}
+
+// assertPlonkProof verifies a PLONK proof over BN254 in a BN254 circuit. The
+// inputs are the verifying key as constants, the proof and the public inputs,
+// flattened as in rsnark_core::recursion::plonk
+func assertPlonkProof(api frontend.API, inputs []frontend.Variable) error {
+	if api.Compiler().Field().Cmp(ecc.BN254.ScalarField()) != 0 {
+		return fmt.Errorf("plonk proofs over BN254 can only be verified in BN254 circuits")
+	}
+
+	vk, nbKeyInputs, err := newPlonkVerifyingKey(inputs)
+	if err != nil {
+		return err
+	}
+
+	rest := inputs[nbKeyInputs:]
+	if len(rest) != plonkProofLen+int(vk.NbPublicVariables) {
+		return fmt.Errorf("assert_plonk_proof operation got %d proof and public inputs, expected %d", len(rest), plonkProofLen+int(vk.NbPublicVariables))
+	}
+	proof := newPlonkProof(rest[:plonkProofLen])
+
+	witness, err := newPlonkWitness(api, rest[plonkProofLen:])
+	if err != nil {
+		return err
+	}
+
+	verifier, err := stdplonk.NewVerifier[sw_bn254.ScalarField, sw_bn254.G1Affine, sw_bn254.G2Affine, sw_bn254.GTEl](api)
+	if err != nil {
+		return fmt.Errorf("failed to create plonk verifier: %w", err)
+	}
+
+	return verifier.AssertProof(vk, proof, witness)
+}
+
+// newPlonkVerifyingKey reassembles the serialized verifying key from the
+// constants packed by rsnark_core::recursion::plonk::VerifyingKey, and returns
+// it with the number of inputs it used
+func newPlonkVerifyingKey(inputs []frontend.Variable) (plonkVerifyingKey, int, error) {
+	constant := func(i int) (*big.Int, error) {
+		if i >= len(inputs) {
+			return nil, fmt.Errorf("assert_plonk_proof operation is missing verifying key inputs")
+		}
+		v, ok := inputs[i].(*big.Int)
+		if !ok {
+			return nil, fmt.Errorf("the verifying key of assert_plonk_proof must be constant")
+		}
+		return v, nil
+	}
+
+	length, err := constant(0)
+	if err != nil {
+		return plonkVerifyingKey{}, 0, err
+	}
+	if !length.IsInt64() {
+		return plonkVerifyingKey{}, 0, fmt.Errorf("invalid verifying key length %s", length)
+	}
+	remaining := int(length.Int64())
+
+	data := make([]byte, 0, remaining)
+	n := 1
+	for ; remaining > 0; n++ {
+		size := min(remaining, plonkKeyChunkBytes)
+		chunk, err := constant(n)
+		if err != nil {
+			return plonkVerifyingKey{}, 0, err
+		}
+		if chunk.Sign() < 0 || chunk.BitLen() > 8*size {
+			return plonkVerifyingKey{}, 0, fmt.Errorf("invalid verifying key chunk %d", n)
+		}
+		data = append(data, chunk.FillBytes(make([]byte, size))...)
+		remaining -= size
+	}
+
+	key := plonk.NewVerifyingKey(ecc.BN254)
+	if _, err := key.ReadFrom(bytes.NewReader(data)); err != nil {
+		return plonkVerifyingKey{}, 0, fmt.Errorf("failed to deserialize verifying key: %w", err)
+	}
+	if bn254Key, ok := key.(*bn254plonk.VerifyingKey); !ok || len(bn254Key.Qcp) != 0 {
+		return plonkVerifyingKey{}, 0, fmt.Errorf("verifying keys with commitments are not supported")
+	}
+
+	vk, err := stdplonk.ValueOfVerifyingKeyFixed[sw_bn254.ScalarField, sw_bn254.G1Affine, sw_bn254.G2Affine](key)
+	if err != nil {
+		return plonkVerifyingKey{}, 0, fmt.Errorf("failed to convert verifying key: %w", err)
+	}
+
+	return vk, n, nil
+}
+
+// newPlonkProof builds the proof from its limbs, in the layout of
+// rsnark_core::recursion::plonk::Proof
+func newPlonkProof(v []frontend.Variable) plonkProof {
+	points := func(i int) sw_bn254.G1Affine {
+		return newBN254G1(v[2*plonkLimbs*i : 2*plonkLimbs*(i+1)])
+	}
+	scalars := v[9*2*plonkLimbs:]
+
+	var proof plonkProof
+	for i := range proof.LRO {
+		proof.LRO[i] = kzg.Commitment[sw_bn254.G1Affine]{G1El: points(i)}
+	}
+	proof.Z = kzg.Commitment[sw_bn254.G1Affine]{G1El: points(3)}
+	for i := range proof.H {
+		proof.H[i] = kzg.Commitment[sw_bn254.G1Affine]{G1El: points(4 + i)}
+	}
+	proof.BatchedProof.Quotient = points(7)
+	proof.ZShiftedOpening.Quotient = points(8)
+
+	nbClaimed := len(scalars)/plonkLimbs - 1
+	proof.BatchedProof.ClaimedValues = make([]emulated.Element[sw_bn254.ScalarField], nbClaimed)
+	for i := range proof.BatchedProof.ClaimedValues {
+		proof.BatchedProof.ClaimedValues[i] = newEmulated[sw_bn254.ScalarField](scalars[plonkLimbs*i : plonkLimbs*(i+1)])
+	}
+	proof.ZShiftedOpening.ClaimedValue = newEmulated[sw_bn254.ScalarField](scalars[plonkLimbs*nbClaimed:])
+
+	return proof
+}
+
+// newPlonkWitness converts native public inputs into emulated elements of the
+// same field
+func newPlonkWitness(api frontend.API, publicVars []frontend.Variable) (plonkWitness, error) {
+	scalars, err := emulated.NewField[sw_bn254.ScalarField](api)
+	if err != nil {
+		return plonkWitness{}, fmt.Errorf("failed to create scalar field: %w", err)
+	}
+
+	bits := api.Compiler().FieldBitLen()
+	witness := plonkWitness{Public: make([]emulated.Element[sw_bn254.ScalarField], len(publicVars))}
+	for i, v := range publicVars {
+		witness.Public[i] = *scalars.FromBits(api.ToBinary(v, bits)...)
+	}
+
+	return witness, nil
+}
+
+func newEmulated[T emulated.FieldParams](limbs []frontend.Variable) emulated.Element[T] {
+	return emulated.Element[T]{Limbs: limbs}
+}
+
+func newBN254G1(v []frontend.Variable) sw_bn254.G1Affine {
+	return sw_bn254.G1Affine{
+		X: newEmulated[emparams.BN254Fp](v[:plonkLimbs]),
+		Y: newEmulated[emparams.BN254Fp](v[plonkLimbs : 2*plonkLimbs]),
+	}
+}
@@ -274,3 +274,90 @@ func TestConstantVariableCircuit(t *testing.T) {
 	assert := test.NewAssert(t)
 	assert.CheckCircuit(circuit, test.WithValidAssignment(witnessCircuit))
 }
+
+func TestToBinaryWithBitCount(t *testing.T) {
+	// The Rust API encodes to_binary as [x, n]: private[0] is decomposed into
+	// 4 bits, which are recombined into public[0]
+	toBinaryJSON := `{
+  "private_len": 1,
+  "public_len": 1,
+  "local_len": 4,
+  "operations": [
+    {
+      "op": "to_binary",
+      "in": [
+        {
+          "t": "private",
+          "v": 0
+        },
+        {
+          "t": "constant",
+          "v": "4"
+        }
+      ],
+      "out": [
+        {
+          "t": "local",
+          "v": 0
+        },
+        {
+          "t": "local",
+          "v": 1
+        },
+        {
+          "t": "local",
+          "v": 2
+        },
+        {
+          "t": "local",
+          "v": 3
+        }
+      ]
+    },
+    {
+      "op": "from_binary",
+      "in": [
+        {
+          "t": "local",
+          "v": 0
+        },
+        {
+          "t": "local",
+          "v": 1
+        },
+        {
+          "t": "local",
+          "v": 2
+        },
+        {
+          "t": "local",
+          "v": 3
+        }
+      ],
+      "out": [
+        {
+          "t": "public",
+          "v": 0
+        }
+      ]
+    }
+  ]
+}`
+
+	circuit, err := NewTemplateCircuitFromJSON(toBinaryJSON)
+	if err != nil {
+		t.Fatalf("Failed to create to_binary template circuit: %v", err)
+	}
+
+	assert := test.NewAssert(t)
+	assert.CheckCircuit(circuit,
+		test.WithValidAssignment(&TemplateCircuit{
+			PublicVariables:  []frontend.Variable{5},
+			PrivateVariables: []frontend.Variable{5},
+		}),
+		test.WithInvalidAssignment(&TemplateCircuit{
+			PublicVariables:  []frontend.Variable{17},
+			PrivateVariables: []frontend.Variable{17},
+		}),
+	)
+}
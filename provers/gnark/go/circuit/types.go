@@ -33,6 +33,8 @@ const (
 	OpAssertIsBoolean     OpCode = "assert_is_boolean"
 	OpAssertIsCrumb       OpCode = "assert_is_crumb"
 	OpAssertIsLessOrEqual OpCode = "assert_is_less_or_equal"
+	OpAssertGroth16Proof  OpCode = "assert_groth16_proof"
+	OpAssertPlonkProof    OpCode = "assert_plonk_proof"
 	OpPrintln             OpCode = "println"
 )
 
@@ -139,9 +141,36 @@ func (vt *VariableType) String() string {
 
 // Operation represents a single operation in the circuit
 type Operation struct {
-	Op      OpCode         `json:"op"`
-	Inputs  []VariableType `json:"in"`
-	Outputs []VariableType `json:"out"`
+	Op        OpCode         `json:"op"`
+	Inputs    []VariableType `json:"in"`
+	Outputs   []VariableType `json:"out"`
+	Span      *Span          `json:"span,omitempty"`
+	Namespace []string       `json:"ns,omitempty"`
+}
+
+// Span represents the location in the Rust circuit code that recorded an operation
+type Span struct {
+	File   string `json:"file"`
+	Line   uint32 `json:"line"`
+	Column uint32 `json:"column"`
+}
+
+// String returns the span formatted as file:line:column
+func (s *Span) String() string {
+	return fmt.Sprintf("%s:%d:%d", s.File, s.Line, s.Column)
+}
+
+// Label returns where the operation comes from, formatted as "outer/inner at file:line:column"
+func (op *Operation) Label() string {
+	namespace := strings.Join(op.Namespace, "/")
+
+	if op.Span == nil {
+		return namespace
+	}
+	if namespace == "" {
+		return op.Span.String()
+	}
+	return fmt.Sprintf("%s at %s", namespace, op.Span.String())
 }
 
 // String returns a string representation of the operation
@@ -161,12 +190,16 @@ func (op *Operation) String() string {
 		strings.Join(outputStrs, ", "))
 }
 
+// SchemaVersion is the newest CircuitDefinition schema version this package understands
+const SchemaVersion = 1
+
 // CircuitDefinition represents the complete circuit definition
 type CircuitDefinition struct {
-	PrivateLen uint64      `json:"private_len"`
-	PublicLen  uint64      `json:"public_len"`
-	LocalLen   uint64      `json:"local_len"`
-	Operations []Operation `json:"operations"`
+	SchemaVersion uint32      `json:"schema_version"`
+	PrivateLen    uint64      `json:"private_len"`
+	PublicLen     uint64      `json:"public_len"`
+	LocalLen      uint64      `json:"local_len"`
+	Operations    []Operation `json:"operations"`
 }
 
 // String returns a string representation of the circuit definition
@@ -181,5 +214,8 @@ func ParseCircuitDefinition(jsonData []byte) (*CircuitDefinition, error) {
 	if err := json.Unmarshal(jsonData, &cd); err != nil {
 		return nil, fmt.Errorf("failed to parse circuit definition: %w", err)
 	}
+	if cd.SchemaVersion > SchemaVersion {
+		return nil, fmt.Errorf("unsupported circuit schema version %d", cd.SchemaVersion)
+	}
 	return &cd, nil
 }
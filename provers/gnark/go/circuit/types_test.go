@@ -311,7 +311,8 @@ func TestAllOpCodes(t *testing.T) {
 		OpAdd, OpMulAcc, OpNeg, OpSub, OpMul, OpDivUnchecked, OpDiv,
 		OpInverse, OpToBinary, OpFromBinary, OpXor, OpOr, OpAnd, OpSelect,
 		OpLookup2, OpIsZero, OpCmp, OpAssertIsEqual, OpAssertIsDifferent,
-		OpAssertIsBoolean, OpAssertIsCrumb, OpAssertIsLessOrEqual, OpPrintln,
+		OpAssertIsBoolean, OpAssertIsCrumb, OpAssertIsLessOrEqual, OpAssertGroth16Proof,
+		OpAssertPlonkProof, OpPrintln,
 	}
 
 	for _, op := range opCodes {
@@ -31,11 +31,18 @@ var Groth16ProverImpl Groth16Prover
 
 type Groth16Prover interface {
 	groth16_create(curve_id *uint64) uint64
-	groth16_compile(curve_id *uint64, circuit *[]uint8) int64
+	groth16_compile(prover *uint64, circuit *[]uint8) int64
 	groth16_setup(prover *uint64, compiled_circuit *int64) []uint8
 	groth16_prove(prover *uint64, compiled_circuit *int64, pk *int64, witness *[]uint8) int64
 	groth16_verify(prover *uint64, vk *int64, proof *int64, public_witness *[]uint8) int64
 	groth16_remove_prover(prover *uint64)
+	groth16_set_seed(prover *uint64, seed *uint64) int64
+	groth16_set_config(prover *uint64, config *[]uint8) int64
+	groth16_phase1_insecure(compiled_circuit *int64) int64
+	groth16_phase2_initialize(phase1 *int64, compiled_circuit *int64) []uint8
+	groth16_phase2_contribute(phase2 *int64) int64
+	groth16_phase2_verify(phase2 *int64, next *int64) int64
+	groth16_phase2_seal(phase1 *int64, evaluations *int64, phase2 *int64, beacon *[]uint8) []uint8
 }
 
 //export CGroth16Prover_groth16_create
@@ -50,10 +57,10 @@ func CGroth16Prover_groth16_create(curve_id C.uint64_t, slot *C.void, cb *C.void
 }
 
 //export CGroth16Prover_groth16_compile
-func CGroth16Prover_groth16_compile(curve_id C.uint64_t, circuit C.ListRef, slot *C.void, cb *C.void) {
-	_new_curve_id := newC_uint64_t(curve_id)
+func CGroth16Prover_groth16_compile(prover C.uint64_t, circuit C.ListRef, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
 	_new_circuit := new_list_mapper_primitive(newC_uint8_t)(circuit)
-	resp := Groth16ProverImpl.groth16_compile(&_new_curve_id, &_new_circuit)
+	resp := Groth16ProverImpl.groth16_compile(&_new_prover, &_new_circuit)
 	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
 	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
 	runtime.KeepAlive(resp_ref)
@@ -107,15 +114,103 @@ func CGroth16Prover_groth16_remove_prover(prover C.uint64_t) {
 	Groth16ProverImpl.groth16_remove_prover(&_new_prover)
 }
 
+//export CGroth16Prover_groth16_set_seed
+func CGroth16Prover_groth16_set_seed(prover C.uint64_t, seed C.uint64_t, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
+	_new_seed := newC_uint64_t(seed)
+	resp := Groth16ProverImpl.groth16_set_seed(&_new_prover, &_new_seed)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CGroth16Prover_groth16_set_config
+func CGroth16Prover_groth16_set_config(prover C.uint64_t, config C.ListRef, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
+	_new_config := new_list_mapper_primitive(newC_uint8_t)(config)
+	resp := Groth16ProverImpl.groth16_set_config(&_new_prover, &_new_config)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CGroth16Prover_groth16_phase1_insecure
+func CGroth16Prover_groth16_phase1_insecure(compiled_circuit C.int64_t, slot *C.void, cb *C.void) {
+	_new_compiled_circuit := newC_int64_t(compiled_circuit)
+	resp := Groth16ProverImpl.groth16_phase1_insecure(&_new_compiled_circuit)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CGroth16Prover_groth16_phase2_initialize
+func CGroth16Prover_groth16_phase2_initialize(phase1 C.int64_t, compiled_circuit C.int64_t, slot *C.void, cb *C.void) {
+	_new_phase1 := newC_int64_t(phase1)
+	_new_compiled_circuit := newC_int64_t(compiled_circuit)
+	resp := Groth16ProverImpl.groth16_phase2_initialize(&_new_phase1, &_new_compiled_circuit)
+	resp_ref, buffer := cvt_ref(cnt_list_mapper_primitive(cntC_uint8_t), ref_list_mapper_primitive(refC_uint8_t))(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CGroth16Prover_groth16_phase2_contribute
+func CGroth16Prover_groth16_phase2_contribute(phase2 C.int64_t, slot *C.void, cb *C.void) {
+	_new_phase2 := newC_int64_t(phase2)
+	resp := Groth16ProverImpl.groth16_phase2_contribute(&_new_phase2)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CGroth16Prover_groth16_phase2_verify
+func CGroth16Prover_groth16_phase2_verify(phase2 C.int64_t, next C.int64_t, slot *C.void, cb *C.void) {
+	_new_phase2 := newC_int64_t(phase2)
+	_new_next := newC_int64_t(next)
+	resp := Groth16ProverImpl.groth16_phase2_verify(&_new_phase2, &_new_next)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CGroth16Prover_groth16_phase2_seal
+func CGroth16Prover_groth16_phase2_seal(phase1 C.int64_t, evaluations C.int64_t, phase2 C.int64_t, beacon C.ListRef, slot *C.void, cb *C.void) {
+	_new_phase1 := newC_int64_t(phase1)
+	_new_evaluations := newC_int64_t(evaluations)
+	_new_phase2 := newC_int64_t(phase2)
+	_new_beacon := new_list_mapper_primitive(newC_uint8_t)(beacon)
+	resp := Groth16ProverImpl.groth16_phase2_seal(&_new_phase1, &_new_evaluations, &_new_phase2, &_new_beacon)
+	resp_ref, buffer := cvt_ref(cnt_list_mapper_primitive(cntC_uint8_t), ref_list_mapper_primitive(refC_uint8_t))(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
 var PlonkProverImpl PlonkProver
 
 type PlonkProver interface {
 	plonk_create(curve_id *uint64) uint64
-	plonk_compile(curve_id *uint64, circuit *[]uint8) int64
+	plonk_compile(prover *uint64, circuit *[]uint8) int64
 	plonk_setup(prover *uint64, compiled_circuit *int64) []uint8
 	plonk_prove(prover *uint64, compiled_circuit *int64, pk *int64, witness *[]uint8) int64
 	plonk_verify(prover *uint64, vk *int64, proof *int64, public_witness *[]uint8) int64
 	plonk_remove_prover(prover *uint64)
+	plonk_set_recursion(prover *uint64) int64
+	plonk_set_srs(prover *uint64, format *uint64, path *string) int64
+	plonk_set_seed(prover *uint64, seed *uint64) int64
+	plonk_set_config(prover *uint64, config *[]uint8) int64
 }
 
 //export CPlonkProver_plonk_create
@@ -130,10 +225,10 @@ func CPlonkProver_plonk_create(curve_id C.uint64_t, slot *C.void, cb *C.void) {
 }
 
 //export CPlonkProver_plonk_compile
-func CPlonkProver_plonk_compile(curve_id C.uint64_t, circuit C.ListRef, slot *C.void, cb *C.void) {
-	_new_curve_id := newC_uint64_t(curve_id)
+func CPlonkProver_plonk_compile(prover C.uint64_t, circuit C.ListRef, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
 	_new_circuit := new_list_mapper_primitive(newC_uint8_t)(circuit)
-	resp := PlonkProverImpl.plonk_compile(&_new_curve_id, &_new_circuit)
+	resp := PlonkProverImpl.plonk_compile(&_new_prover, &_new_circuit)
 	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
 	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
 	runtime.KeepAlive(resp_ref)
@@ -187,6 +282,54 @@ func CPlonkProver_plonk_remove_prover(prover C.uint64_t) {
 	PlonkProverImpl.plonk_remove_prover(&_new_prover)
 }
 
+//export CPlonkProver_plonk_set_recursion
+func CPlonkProver_plonk_set_recursion(prover C.uint64_t, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
+	resp := PlonkProverImpl.plonk_set_recursion(&_new_prover)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CPlonkProver_plonk_set_srs
+func CPlonkProver_plonk_set_srs(prover C.uint64_t, format C.uint64_t, path C.StringRef, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
+	_new_format := newC_uint64_t(format)
+	_new_path := newString(path)
+	resp := PlonkProverImpl.plonk_set_srs(&_new_prover, &_new_format, &_new_path)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CPlonkProver_plonk_set_seed
+func CPlonkProver_plonk_set_seed(prover C.uint64_t, seed C.uint64_t, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
+	_new_seed := newC_uint64_t(seed)
+	resp := PlonkProverImpl.plonk_set_seed(&_new_prover, &_new_seed)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CPlonkProver_plonk_set_config
+func CPlonkProver_plonk_set_config(prover C.uint64_t, config C.ListRef, slot *C.void, cb *C.void) {
+	_new_prover := newC_uint64_t(prover)
+	_new_config := new_list_mapper_primitive(newC_uint8_t)(config)
+	resp := PlonkProverImpl.plonk_set_config(&_new_prover, &_new_config)
+	resp_ref, buffer := cvt_ref(cntC_int64_t, refC_int64_t)(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
 var ObjectImpl Object
 
 type Object interface {
@@ -196,6 +339,8 @@ type Object interface {
 	read_from_file(ty *uint64, curve_id *uint64, path *string) int64
 	remove_object(object_id *int64)
 	export_solidity(object_id *int64, type_id *uint64) []uint8
+	constraint_stats(object_id *int64) []uint8
+	recursion_values(object_id *int64) []uint8
 }
 
 //export CObject_serialize
@@ -265,6 +410,28 @@ func CObject_export_solidity(object_id C.int64_t, type_id C.uint64_t, slot *C.vo
 	runtime.KeepAlive(buffer)
 }
 
+//export CObject_constraint_stats
+func CObject_constraint_stats(object_id C.int64_t, slot *C.void, cb *C.void) {
+	_new_object_id := newC_int64_t(object_id)
+	resp := ObjectImpl.constraint_stats(&_new_object_id)
+	resp_ref, buffer := cvt_ref(cnt_list_mapper_primitive(cntC_uint8_t), ref_list_mapper_primitive(refC_uint8_t))(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
+//export CObject_recursion_values
+func CObject_recursion_values(object_id C.int64_t, slot *C.void, cb *C.void) {
+	_new_object_id := newC_int64_t(object_id)
+	resp := ObjectImpl.recursion_values(&_new_object_id)
+	resp_ref, buffer := cvt_ref(cnt_list_mapper_primitive(cntC_uint8_t), ref_list_mapper_primitive(refC_uint8_t))(&resp)
+	asmcall.CallFuncG0P2(unsafe.Pointer(cb), unsafe.Pointer(&resp_ref), unsafe.Pointer(slot))
+	runtime.KeepAlive(resp_ref)
+	runtime.KeepAlive(resp)
+	runtime.KeepAlive(buffer)
+}
+
 // An alternative impl of unsafe.String for go1.18
 func unsafeString(ptr *byte, length int) string {
 	sliceHeader := &reflect.SliceHeader{
@@ -5,8 +5,6 @@ import (
 	"log"
 	"sync"
 
-	"github.com/consensys/gnark/frontend"
-	"github.com/consensys/gnark/frontend/cs/r1cs"
 	"github.com/tiannian/rsnark/provers-gnark/circuit"
 	"github.com/tiannian/rsnark/provers-gnark/prover"
 	"github.com/tiannian/rsnark/provers-gnark/prover/types"
@@ -44,35 +42,30 @@ func (p Groth16ProverCall) groth16_create(curve *uint64) uint64 {
 	return addProver(prover)
 }
 
-func (p Groth16ProverCall) groth16_compile(curve_id *uint64, circuit_data *[]byte) int64 {
-	curveType := types.CurveType(*curve_id)
+func (p Groth16ProverCall) groth16_compile(prover_id *uint64, circuit_data *[]byte) int64 {
+	proverMutex.Lock()
+	prover, proverExists := provers[*prover_id]
+	proverMutex.Unlock()
+	if !proverExists {
+		log.Printf("prover with id %d not found", *prover_id)
+		return -20011
+	}
 
 	// Parse CircuitDefinition from JSON
 	cd, err := circuit.ParseCircuitDefinition(*circuit_data)
 	if err != nil {
-		log.Fatalf("failed to parse circuit definition: %v", err)
+		log.Printf("failed to parse circuit definition: %v", err)
 		return -20013
 	}
 
-	// Create TemplateCircuit from CircuitDefinition
-	templateCircuit, err := circuit.NewTemplateCircuit(cd)
+	// Compile with the options of the prover
+	compiled, err := prover.Compile(cd)
 	if err != nil {
-		log.Fatalf("failed to create template circuit: %v", err)
-		return -20014
-	}
-
-	r1cs, err := frontend.Compile(curveType.ToECC().ScalarField(), r1cs.NewBuilder, templateCircuit)
-	if err != nil {
-		log.Fatalf("failed to compile circuit to R1CS: %v", err)
+		log.Printf("failed to compile circuit: %v", err)
 		return -20015
 	}
 
-	compiled := &types.Groth16CompiledCircuit{
-		CS: r1cs,
-	}
-
 	return addObject(compiled)
-
 }
 
 func (p Groth16ProverCall) groth16_setup(prover_id *uint64, compiled_circuit_id *int64) []byte {
@@ -80,7 +73,7 @@ func (p Groth16ProverCall) groth16_setup(prover_id *uint64, compiled_circuit_id
 	prover, proverExists := provers[*prover_id]
 	proverMutex.Unlock()
 	if !proverExists {
-		log.Fatalf("prover with id %d not found", *prover_id)
+		log.Printf("prover with id %d not found", *prover_id)
 		return int64ToBytes2(-20011, 0)
 	}
 
@@ -89,20 +82,20 @@ func (p Groth16ProverCall) groth16_setup(prover_id *uint64, compiled_circuit_id
 	objectMutex.Unlock()
 
 	if !objExists {
-		log.Fatalf("compiled circuit with id %d not found", *compiled_circuit_id)
+		log.Printf("compiled circuit with id %d not found", *compiled_circuit_id)
 		return int64ToBytes2(-20012, 0)
 	}
 
 	compiled, ok := compiledObj.(*types.Groth16CompiledCircuit)
 	if !ok {
-		log.Fatalf("failed to cast compiled circuit to types.CompiledCircuit")
+		log.Printf("failed to cast compiled circuit to types.CompiledCircuit")
 		return int64ToBytes2(-20003, 0)
 	}
 
 	pk, vk, err := prover.Setup(compiled)
 
 	if err != nil {
-		log.Fatalf("failed to setup Groth16: %v", err)
+		log.Printf("failed to setup Groth16: %v", err)
 		return int64ToBytes2(-20004, 0)
 	}
 
@@ -132,7 +125,7 @@ func (p Groth16ProverCall) groth16_prove(prover_id *uint64, compiled_circuit_id
 	prover, proverExists := provers[*prover_id]
 	proverMutex.Unlock()
 	if !proverExists {
-		log.Fatalf("prover with id %d not found", *prover_id)
+		log.Printf("prover with id %d not found", *prover_id)
 		return -20011
 	}
 
@@ -141,38 +134,38 @@ func (p Groth16ProverCall) groth16_prove(prover_id *uint64, compiled_circuit_id
 
 	pkObj, pkExists := objects[*pk_id]
 	if !pkExists {
-		log.Fatalf("proving key with id %d not found", *pk_id)
+		log.Printf("proving key with id %d not found", *pk_id)
 		return -20012
 	}
 
 	pk, ok := pkObj.(*types.Groth16ProvingKey)
 	if !ok {
-		log.Fatalf("failed to cast pk to types.Groth16ProvingKey")
+		log.Printf("failed to cast pk to types.Groth16ProvingKey")
 		return -20005
 	}
 
 	compiledObj, compiledExists := objects[*compiled_circuit_id]
 	if !compiledExists {
-		log.Fatalf("compiled circuit with id %d not found", *compiled_circuit_id)
+		log.Printf("compiled circuit with id %d not found", *compiled_circuit_id)
 		return -20012
 	}
 
 	compiled, ok := compiledObj.(*types.Groth16CompiledCircuit)
 	if !ok {
-		log.Fatalf("failed to cast compiled circuit to types.CompiledCircuit")
+		log.Printf("failed to cast compiled circuit to types.CompiledCircuit")
 		return -20006
 	}
 
 	var witness types.TemplateWitness
 	err := witness.FromJSON(*witness_data)
 	if err != nil {
-		log.Fatalf("failed to deserialize witness: %v", err)
+		log.Printf("failed to deserialize witness: %v", err)
 		return -20007
 	}
 
 	proof, err := prover.Prove(compiled, pk, &witness)
 	if err != nil {
-		log.Fatalf("failed to prove: %v", err)
+		log.Printf("failed to prove: %v", err)
 		return -20008
 	}
 
@@ -184,7 +177,7 @@ func (p Groth16ProverCall) groth16_verify(prover_id *uint64, vk_id *int64, proof
 	prover, proverExists := provers[*prover_id]
 	proverMutex.Unlock()
 	if !proverExists {
-		log.Fatalf("prover with id %d not found", *prover_id)
+		log.Printf("prover with id %d not found", *prover_id)
 		return -20011
 	}
 
@@ -193,38 +186,38 @@ func (p Groth16ProverCall) groth16_verify(prover_id *uint64, vk_id *int64, proof
 
 	vkObj, vkExists := objects[*vk_id]
 	if !vkExists {
-		log.Fatalf("verifying key with id %d not found", *vk_id)
+		log.Printf("verifying key with id %d not found", *vk_id)
 		return -20012
 	}
 
 	vk, ok := vkObj.(*types.Groth16VerifyingKey)
 	if !ok {
-		log.Fatalf("failed to cast vk to types.Groth16VerifyingKey")
+		log.Printf("failed to cast vk to types.Groth16VerifyingKey")
 		return -20009
 	}
 
 	var public_witness types.TemplatePublicWitness
 	err := public_witness.FromJSON(*public_witness_data)
 	if err != nil {
-		log.Fatalf("failed to deserialize public witness: %v", err)
+		log.Printf("failed to deserialize public witness: %v", err)
 		return -20007
 	}
 
 	proofObj, proofExists := objects[*proof_id]
 	if !proofExists {
-		log.Fatalf("proof with id %d not found", *proof_id)
+		log.Printf("proof with id %d not found", *proof_id)
 		return -20013
 	}
 
 	proof, ok := proofObj.(*types.Groth16Proof)
 	if !ok {
-		log.Fatalf("failed to cast proof to types.Groth16Proof")
+		log.Printf("failed to cast proof to types.Groth16Proof")
 		return -20014
 	}
 
 	err = prover.Verify(proof, vk, &public_witness)
 	if err != nil {
-		log.Fatalf("failed to verify: %v", err)
+		log.Printf("failed to verify: %v", err)
 		return -20010
 	}
 
@@ -237,3 +230,39 @@ func (p Groth16ProverCall) groth16_remove_prover(prover_id *uint64) {
 
 	delete(provers, *prover_id)
 }
+
+func (p Groth16ProverCall) groth16_set_config(prover_id *uint64, config_data *[]byte) int64 {
+	config, err := prover.ParseConfig(*config_data)
+	if err != nil {
+		log.Printf("failed to parse config: %v", err)
+		return -10012
+	}
+
+	proverMutex.Lock()
+	defer proverMutex.Unlock()
+
+	prover, exists := provers[*prover_id]
+	if !exists {
+		log.Printf("prover with id %d not found", *prover_id)
+		return -20011
+	}
+
+	prover.SetConfig(config)
+
+	return 0
+}
+
+func (p Groth16ProverCall) groth16_set_seed(prover_id *uint64, seed *uint64) int64 {
+	proverMutex.Lock()
+	defer proverMutex.Unlock()
+
+	prover, exists := provers[*prover_id]
+	if !exists {
+		log.Printf("prover with id %d not found", *prover_id)
+		return -20011
+	}
+
+	prover.SetSeed(*seed)
+
+	return 0
+}
@@ -1,10 +1,13 @@
 package main
 
 import (
+	"fmt"
 	"log"
+	"math/big"
 	"os"
 	"sync"
 
+	"github.com/consensys/gnark/constraint"
 	"github.com/tiannian/rsnark/provers-gnark/prover/types"
 )
 
@@ -42,34 +45,44 @@ func (o ObjectCall) serialize(object_id *int64) []byte {
 
 	object, exists := objects[*object_id]
 	if !exists {
-		log.Fatalf("object with id %d not found", *object_id)
-		return []byte{}
+		log.Printf("object with id %d not found", *object_id)
+		return int64ToBytes(-20012)
 	}
 
 	data, err := object.Serialize()
 	if err != nil {
-		log.Fatalf("failed to serialize object: %v", err)
-		return []byte{}
+		log.Printf("failed to serialize object: %v", err)
+		return int64ToBytes(-10003)
 	}
 
-	return data
+	return append(int64ToBytes(0), data...)
 }
 
 func (o ObjectCall) deserialize(ty *uint64, curve_id *uint64, data *[]byte) int64 {
 
 	switch *ty {
 	case 1:
-		return deserializeObject[*types.Groth16ProvingKey](*curve_id, data)
+		return deserializeObject[types.Groth16ProvingKey](*curve_id, data)
 	case 2:
-		return deserializeObject[*types.Groth16VerifyingKey](*curve_id, data)
+		return deserializeObject[types.Groth16VerifyingKey](*curve_id, data)
 	case 3:
-		return deserializeObject[*types.Groth16CompiledCircuit](*curve_id, data)
+		return deserializeObject[types.Groth16CompiledCircuit](*curve_id, data)
 	case 4:
-		return deserializeObject[*types.PlonkProvingKey](*curve_id, data)
+		return deserializeObject[types.Groth16Proof](*curve_id, data)
 	case 5:
-		return deserializeObject[*types.PlonkVerifyingKey](*curve_id, data)
+		return deserializeObject[types.PlonkProvingKey](*curve_id, data)
 	case 6:
-		return deserializeObject[*types.PlonkCompiledCircuit](*curve_id, data)
+		return deserializeObject[types.PlonkVerifyingKey](*curve_id, data)
+	case 7:
+		return deserializeObject[types.PlonkProof](*curve_id, data)
+	case 8:
+		return deserializeObject[types.Groth16Phase1](*curve_id, data)
+	case 9:
+		return deserializeObject[types.Groth16Phase2](*curve_id, data)
+	case 10:
+		return deserializeObject[types.Groth16Phase2Evaluations](*curve_id, data)
+	case 11:
+		return deserializeObject[types.PlonkCompiledCircuit](*curve_id, data)
 	}
 	return 0
 }
@@ -79,19 +92,19 @@ func (o ObjectCall) write_to_file(object_id *int64, path *string) int64 {
 	defer objectMutex.Unlock()
 	object, exists := objects[*object_id]
 	if !exists {
-		log.Fatalf("object with id %d not found", *object_id)
+		log.Printf("object with id %d not found", *object_id)
 		return -20012
 	}
 
 	data, err := object.Serialize()
 	if err != nil {
-		log.Fatalf("failed to write object to file: %v", err)
+		log.Printf("failed to write object to file: %v", err)
 		return -10003
 	}
 
 	err = os.WriteFile(*path, data, 0644)
 	if err != nil {
-		log.Fatalf("failed to write object to file: %v", err)
+		log.Printf("failed to write object to file: %v", err)
 		return -10004
 	}
 	return 0
@@ -100,39 +113,55 @@ func (o ObjectCall) write_to_file(object_id *int64, path *string) int64 {
 func (o ObjectCall) read_from_file(ty *uint64, curve_id *uint64, path *string) int64 {
 	switch *ty {
 	case 1:
-		return readFromFile[*types.Groth16ProvingKey](*curve_id, path)
+		return readFromFile[types.Groth16ProvingKey](*curve_id, path)
 	case 2:
-		return readFromFile[*types.Groth16VerifyingKey](*curve_id, path)
+		return readFromFile[types.Groth16VerifyingKey](*curve_id, path)
 	case 3:
-		return readFromFile[*types.Groth16CompiledCircuit](*curve_id, path)
+		return readFromFile[types.Groth16CompiledCircuit](*curve_id, path)
 	case 4:
-		return readFromFile[*types.PlonkProvingKey](*curve_id, path)
+		return readFromFile[types.Groth16Proof](*curve_id, path)
 	case 5:
-		return readFromFile[*types.PlonkVerifyingKey](*curve_id, path)
+		return readFromFile[types.PlonkProvingKey](*curve_id, path)
 	case 6:
-		return readFromFile[*types.PlonkCompiledCircuit](*curve_id, path)
+		return readFromFile[types.PlonkVerifyingKey](*curve_id, path)
+	case 7:
+		return readFromFile[types.PlonkProof](*curve_id, path)
+	case 8:
+		return readFromFile[types.Groth16Phase1](*curve_id, path)
+	case 9:
+		return readFromFile[types.Groth16Phase2](*curve_id, path)
+	case 10:
+		return readFromFile[types.Groth16Phase2Evaluations](*curve_id, path)
+	case 11:
+		return readFromFile[types.PlonkCompiledCircuit](*curve_id, path)
 	}
 
 	return 0
 }
 
-func readFromFile[T types.SerializableObject](curve_id uint64, path *string) int64 {
+func readFromFile[T any, P deserializable[T]](curve_id uint64, path *string) int64 {
 	data, err := os.ReadFile(*path)
 	if err != nil {
-		log.Fatalf("failed to read file: %v", err)
+		log.Printf("failed to read file: %v", err)
 		return -10002
 	}
 
-	return deserializeObject[T](curve_id, &data)
+	return deserializeObject[T, P](curve_id, &data)
+}
+
+// deserializable is a pointer to T which can be deserialized in place
+type deserializable[T any] interface {
+	*T
+	types.SerializableObject
 }
 
-func deserializeObject[T types.SerializableObject](curve_id uint64, data *[]byte) int64 {
+func deserializeObject[T any, P deserializable[T]](curve_id uint64, data *[]byte) int64 {
 	curve := types.CurveType(curve_id)
 
-	var object T
+	object := P(new(T))
 	err := object.Deserialize(*data, curve)
 	if err != nil {
-		log.Fatalf("failed to deserialize object: %v", err)
+		log.Printf("failed to deserialize object: %v", err)
 		return -10001
 	}
 
@@ -160,25 +189,94 @@ func exportSolidityContract[T types.ToSolidityObject](object_id *int64) []byte {
 	defer objectMutex.Unlock()
 	object, exists := objects[*object_id]
 	if !exists {
-		log.Fatalf("object with id %d not found", *object_id)
+		log.Printf("object with id %d not found", *object_id)
 		return int64ToBytes(-20012)
 	}
 
 	pk, ok := object.(T)
 	if !ok {
-		log.Fatalf("failed to cast object to types.Groth16VerifyingKey")
+		log.Printf("failed to cast object to types.Groth16VerifyingKey")
 		return int64ToBytes(-10005)
 	}
 
 	solidity, err := pk.ExportSolidity()
 	if err != nil {
-		log.Fatalf("failed to export solidity: %v", err)
+		log.Printf("failed to export solidity: %v", err)
 		return int64ToBytes(-10006)
 	}
 
 	return append(int64ToBytes(0), solidity...)
 }
 
+func (o ObjectCall) constraint_stats(object_id *int64) []byte {
+	objectMutex.Lock()
+	defer objectMutex.Unlock()
+	object, exists := objects[*object_id]
+	if !exists {
+		log.Printf("object with id %d not found", *object_id)
+		return int64ToBytes(-20012)
+	}
+
+	var cs constraint.ConstraintSystem
+	switch compiled := object.(type) {
+	case *types.Groth16CompiledCircuit:
+		cs = compiled.CS
+	case *types.PlonkCompiledCircuit:
+		cs = compiled.CS
+	default:
+		log.Printf("object with id %d is not a compiled circuit", *object_id)
+		return int64ToBytes(-20003)
+	}
+
+	counts := []int{
+		cs.GetNbConstraints(),
+		cs.GetNbInternalVariables(),
+		cs.GetNbPublicVariables(),
+		cs.GetNbSecretVariables(),
+	}
+
+	res := int64ToBytes(0)
+	for _, count := range counts {
+		res = append(res, int64ToBytes(int64(count))...)
+	}
+
+	return res
+}
+
+func (o ObjectCall) recursion_values(object_id *int64) []byte {
+	objectMutex.Lock()
+	defer objectMutex.Unlock()
+	object, exists := objects[*object_id]
+	if !exists {
+		log.Printf("object with id %d not found", *object_id)
+		return int64ToBytes(-20012)
+	}
+
+	var values []*big.Int
+	var err error
+	switch obj := object.(type) {
+	case *types.Groth16Proof:
+		values, err = obj.RecursionValues()
+	case *types.Groth16VerifyingKey:
+		values, err = obj.RecursionValues()
+	case *types.PlonkProof:
+		values, err = obj.RecursionValues()
+	default:
+		err = fmt.Errorf("object with id %d cannot be verified in a circuit", *object_id)
+	}
+	if err != nil {
+		log.Printf("failed to convert object to recursion values: %v", err)
+		return int64ToBytes(-10008)
+	}
+
+	res := int64ToBytes(0)
+	for _, value := range values {
+		res = append(res, value.FillBytes(make([]byte, types.RecursionValueBytes))...)
+	}
+
+	return res
+}
+
 func (o ObjectCall) remove_object(object_id *int64) {
 	objectMutex.Lock()
 	defer objectMutex.Unlock()
@@ -1,11 +1,10 @@
 package main
 
 import (
+	"errors"
 	"log"
 	"sync"
 
-	"github.com/consensys/gnark/frontend"
-	"github.com/consensys/gnark/frontend/cs/scs"
 	"github.com/tiannian/rsnark/provers-gnark/circuit"
 	"github.com/tiannian/rsnark/provers-gnark/prover"
 	"github.com/tiannian/rsnark/provers-gnark/prover/types"
@@ -43,33 +42,29 @@ func (p PlonkProverCall) plonk_create(curve *uint64) uint64 {
 	return addPlonkProver(prover)
 }
 
-func (p PlonkProverCall) plonk_compile(curve_id *uint64, circuit_data *[]byte) int64 {
-	curveType := types.CurveType(*curve_id)
+func (p PlonkProverCall) plonk_compile(prover_id *uint64, circuit_data *[]byte) int64 {
+	plonkProverMutex.Lock()
+	prover, proverExists := plonkProvers[*prover_id]
+	plonkProverMutex.Unlock()
+	if !proverExists {
+		log.Printf("PLONK prover with id %d not found", *prover_id)
+		return -20011
+	}
 
 	// Parse CircuitDefinition from JSON
 	cd, err := circuit.ParseCircuitDefinition(*circuit_data)
 	if err != nil {
-		log.Fatalf("failed to parse circuit definition: %v", err)
+		log.Printf("failed to parse circuit definition: %v", err)
 		return -20013
 	}
 
-	// Create TemplateCircuit from CircuitDefinition
-	templateCircuit, err := circuit.NewTemplateCircuit(cd)
-	if err != nil {
-		log.Fatalf("failed to create template circuit: %v", err)
-		return -20014
-	}
-
-	scs, err := frontend.Compile(curveType.ToECC().ScalarField(), scs.NewBuilder, templateCircuit)
+	// Compile with the options of the prover
+	compiled, err := prover.Compile(cd)
 	if err != nil {
-		log.Fatalf("failed to compile circuit to R1CS: %v", err)
+		log.Printf("failed to compile circuit: %v", err)
 		return -20015
 	}
 
-	compiled := &types.PlonkCompiledCircuit{
-		CS: scs,
-	}
-
 	return addObject(compiled)
 }
 
@@ -78,7 +73,7 @@ func (p PlonkProverCall) plonk_setup(prover_id *uint64, compiled_circuit_id *int
 	prover, proverExists := plonkProvers[*prover_id]
 	plonkProverMutex.Unlock()
 	if !proverExists {
-		log.Fatalf("PLONK prover with id %d not found", *prover_id)
+		log.Printf("PLONK prover with id %d not found", *prover_id)
 		return int64ToBytes2(-20011, 0)
 	}
 
@@ -87,20 +82,24 @@ func (p PlonkProverCall) plonk_setup(prover_id *uint64, compiled_circuit_id *int
 	objectMutex.Unlock()
 
 	if !objExists {
-		log.Fatalf("compiled circuit with id %d not found", *compiled_circuit_id)
+		log.Printf("compiled circuit with id %d not found", *compiled_circuit_id)
 		return int64ToBytes2(-20012, 0)
 	}
 
 	compiled, ok := compiledObj.(*types.PlonkCompiledCircuit)
 	if !ok {
-		log.Fatalf("failed to cast compiled circuit to types.PlonkCompiledCircuit")
+		log.Printf("failed to cast compiled circuit to types.PlonkCompiledCircuit")
 		return int64ToBytes2(-20003, 0)
 	}
 
 	pk, vk, err := prover.Setup(compiled)
 
+	if code := srsErrorCode(err); code != 0 {
+		log.Printf("failed to setup PLONK: %v", err)
+		return int64ToBytes2(code, 0)
+	}
 	if err != nil {
-		log.Fatalf("failed to setup PLONK: %v", err)
+		log.Printf("failed to setup PLONK: %v", err)
 		return int64ToBytes2(-20004, 0)
 	}
 
@@ -115,7 +114,7 @@ func (p PlonkProverCall) plonk_prove(prover_id *uint64, compiled_circuit_id *int
 	prover, proverExists := plonkProvers[*prover_id]
 	plonkProverMutex.Unlock()
 	if !proverExists {
-		log.Fatalf("PLONK prover with id %d not found", *prover_id)
+		log.Printf("PLONK prover with id %d not found", *prover_id)
 		return -20011
 	}
 
@@ -124,38 +123,38 @@ func (p PlonkProverCall) plonk_prove(prover_id *uint64, compiled_circuit_id *int
 
 	pkObj, pkExists := objects[*pk_id]
 	if !pkExists {
-		log.Fatalf("PLONK proving key with id %d not found", *pk_id)
+		log.Printf("PLONK proving key with id %d not found", *pk_id)
 		return -20012
 	}
 
 	pk, ok := pkObj.(*types.PlonkProvingKey)
 	if !ok {
-		log.Fatalf("failed to cast pk to types.PlonkProvingKey")
+		log.Printf("failed to cast pk to types.PlonkProvingKey")
 		return -20005
 	}
 
 	compiledObj, compiledExists := objects[*compiled_circuit_id]
 	if !compiledExists {
-		log.Fatalf("compiled circuit with id %d not found", *compiled_circuit_id)
+		log.Printf("compiled circuit with id %d not found", *compiled_circuit_id)
 		return -20012
 	}
 
 	compiled, ok := compiledObj.(*types.PlonkCompiledCircuit)
 	if !ok {
-		log.Fatalf("failed to cast compiled circuit to types.CompiledCircuit")
+		log.Printf("failed to cast compiled circuit to types.CompiledCircuit")
 		return -20006
 	}
 
 	var witness types.TemplateWitness
 	err := witness.FromJSON(*witness_data)
 	if err != nil {
-		log.Fatalf("failed to deserialize witness: %v", err)
+		log.Printf("failed to deserialize witness: %v", err)
 		return -20007
 	}
 
 	proof, err := prover.Prove(compiled, pk, &witness)
 	if err != nil {
-		log.Fatalf("failed to prove: %v", err)
+		log.Printf("failed to prove: %v", err)
 		return -20008
 	}
 
@@ -167,7 +166,7 @@ func (p PlonkProverCall) plonk_verify(prover_id *uint64, vk_id *int64, proof_id
 	prover, proverExists := plonkProvers[*prover_id]
 	plonkProverMutex.Unlock()
 	if !proverExists {
-		log.Fatalf("PLONK prover with id %d not found", *prover_id)
+		log.Printf("PLONK prover with id %d not found", *prover_id)
 		return -20011
 	}
 
@@ -176,47 +175,126 @@ func (p PlonkProverCall) plonk_verify(prover_id *uint64, vk_id *int64, proof_id
 
 	vkObj, vkExists := objects[*vk_id]
 	if !vkExists {
-		log.Fatalf("PLONK verifying key with id %d not found", *vk_id)
+		log.Printf("PLONK verifying key with id %d not found", *vk_id)
 		return -20012
 	}
 
 	vk, ok := vkObj.(*types.PlonkVerifyingKey)
 	if !ok {
-		log.Fatalf("failed to cast vk to types.PlonkVerifyingKey")
+		log.Printf("failed to cast vk to types.PlonkVerifyingKey")
 		return -20009
 	}
 
 	var public_witness types.TemplatePublicWitness
 	err := public_witness.FromJSON(*public_witness_data)
 	if err != nil {
-		log.Fatalf("failed to deserialize public witness: %v", err)
+		log.Printf("failed to deserialize public witness: %v", err)
 		return -20007
 	}
 
 	proofObj, proofExists := objects[*proof_id]
 	if !proofExists {
-		log.Fatalf("proof with id %d not found", *proof_id)
+		log.Printf("proof with id %d not found", *proof_id)
 		return -20013
 	}
 
 	proof, ok := proofObj.(*types.PlonkProof)
 	if !ok {
-		log.Fatalf("failed to cast proof to types.PlonkProof")
+		log.Printf("failed to cast proof to types.PlonkProof")
 		return -20014
 	}
 
 	err = prover.Verify(proof, vk, &public_witness)
 	if err != nil {
-		log.Fatalf("failed to verify: %v", err)
+		log.Printf("failed to verify: %v", err)
 		return -20010
 	}
 
 	return int64(0)
 }
 
+func (p PlonkProverCall) plonk_set_recursion(prover_id *uint64) int64 {
+	plonkProverMutex.Lock()
+	defer plonkProverMutex.Unlock()
+
+	prover, exists := plonkProvers[*prover_id]
+	if !exists {
+		log.Printf("PLONK prover with id %d not found", *prover_id)
+		return -20011
+	}
+
+	prover.SetRecursion()
+
+	return 0
+}
+
+func (p PlonkProverCall) plonk_set_srs(prover_id *uint64, format *uint64, path *string) int64 {
+	plonkProverMutex.Lock()
+	defer plonkProverMutex.Unlock()
+
+	plonkProver, exists := plonkProvers[*prover_id]
+	if !exists {
+		log.Printf("PLONK prover with id %d not found", *prover_id)
+		return -20011
+	}
+
+	plonkProver.SetSRS(prover.SRSSource{Format: prover.SRSFormat(*format), Path: *path})
+
+	return 0
+}
+
+func (p PlonkProverCall) plonk_set_config(prover_id *uint64, config_data *[]byte) int64 {
+	config, err := prover.ParseConfig(*config_data)
+	if err != nil {
+		log.Printf("failed to parse config: %v", err)
+		return -10012
+	}
+
+	plonkProverMutex.Lock()
+	defer plonkProverMutex.Unlock()
+
+	plonkProver, exists := plonkProvers[*prover_id]
+	if !exists {
+		log.Printf("PLONK prover with id %d not found", *prover_id)
+		return -20011
+	}
+
+	plonkProver.SetConfig(config)
+
+	return 0
+}
+
+func (p PlonkProverCall) plonk_set_seed(prover_id *uint64, seed *uint64) int64 {
+	plonkProverMutex.Lock()
+	defer plonkProverMutex.Unlock()
+
+	plonkProver, exists := plonkProvers[*prover_id]
+	if !exists {
+		log.Printf("PLONK prover with id %d not found", *prover_id)
+		return -20011
+	}
+
+	plonkProver.SetSeed(*seed)
+
+	return 0
+}
+
 func (p PlonkProverCall) plonk_remove_prover(prover_id *uint64) {
 	plonkProverMutex.Lock()
 	defer plonkProverMutex.Unlock()
 
 	delete(plonkProvers, *prover_id)
 }
+
+// srsErrorCode returns the error code of a setup that failed to get its KZG
+// SRS, or 0 for other errors
+func srsErrorCode(err error) int64 {
+	switch {
+	case errors.Is(err, prover.ErrMissingSRS):
+		return -10009
+	case errors.Is(err, prover.ErrSRS):
+		return -10010
+	default:
+		return 0
+	}
+}
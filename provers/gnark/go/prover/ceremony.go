@@ -0,0 +1,99 @@
+package prover
+
+import (
+	"errors"
+	"fmt"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark/backend/groth16/bn254/mpcsetup"
+	cs_bn254 "github.com/consensys/gnark/constraint/bn254"
+
+	"github.com/tiannian/rsnark/provers-gnark/prover/types"
+)
+
+// ErrCeremony is returned when a step of the Groth16 MPC setup fails, such as
+// the verification of a contribution
+var ErrCeremony = errors.New("invalid MPC setup")
+
+// insecurePhase1Beacon seals the test phase 1
+var insecurePhase1Beacon = []byte("rsnark insecure test phase 1")
+
+func ceremonyR1CS(compiled *types.Groth16CompiledCircuit) (*cs_bn254.R1CS, error) {
+	r1cs, ok := compiled.CS.(*cs_bn254.R1CS)
+	if !ok {
+		return nil, fmt.Errorf("%w: MPC setup is only supported on BN254", ErrCeremony)
+	}
+	return r1cs, nil
+}
+
+// InsecurePhase1 runs a phase 1 with a single contribution, large enough for
+// the circuit. It is meant for tests, real ceremonies import a phase 1
+// transcript.
+func InsecurePhase1(compiled *types.Groth16CompiledCircuit) (*types.Groth16Phase1, error) {
+	r1cs, err := ceremonyR1CS(compiled)
+	if err != nil {
+		return nil, err
+	}
+
+	var phase1 mpcsetup.Phase1
+	phase1.Initialize(ecc.NextPowerOfTwo(uint64(r1cs.GetNbConstraints())))
+	randomnessMutex.RLock()
+	phase1.Contribute()
+	randomnessMutex.RUnlock()
+	commons := phase1.Seal(insecurePhase1Beacon)
+
+	return &types.Groth16Phase1{Commons: &commons}, nil
+}
+
+// InitializePhase2 starts the phase 2 of a circuit from the output of phase 1
+func InitializePhase2(phase1 *types.Groth16Phase1, compiled *types.Groth16CompiledCircuit) (*types.Groth16Phase2, *types.Groth16Phase2Evaluations, error) {
+	r1cs, err := ceremonyR1CS(compiled)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	if size := len(phase1.Commons.G1.AlphaTau); size < r1cs.GetNbConstraints() {
+		return nil, nil, fmt.Errorf("%w: phase 1 has %d powers of tau, the circuit has %d constraints", ErrCeremony, size, r1cs.GetNbConstraints())
+	}
+
+	var phase2 mpcsetup.Phase2
+	evaluations := phase2.Initialize(r1cs, phase1.Commons)
+
+	return &types.Groth16Phase2{Phase: &phase2}, &types.Groth16Phase2Evaluations{Evaluations: &evaluations}, nil
+}
+
+// ContributePhase2 returns the phase 2 state after a contribution with fresh
+// randomness, leaving phase untouched
+func ContributePhase2(phase *types.Groth16Phase2) (*types.Groth16Phase2, error) {
+	next, err := phase.Clone()
+	if err != nil {
+		return nil, err
+	}
+
+	randomnessMutex.RLock()
+	next.Phase.Contribute()
+	randomnessMutex.RUnlock()
+	return next, nil
+}
+
+// VerifyPhase2 checks that next is a valid contribution on top of phase
+func VerifyPhase2(phase, next *types.Groth16Phase2) error {
+	if err := phase.Phase.Verify(next.Phase); err != nil {
+		return fmt.Errorf("%w: %w", ErrCeremony, err)
+	}
+	return nil
+}
+
+// SealPhase2 applies the random beacon to the last phase 2 state and extracts
+// the keys. Contributions must have been verified beforehand.
+func SealPhase2(phase1 *types.Groth16Phase1, evaluations *types.Groth16Phase2Evaluations, phase *types.Groth16Phase2, beacon []byte) (*types.Groth16ProvingKey, *types.Groth16VerifyingKey, error) {
+	// Sealing mutates the state
+	last, err := phase.Clone()
+	if err != nil {
+		return nil, nil, err
+	}
+
+	pk, vk := last.Phase.Seal(phase1.Commons, evaluations.Evaluations, beacon)
+
+	return &types.Groth16ProvingKey{Key: &pk}, &types.Groth16VerifyingKey{Key: &vk}, nil
+}
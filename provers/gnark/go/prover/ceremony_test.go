@@ -0,0 +1,109 @@
+package prover
+
+import (
+	"errors"
+	"math/big"
+	"testing"
+
+	"github.com/tiannian/rsnark/provers-gnark/circuit"
+	"github.com/tiannian/rsnark/provers-gnark/prover/types"
+)
+
+func compileTestGroth16Circuit(t *testing.T, prover *Groth16Prover) *types.Groth16CompiledCircuit {
+	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testCircuitJSON))
+	if err != nil {
+		t.Fatalf("Failed to create circuit definition: %v", err)
+	}
+	compiled, err := prover.Compile(circuitDef)
+	if err != nil {
+		t.Fatalf("Failed to compile circuit: %v", err)
+	}
+	return compiled
+}
+
+// runTestPhase2 initializes a phase 2 and makes n contributions
+func runTestPhase2(t *testing.T, compiled *types.Groth16CompiledCircuit, n int) (*types.Groth16Phase1, *types.Groth16Phase2Evaluations, []*types.Groth16Phase2) {
+	phase1, err := InsecurePhase1(compiled)
+	if err != nil {
+		t.Fatalf("Failed to run phase 1: %v", err)
+	}
+
+	phase2, evaluations, err := InitializePhase2(phase1, compiled)
+	if err != nil {
+		t.Fatalf("Failed to initialize phase 2: %v", err)
+	}
+
+	transcript := []*types.Groth16Phase2{phase2}
+	for range n {
+		next, err := ContributePhase2(transcript[len(transcript)-1])
+		if err != nil {
+			t.Fatalf("Failed to contribute: %v", err)
+		}
+		transcript = append(transcript, next)
+	}
+
+	return phase1, evaluations, transcript
+}
+
+func TestCeremonyProveAndVerify(t *testing.T) {
+	prover := NewGroth16Prover(types.CurveBN254)
+	compiled := compileTestGroth16Circuit(t, prover)
+
+	phase1, evaluations, transcript := runTestPhase2(t, compiled, 2)
+	for i := 1; i < len(transcript); i++ {
+		if err := VerifyPhase2(transcript[i-1], transcript[i]); err != nil {
+			t.Fatalf("Failed to verify contribution %d: %v", i, err)
+		}
+	}
+
+	pk, vk, err := SealPhase2(phase1, evaluations, transcript[len(transcript)-1], []byte("beacon"))
+	if err != nil {
+		t.Fatalf("Failed to seal phase 2: %v", err)
+	}
+
+	witness := types.NewTemplateWitness(
+		[]*big.Int{big.NewInt(8)},
+		[]*big.Int{big.NewInt(3), big.NewInt(5)},
+	)
+	proof, err := prover.Prove(compiled, pk, witness)
+	if err != nil {
+		t.Fatalf("Failed to generate proof: %v", err)
+	}
+	if err := prover.Verify(proof, vk, types.NewTemplatePublicWitnessFromTemplate(witness)); err != nil {
+		t.Fatalf("Failed to verify proof: %v", err)
+	}
+}
+
+func TestCeremonyRejectsSkippedContribution(t *testing.T) {
+	compiled := compileTestGroth16Circuit(t, NewGroth16Prover(types.CurveBN254))
+
+	_, _, transcript := runTestPhase2(t, compiled, 2)
+
+	// The second contribution does not build on the initial state
+	err := VerifyPhase2(transcript[0], transcript[2])
+	if !errors.Is(err, ErrCeremony) {
+		t.Fatalf("Expected ErrCeremony, got %v", err)
+	}
+}
+
+func TestCeremonyPhase2Serialization(t *testing.T) {
+	compiled := compileTestGroth16Circuit(t, NewGroth16Prover(types.CurveBN254))
+
+	_, _, transcript := runTestPhase2(t, compiled, 1)
+
+	data, err := transcript[1].Serialize()
+	if err != nil {
+		t.Fatalf("Failed to serialize phase 2: %v", err)
+	}
+	var restored types.Groth16Phase2
+	if err := restored.Deserialize(data, types.CurveBN254); err != nil {
+		t.Fatalf("Failed to deserialize phase 2: %v", err)
+	}
+
+	if err := VerifyPhase2(transcript[0], &restored); err != nil {
+		t.Fatalf("Failed to verify restored contribution: %v", err)
+	}
+	if err := restored.Deserialize(data, types.CurveBLS12_381); err == nil {
+		t.Error("Expected error when deserializing phase 2 over BLS12-381")
+	}
+}
@@ -0,0 +1,73 @@
+package prover
+
+import (
+	"encoding/json"
+	"fmt"
+	"math/big"
+	"runtime"
+
+	"github.com/consensys/gnark/constraint"
+	"github.com/consensys/gnark/frontend"
+	"github.com/consensys/gnark/profile"
+)
+
+// Config configures a prover, decoded from the JSON of the Rust GnarkConfig
+type Config struct {
+	// MaxProcs sets GOMAXPROCS for the whole Go runtime
+	MaxProcs *int `json:"max_procs"`
+	// Profile is where a pprof profile of the constraints of each compiled
+	// circuit is written
+	Profile *string `json:"profile"`
+	// Compile holds the options of frontend.Compile
+	Compile CompileConfig `json:"compile"`
+	// InsecureSeed makes Setup deterministic and insecure
+	InsecureSeed *uint64 `json:"insecure_seed"`
+}
+
+// CompileConfig holds the options of frontend.Compile
+type CompileConfig struct {
+	Capacity                  *int `json:"capacity"`
+	IgnoreUnconstrainedInputs bool `json:"ignore_unconstrained_inputs"`
+	CompressThreshold         *int `json:"compress_threshold"`
+}
+
+// ParseConfig decodes a Config from JSON
+func ParseConfig(data []byte) (Config, error) {
+	var config Config
+	if err := json.Unmarshal(data, &config); err != nil {
+		return Config{}, fmt.Errorf("failed to parse prover config: %w", err)
+	}
+	return config, nil
+}
+
+// apply sets the process-wide parts of the configuration
+func (c *Config) apply() {
+	if c.MaxProcs != nil {
+		runtime.GOMAXPROCS(*c.MaxProcs)
+	}
+}
+
+func (c *CompileConfig) options() []frontend.CompileOption {
+	var options []frontend.CompileOption
+	if c.Capacity != nil {
+		options = append(options, frontend.WithCapacity(*c.Capacity))
+	}
+	if c.IgnoreUnconstrainedInputs {
+		options = append(options, frontend.IgnoreUnconstrainedInputs())
+	}
+	if c.CompressThreshold != nil {
+		options = append(options, frontend.WithCompressThreshold(*c.CompressThreshold))
+	}
+	return options
+}
+
+// compile compiles a circuit with the configured options, recording its
+// constraint profile if one is configured
+func (c *Config) compile(field *big.Int, newBuilder frontend.NewBuilder, circuit frontend.Circuit) (constraint.ConstraintSystem, error) {
+	if c.Profile != nil {
+		p := profile.Start(profile.WithPath(*c.Profile))
+		defer p.Stop()
+	}
+
+	return frontend.Compile(field, newBuilder, circuit, c.Compile.options()...)
+}
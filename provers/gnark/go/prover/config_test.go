@@ -0,0 +1,74 @@
+package prover
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/tiannian/rsnark/provers-gnark/prover/types"
+)
+
+func TestParseConfig(t *testing.T) {
+	config, err := ParseConfig([]byte(`{
+  "max_procs": null,
+  "profile": "/tmp/circuit.pprof",
+  "compile": {
+    "capacity": 16,
+    "ignore_unconstrained_inputs": true,
+    "compress_threshold": null
+  },
+  "insecure_seed": 7
+}`))
+	if err != nil {
+		t.Fatalf("Failed to parse config: %v", err)
+	}
+
+	if config.MaxProcs != nil || config.Compile.CompressThreshold != nil {
+		t.Error("Expected unset options to be nil")
+	}
+	if config.Profile == nil || *config.Profile != "/tmp/circuit.pprof" {
+		t.Errorf("Unexpected profile: %v", config.Profile)
+	}
+	if config.InsecureSeed == nil || *config.InsecureSeed != 7 {
+		t.Errorf("Unexpected seed: %v", config.InsecureSeed)
+	}
+	if len(config.Compile.options()) != 2 {
+		t.Errorf("Expected 2 compile options, got %d", len(config.Compile.options()))
+	}
+
+	if _, err := ParseConfig([]byte(`{"max_procs": "all"}`)); err == nil {
+		t.Error("Expected an invalid config to fail")
+	}
+}
+
+func TestConfigProfile(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "circuit.pprof")
+
+	prover := NewPlonkProver(types.CurveBN254)
+	prover.SetConfig(Config{Profile: &path})
+	compileTestPlonkCircuit(t, prover)
+
+	if info, err := os.Stat(path); err != nil || info.Size() == 0 {
+		t.Errorf("Expected a profile to be written to %s: %v", path, err)
+	}
+}
+
+func TestConfigSeed(t *testing.T) {
+	seed := uint64(1)
+
+	prover := NewGroth16Prover(types.CurveBN254)
+	prover.SetConfig(Config{InsecureSeed: &seed})
+	_, vk, err := prover.Setup(compileTestGroth16Circuit(t, prover))
+	if err != nil {
+		t.Fatalf("Failed to setup Groth16: %v", err)
+	}
+	data, err := vk.Serialize()
+	if err != nil {
+		t.Fatalf("Failed to serialize verifying key: %v", err)
+	}
+
+	if !bytes.Equal(data, seededGroth16VerifyingKey(t, seed)) {
+		t.Error("Expected the seed of the config to be used")
+	}
+}
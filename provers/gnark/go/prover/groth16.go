@@ -3,7 +3,10 @@ package prover
 import (
 	"fmt"
 
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark/backend"
 	"github.com/consensys/gnark/backend/groth16"
+	"github.com/consensys/gnark/backend/solidity"
 	"github.com/consensys/gnark/frontend"
 	"github.com/consensys/gnark/frontend/cs/r1cs"
 
@@ -14,6 +17,10 @@ import (
 // Groth16Prover represents the Groth16 prover interface
 type Groth16Prover struct {
 	curve types.CurveType
+	// seed makes Setup deterministic and insecure when it is set
+	seed *uint64
+	// config holds the options of Compile
+	config Config
 }
 
 // NewGroth16Prover creates a new Groth16 prover instance
@@ -27,6 +34,37 @@ func (p *Groth16Prover) CurveId() types.CurveType {
 	return p.curve
 }
 
+// SetConfig configures the prover. GOMAXPROCS applies to the whole runtime.
+func (p *Groth16Prover) SetConfig(config Config) {
+	config.apply()
+	p.config = config
+	if config.InsecureSeed != nil {
+		p.SetSeed(*config.InsecureSeed)
+	}
+}
+
+// SetSeed makes Setup draw its toxic waste from seed, which anyone knowing the
+// seed can use to forge proofs
+func (p *Groth16Prover) SetSeed(seed uint64) {
+	p.seed = &seed
+}
+
+// proverOptions makes BN254 proofs with commitments, such as the ones of
+// circuits verifying other proofs, verifiable by the exported Solidity verifier
+func (p *Groth16Prover) proverOptions() []backend.ProverOption {
+	if p.curve.ToECC() != ecc.BN254 {
+		return nil
+	}
+	return []backend.ProverOption{solidity.WithProverTargetSolidityVerifier(backend.GROTH16)}
+}
+
+func (p *Groth16Prover) verifierOptions() []backend.VerifierOption {
+	if p.curve.ToECC() != ecc.BN254 {
+		return nil
+	}
+	return []backend.VerifierOption{solidity.WithVerifierTargetSolidityVerifier(backend.GROTH16)}
+}
+
 // CompileFromDefinition compiles a circuit from CircuitDefinition
 func (p *Groth16Prover) Compile(cd *circuit.CircuitDefinition) (*types.Groth16CompiledCircuit, error) {
 	// Create TemplateCircuit from CircuitDefinition
@@ -36,7 +74,7 @@ func (p *Groth16Prover) Compile(cd *circuit.CircuitDefinition) (*types.Groth16Co
 	}
 
 	// Compile to R1CS for Groth16
-	r1cs, err := frontend.Compile(p.curve.ToECC().ScalarField(), r1cs.NewBuilder, templateCircuit)
+	r1cs, err := p.config.compile(p.curve.ToECC().ScalarField(), r1cs.NewBuilder, templateCircuit)
 	if err != nil {
 		return nil, fmt.Errorf("failed to compile circuit to R1CS: %w", err)
 	}
@@ -50,7 +88,20 @@ func (p *Groth16Prover) Compile(cd *circuit.CircuitDefinition) (*types.Groth16Co
 
 // Setup performs the trusted setup for the compiled circuit
 func (p *Groth16Prover) Setup(compiled *types.Groth16CompiledCircuit) (*types.Groth16ProvingKey, *types.Groth16VerifyingKey, error) {
-	pk, vk, err := groth16.Setup(compiled.CS)
+	var pk groth16.ProvingKey
+	var vk groth16.VerifyingKey
+	var err error
+	if p.seed != nil {
+		err = withSeededRandomness(*p.seed, func() (err error) {
+			pk, vk, err = groth16.Setup(compiled.CS)
+			return err
+		})
+	} else {
+		err = withRandomness(func() (err error) {
+			pk, vk, err = groth16.Setup(compiled.CS)
+			return err
+		})
+	}
 	if err != nil {
 		return nil, nil, fmt.Errorf("failed to setup Groth16: %w", err)
 	}
@@ -89,7 +140,11 @@ func (p *Groth16Prover) Prove(compiled *types.Groth16CompiledCircuit, pk *types.
 		return nil, fmt.Errorf("failed to create gnark witness: %w", err)
 	}
 
-	groth16Proof, err := groth16.Prove(compiled.CS, pk.Key, gnarkWitness)
+	var groth16Proof groth16.Proof
+	err = withRandomness(func() (err error) {
+		groth16Proof, err = groth16.Prove(compiled.CS, pk.Key, gnarkWitness, p.proverOptions()...)
+		return err
+	})
 	if err != nil {
 		return nil, fmt.Errorf("failed to generate Groth16 proof: %w", err)
 	}
@@ -124,7 +179,7 @@ func (p *Groth16Prover) Verify(proof *types.Groth16Proof, vk *types.Groth16Verif
 		return fmt.Errorf("failed to extract public witness: %w", err)
 	}
 
-	err = groth16.Verify(proof.Proof, vk.Key, publicGnarkWitness)
+	err = groth16.Verify(proof.Proof, vk.Key, publicGnarkWitness, p.verifierOptions()...)
 	if err != nil {
 		return fmt.Errorf("Groth16 verification failed: %w", err)
 	}
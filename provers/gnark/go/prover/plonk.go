@@ -3,10 +3,11 @@ package prover
 import (
 	"fmt"
 
+	"github.com/consensys/gnark/backend"
 	"github.com/consensys/gnark/backend/plonk"
 	"github.com/consensys/gnark/frontend"
 	"github.com/consensys/gnark/frontend/cs/scs"
-	"github.com/consensys/gnark/test/unsafekzg"
+	stdplonk "github.com/consensys/gnark/std/recursion/plonk"
 
 	"github.com/tiannian/rsnark/provers-gnark/circuit"
 	"github.com/tiannian/rsnark/provers-gnark/prover/types"
@@ -15,6 +16,13 @@ import (
 // PlonkProver represents the PLONK prover interface
 type PlonkProver struct {
 	curve types.CurveType
+	// recursive selects the transcript of the in-circuit verifier, so proofs can
+	// be verified in circuits over the same curve
+	recursive bool
+	// srs is the origin of the KZG SRS used by Setup, which fails if it is nil
+	srs *SRSSource
+	// config holds the options of Compile
+	config Config
 }
 
 // NewPlonkProver creates a new PLONK prover instance
@@ -28,6 +36,48 @@ func (p *PlonkProver) CurveId() types.CurveType {
 	return p.curve
 }
 
+// SetSRS sets the origin of the KZG SRS used by Setup
+func (p *PlonkProver) SetSRS(source SRSSource) {
+	p.srs = &source
+}
+
+// SetConfig configures the prover. GOMAXPROCS applies to the whole runtime,
+// and a seed replaces any configured SRS.
+func (p *PlonkProver) SetConfig(config Config) {
+	config.apply()
+	p.config = config
+	if config.InsecureSeed != nil {
+		p.SetSeed(*config.InsecureSeed)
+	}
+}
+
+// SetSeed makes Setup use a test SRS whose toxic waste is derived from seed,
+// replacing any configured SRS
+func (p *PlonkProver) SetSeed(seed uint64) {
+	p.SetSRS(SRSSource{Format: SRSSeeded, Seed: seed})
+}
+
+// SetRecursion makes proofs verifiable in circuits over the same curve
+func (p *PlonkProver) SetRecursion() {
+	p.recursive = true
+}
+
+func (p *PlonkProver) proverOptions() []backend.ProverOption {
+	if !p.recursive {
+		return nil
+	}
+	field := p.curve.ToECC().ScalarField()
+	return []backend.ProverOption{stdplonk.GetNativeProverOptions(field, field)}
+}
+
+func (p *PlonkProver) verifierOptions() []backend.VerifierOption {
+	if !p.recursive {
+		return nil
+	}
+	field := p.curve.ToECC().ScalarField()
+	return []backend.VerifierOption{stdplonk.GetNativeVerifierOptions(field, field)}
+}
+
 // Compile compiles a circuit from CircuitDefinition
 func (p *PlonkProver) Compile(cd *circuit.CircuitDefinition) (*types.PlonkCompiledCircuit, error) {
 	// Create TemplateCircuit from CircuitDefinition
@@ -37,7 +87,7 @@ func (p *PlonkProver) Compile(cd *circuit.CircuitDefinition) (*types.PlonkCompil
 	}
 
 	// Compile to SCS for PLONK (Sparse Constraint System)
-	scs, err := frontend.Compile(p.curve.ToECC().ScalarField(), scs.NewBuilder, templateCircuit)
+	scs, err := p.config.compile(p.curve.ToECC().ScalarField(), scs.NewBuilder, templateCircuit)
 	if err != nil {
 		return nil, fmt.Errorf("failed to compile circuit to SCS: %w", err)
 	}
@@ -51,10 +101,13 @@ func (p *PlonkProver) Compile(cd *circuit.CircuitDefinition) (*types.PlonkCompil
 
 // Setup performs the trusted setup for the compiled circuit
 func (p *PlonkProver) Setup(compiled *types.PlonkCompiledCircuit) (*types.PlonkProvingKey, *types.PlonkVerifyingKey, error) {
+	if p.srs == nil {
+		return nil, nil, ErrMissingSRS
+	}
 
-	srs, srsLagrange, err := unsafekzg.NewSRS(compiled.CS)
+	srs, srsLagrange, err := loadSRS(p.curve.ToECC(), *p.srs, compiled.CS)
 	if err != nil {
-		return nil, nil, fmt.Errorf("failed to create KZG SRS: %w", err)
+		return nil, nil, err
 	}
 
 	pk, vk, err := plonk.Setup(compiled.CS, srs, srsLagrange)
@@ -96,7 +149,11 @@ func (p *PlonkProver) Prove(compiled *types.PlonkCompiledCircuit, pk *types.Plon
 		return nil, fmt.Errorf("failed to create gnark witness: %w", err)
 	}
 
-	plonkProof, err := plonk.Prove(compiled.CS, pk.Key, gnarkWitness)
+	var plonkProof plonk.Proof
+	err = withRandomness(func() (err error) {
+		plonkProof, err = plonk.Prove(compiled.CS, pk.Key, gnarkWitness, p.proverOptions()...)
+		return err
+	})
 	if err != nil {
 		return nil, fmt.Errorf("failed to generate PLONK proof: %w", err)
 	}
@@ -130,7 +187,7 @@ func (p *PlonkProver) Verify(proof *types.PlonkProof, vk *types.PlonkVerifyingKe
 		return fmt.Errorf("failed to extract public witness: %w", err)
 	}
 
-	err = plonk.Verify(proof.Proof, vk.Key, publicGnarkWitness)
+	err = plonk.Verify(proof.Proof, vk.Key, publicGnarkWitness, p.verifierOptions()...)
 	if err != nil {
 		return fmt.Errorf("PLONK verification failed: %w", err)
 	}
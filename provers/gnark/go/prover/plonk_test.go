@@ -53,7 +53,7 @@ const testPlonkCircuitJSON = `{
 
 func TestPlonkProverCreation(t *testing.T) {
 	// Test creating a new PLONK prover
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 	if prover == nil {
 		t.Error("Expected prover to be created")
 		return
@@ -69,7 +69,7 @@ func TestPlonkProverCreation(t *testing.T) {
 }
 
 func TestPlonkCompileFromDefinition(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create circuit definition from JSON
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -94,7 +94,7 @@ func TestPlonkCompileFromDefinition(t *testing.T) {
 }
 
 func TestPlonkSetup(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -123,7 +123,7 @@ func TestPlonkSetup(t *testing.T) {
 }
 
 func TestPlonkProveAndVerify(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -165,7 +165,7 @@ func TestPlonkProveAndVerify(t *testing.T) {
 }
 
 func TestPlonkProveAndVerifyInvalidWitness(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -198,7 +198,7 @@ func TestPlonkProveAndVerifyInvalidWitness(t *testing.T) {
 }
 
 func TestPlonkVerifyWithWrongPublicWitness(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -242,7 +242,7 @@ func TestPlonkVerifyWithWrongPublicWitness(t *testing.T) {
 }
 
 func TestPlonkKeySerialization(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -297,7 +297,7 @@ func TestPlonkKeySerialization(t *testing.T) {
 }
 
 func TestPlonkKeyFileOperations(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -330,7 +330,7 @@ func TestPlonkKeyFileOperations(t *testing.T) {
 }
 
 func TestPlonkCompiledCircuitSerialization(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -362,7 +362,7 @@ func TestPlonkCompiledCircuitSerialization(t *testing.T) {
 }
 
 func TestPlonkMultipleProofs(t *testing.T) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 
 	// Create and compile circuit
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
@@ -429,7 +429,7 @@ func TestPlonkDifferentCurves(t *testing.T) {
 
 	for _, tc := range curves {
 		t.Run(tc.name, func(t *testing.T) {
-			prover := NewPlonkProver(tc.curve)
+			prover := newTestPlonkProver(tc.curve)
 
 			// Verify prover creation
 			if prover.CurveId() != tc.curve {
@@ -456,8 +456,8 @@ func TestPlonkDifferentCurves(t *testing.T) {
 
 func TestPlonkProverConsistency(t *testing.T) {
 	// Test that multiple prover instances with same curve produce consistent results
-	prover1 := NewPlonkProver(types.CurveBN254)
-	prover2 := NewPlonkProver(types.CurveBN254)
+	prover1 := newTestPlonkProver(types.CurveBN254)
+	prover2 := newTestPlonkProver(types.CurveBN254)
 
 	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
 	if err != nil {
@@ -519,7 +519,7 @@ func TestPlonkProverConsistency(t *testing.T) {
 
 // Benchmark tests for performance comparison
 func BenchmarkPlonkSetup(b *testing.B) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 	circuitDef, _ := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
 	compiled, _ := prover.Compile(circuitDef)
 
@@ -533,7 +533,7 @@ func BenchmarkPlonkSetup(b *testing.B) {
 }
 
 func BenchmarkPlonkProve(b *testing.B) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 	circuitDef, _ := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
 	compiled, _ := prover.Compile(circuitDef)
 	pk, _, _ := prover.Setup(compiled)
@@ -553,7 +553,7 @@ func BenchmarkPlonkProve(b *testing.B) {
 }
 
 func BenchmarkPlonkVerify(b *testing.B) {
-	prover := NewPlonkProver(types.CurveBN254)
+	prover := newTestPlonkProver(types.CurveBN254)
 	circuitDef, _ := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
 	compiled, _ := prover.Compile(circuitDef)
 	pk, vk, _ := prover.Setup(compiled)
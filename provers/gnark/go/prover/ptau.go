@@ -0,0 +1,184 @@
+package prover
+
+import (
+	"bufio"
+	"encoding/binary"
+	"fmt"
+	"io"
+	"math/big"
+	"os"
+	"slices"
+
+	"github.com/consensys/gnark-crypto/ecc/bn254"
+	"github.com/consensys/gnark-crypto/ecc/bn254/fp"
+	kzg_bn254 "github.com/consensys/gnark-crypto/ecc/bn254/kzg"
+)
+
+// Sections of a .ptau file used for KZG
+const (
+	ptauSectionHeader = 1
+	ptauSectionTauG1  = 2
+	ptauSectionTauG2  = 3
+)
+
+type ptauSection struct {
+	offset int64
+	size   int64
+}
+
+// readPtau reads the first size powers of tau in G1, and the first 2 in G2, of
+// a snarkjs powers-of-tau transcript over BN254.
+//
+// The file starts with "ptau", a version and a number of sections, each being a
+// type, a size and its data. Points are stored as little-endian coordinates in
+// Montgomery form, which is the memory layout of fp.Element.
+func readPtau(path string, size int) (*kzg_bn254.SRS, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	sections, err := readPtauSections(file)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", path, err)
+	}
+
+	// Header: n8, q, power and ceremony power
+	r, err := ptauSectionReader(file, sections, ptauSectionHeader)
+	if err != nil {
+		return nil, err
+	}
+	var n8 uint32
+	if err := binary.Read(r, binary.LittleEndian, &n8); err != nil {
+		return nil, err
+	}
+	if n8 != fp.Bytes {
+		return nil, fmt.Errorf("%s is not over BN254: field elements have %d bytes", path, n8)
+	}
+	q := make([]byte, n8)
+	if _, err := io.ReadFull(r, q); err != nil {
+		return nil, err
+	}
+	slices.Reverse(q)
+	if new(big.Int).SetBytes(q).Cmp(fp.Modulus()) != 0 {
+		return nil, fmt.Errorf("%s is not over BN254", path)
+	}
+	var power uint32
+	if err := binary.Read(r, binary.LittleEndian, &power); err != nil {
+		return nil, err
+	}
+	if available := (1 << (power + 1)) - 1; size > available {
+		return nil, fmt.Errorf("%s has %d powers of tau, the circuit needs %d", path, available, size)
+	}
+
+	var srs kzg_bn254.SRS
+
+	r, err = ptauSectionReader(file, sections, ptauSectionTauG1)
+	if err != nil {
+		return nil, err
+	}
+	srs.Pk.G1 = make([]bn254.G1Affine, size)
+	for i := range srs.Pk.G1 {
+		if err := readPtauG1(r, &srs.Pk.G1[i]); err != nil {
+			return nil, fmt.Errorf("failed to read tau^%d in G1: %w", i, err)
+		}
+	}
+	srs.Vk.G1 = srs.Pk.G1[0]
+
+	r, err = ptauSectionReader(file, sections, ptauSectionTauG2)
+	if err != nil {
+		return nil, err
+	}
+	for i := range srs.Vk.G2 {
+		if err := readPtauG2(r, &srs.Vk.G2[i]); err != nil {
+			return nil, fmt.Errorf("failed to read tau^%d in G2: %w", i, err)
+		}
+	}
+	srs.Vk.Lines[0] = bn254.PrecomputeLines(srs.Vk.G2[0])
+	srs.Vk.Lines[1] = bn254.PrecomputeLines(srs.Vk.G2[1])
+
+	return &srs, nil
+}
+
+func readPtauSections(file *os.File) (map[uint32]ptauSection, error) {
+	r := io.NewSectionReader(file, 0, 12)
+
+	magic := make([]byte, 4)
+	if _, err := io.ReadFull(r, magic); err != nil {
+		return nil, err
+	}
+	if string(magic) != "ptau" {
+		return nil, fmt.Errorf("not a .ptau file")
+	}
+
+	var header struct {
+		Version    uint32
+		NbSections uint32
+	}
+	if err := binary.Read(r, binary.LittleEndian, &header); err != nil {
+		return nil, err
+	}
+
+	sections := make(map[uint32]ptauSection, header.NbSections)
+	offset := int64(12)
+	for i := uint32(0); i < header.NbSections; i++ {
+		var section struct {
+			Type uint32
+			Size uint64
+		}
+		if err := binary.Read(io.NewSectionReader(file, offset, 12), binary.LittleEndian, &section); err != nil {
+			return nil, err
+		}
+		offset += 12
+
+		sections[section.Type] = ptauSection{offset: offset, size: int64(section.Size)}
+		offset += int64(section.Size)
+	}
+
+	return sections, nil
+}
+
+func ptauSectionReader(file *os.File, sections map[uint32]ptauSection, ty uint32) (io.Reader, error) {
+	section, exists := sections[ty]
+	if !exists {
+		return nil, fmt.Errorf(".ptau file has no section %d", ty)
+	}
+	return bufio.NewReader(io.NewSectionReader(file, section.offset, section.size)), nil
+}
+
+func readPtauElement(r io.Reader, e *fp.Element) error {
+	var b [fp.Bytes]byte
+	if _, err := io.ReadFull(r, b[:]); err != nil {
+		return err
+	}
+	for i := range e {
+		e[i] = binary.LittleEndian.Uint64(b[8*i:])
+	}
+	return nil
+}
+
+func readPtauG1(r io.Reader, p *bn254.G1Affine) error {
+	if err := readPtauElement(r, &p.X); err != nil {
+		return err
+	}
+	if err := readPtauElement(r, &p.Y); err != nil {
+		return err
+	}
+	if !p.IsOnCurve() {
+		return fmt.Errorf("point is not on the curve")
+	}
+	return nil
+}
+
+func readPtauG2(r io.Reader, p *bn254.G2Affine) error {
+	for _, e := range []*fp.Element{&p.X.A0, &p.X.A1, &p.Y.A0, &p.Y.A1} {
+		if err := readPtauElement(r, e); err != nil {
+			return err
+		}
+	}
+	if !p.IsInSubGroup() {
+		return fmt.Errorf("point is not in the subgroup")
+	}
+	return nil
+}
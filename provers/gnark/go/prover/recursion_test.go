@@ -0,0 +1,225 @@
+package prover
+
+import (
+	"fmt"
+	"math/big"
+	"strings"
+	"testing"
+
+	"github.com/tiannian/rsnark/provers-gnark/circuit"
+	"github.com/tiannian/rsnark/provers-gnark/prover/types"
+)
+
+// recursionCircuitJSON verifies a proof of testCircuitJSON: the proof and the
+// verifying key are private, and the public input of the inner proof is public
+func recursionCircuitJSON() string {
+	inputs := make([]string, 0, 8+24+1)
+	for i := 0; i < 8+24; i++ {
+		inputs = append(inputs, fmt.Sprintf(`{"t": "private", "v": %d}`, i))
+	}
+	inputs = append(inputs, `{"t": "public", "v": 0}`)
+
+	return fmt.Sprintf(`{
+  "private_len": %d,
+  "public_len": 1,
+  "local_len": 0,
+  "operations": [
+    {
+      "op": "assert_groth16_proof",
+      "in": [%s],
+      "out": []
+    }
+  ]
+}`, 8+24, strings.Join(inputs, ", "))
+}
+
+func TestGroth16RecursiveProof(t *testing.T) {
+	// Prove 3 + 5 = 8 on BLS12-377
+	inner := NewGroth16Prover(types.CurveBLS12_377)
+	innerDef, err := circuit.ParseCircuitDefinition([]byte(testCircuitJSON))
+	if err != nil {
+		t.Fatalf("Failed to create circuit definition: %v", err)
+	}
+	innerCompiled, err := inner.Compile(innerDef)
+	if err != nil {
+		t.Fatalf("Failed to compile inner circuit: %v", err)
+	}
+	innerPk, innerVk, err := inner.Setup(innerCompiled)
+	if err != nil {
+		t.Fatalf("Failed to setup inner circuit: %v", err)
+	}
+	innerProof, err := inner.Prove(innerCompiled, innerPk, types.NewTemplateWitness(
+		[]*big.Int{big.NewInt(8)},
+		[]*big.Int{big.NewInt(3), big.NewInt(5)},
+	))
+	if err != nil {
+		t.Fatalf("Failed to generate inner proof: %v", err)
+	}
+
+	proofValues, err := innerProof.RecursionValues()
+	if err != nil {
+		t.Fatalf("Failed to flatten proof: %v", err)
+	}
+	vkValues, err := innerVk.RecursionValues()
+	if err != nil {
+		t.Fatalf("Failed to flatten verifying key: %v", err)
+	}
+	if len(proofValues) != 8 || len(vkValues) != 24 {
+		t.Fatalf("Unexpected number of elements: %d, %d", len(proofValues), len(vkValues))
+	}
+
+	private := append(proofValues, vkValues...)
+
+	// Verify the inner proof in a BW6-761 circuit
+	outer := NewGroth16Prover(types.CurveBW6_761)
+	outerDef, err := circuit.ParseCircuitDefinition([]byte(recursionCircuitJSON()))
+	if err != nil {
+		t.Fatalf("Failed to create circuit definition: %v", err)
+	}
+	outerCompiled, err := outer.Compile(outerDef)
+	if err != nil {
+		t.Fatalf("Failed to compile outer circuit: %v", err)
+	}
+	outerPk, outerVk, err := outer.Setup(outerCompiled)
+	if err != nil {
+		t.Fatalf("Failed to setup outer circuit: %v", err)
+	}
+
+	witness := types.NewTemplateWitness([]*big.Int{big.NewInt(8)}, private)
+	outerProof, err := outer.Prove(outerCompiled, outerPk, witness)
+	if err != nil {
+		t.Fatalf("Failed to generate outer proof: %v", err)
+	}
+	err = outer.Verify(outerProof, outerVk, types.NewTemplatePublicWitnessFromTemplate(witness))
+	if err != nil {
+		t.Fatalf("Failed to verify outer proof: %v", err)
+	}
+
+	// The inner proof does not hold for another public input
+	_, err = outer.Prove(outerCompiled, outerPk, types.NewTemplateWitness([]*big.Int{big.NewInt(9)}, private))
+	if err == nil {
+		t.Error("Expected proving to fail with a wrong inner public input")
+	}
+}
+
+func TestGroth16RecursionWrongCurve(t *testing.T) {
+	prover := NewGroth16Prover(types.CurveBN254)
+	circuitDef, err := circuit.ParseCircuitDefinition([]byte(recursionCircuitJSON()))
+	if err != nil {
+		t.Fatalf("Failed to create circuit definition: %v", err)
+	}
+
+	_, err = prover.Compile(circuitDef)
+	if err == nil {
+		t.Error("Expected compiling a recursive circuit on BN254 to fail")
+	}
+}
+
+// plonkRecursionCircuitJSON verifies a proof of testCircuitJSON under the
+// verifying key vk as constants: the proof is private, and the public input of
+// the inner proof is public
+func plonkRecursionCircuitJSON(vk []byte) string {
+	inputs := []string{fmt.Sprintf(`{"t": "constant", "v": "%d"}`, len(vk))}
+	for start := 0; start < len(vk); start += 31 {
+		chunk := new(big.Int).SetBytes(vk[start:min(start+31, len(vk))])
+		inputs = append(inputs, fmt.Sprintf(`{"t": "constant", "v": "%s"}`, chunk))
+	}
+	for i := 0; i < 100; i++ {
+		inputs = append(inputs, fmt.Sprintf(`{"t": "private", "v": %d}`, i))
+	}
+	inputs = append(inputs, `{"t": "public", "v": 0}`)
+
+	return fmt.Sprintf(`{
+  "private_len": 100,
+  "public_len": 1,
+  "local_len": 0,
+  "operations": [
+    {
+      "op": "assert_plonk_proof",
+      "in": [%s],
+      "out": []
+    }
+  ]
+}`, strings.Join(inputs, ", "))
+}
+
+func TestPlonkRecursiveProof(t *testing.T) {
+	// Prove 3 + 5 = 8 with PLONK on BN254, using the transcript of the verifier circuit
+	inner := newTestPlonkProver(types.CurveBN254)
+	inner.SetRecursion()
+	innerDef, err := circuit.ParseCircuitDefinition([]byte(testCircuitJSON))
+	if err != nil {
+		t.Fatalf("Failed to create circuit definition: %v", err)
+	}
+	innerCompiled, err := inner.Compile(innerDef)
+	if err != nil {
+		t.Fatalf("Failed to compile inner circuit: %v", err)
+	}
+	innerPk, innerVk, err := inner.Setup(innerCompiled)
+	if err != nil {
+		t.Fatalf("Failed to setup inner circuit: %v", err)
+	}
+	innerWitness := types.NewTemplateWitness(
+		[]*big.Int{big.NewInt(8)},
+		[]*big.Int{big.NewInt(3), big.NewInt(5)},
+	)
+	innerProof, err := inner.Prove(innerCompiled, innerPk, innerWitness)
+	if err != nil {
+		t.Fatalf("Failed to generate inner proof: %v", err)
+	}
+	err = inner.Verify(innerProof, innerVk, types.NewTemplatePublicWitnessFromTemplate(innerWitness))
+	if err != nil {
+		t.Fatalf("Failed to verify inner proof: %v", err)
+	}
+
+	proofValues, err := innerProof.RecursionValues()
+	if err != nil {
+		t.Fatalf("Failed to flatten proof: %v", err)
+	}
+	if len(proofValues) != 25 {
+		t.Fatalf("Unexpected number of elements: %d", len(proofValues))
+	}
+	private := make([]*big.Int, 0, 100)
+	mask := new(big.Int).SetUint64(^uint64(0))
+	for _, value := range proofValues {
+		for i := 0; i < 4; i++ {
+			private = append(private, new(big.Int).And(new(big.Int).Rsh(value, uint(64*i)), mask))
+		}
+	}
+
+	vk, err := innerVk.Serialize()
+	if err != nil {
+		t.Fatalf("Failed to serialize verifying key: %v", err)
+	}
+
+	// Verify the inner proof in a BN254 circuit, proved with Groth16
+	outer := NewGroth16Prover(types.CurveBN254)
+	outerDef, err := circuit.ParseCircuitDefinition([]byte(plonkRecursionCircuitJSON(vk)))
+	if err != nil {
+		t.Fatalf("Failed to create circuit definition: %v", err)
+	}
+	outerCompiled, err := outer.Compile(outerDef)
+	if err != nil {
+		t.Fatalf("Failed to compile outer circuit: %v", err)
+	}
+	outerPk, outerVk, err := outer.Setup(outerCompiled)
+	if err != nil {
+		t.Fatalf("Failed to setup outer circuit: %v", err)
+	}
+
+	witness := types.NewTemplateWitness([]*big.Int{big.NewInt(8)}, private)
+	outerProof, err := outer.Prove(outerCompiled, outerPk, witness)
+	if err != nil {
+		t.Fatalf("Failed to generate outer proof: %v", err)
+	}
+	err = outer.Verify(outerProof, outerVk, types.NewTemplatePublicWitnessFromTemplate(witness))
+	if err != nil {
+		t.Fatalf("Failed to verify outer proof: %v", err)
+	}
+
+	// The inner proof does not hold for another public input
+	_, err = outer.Prove(outerCompiled, outerPk, types.NewTemplateWitness([]*big.Int{big.NewInt(9)}, private))
+	if err == nil {
+		t.Error("Expected proving to fail with a wrong inner public input")
+	}
+}
@@ -0,0 +1,66 @@
+package prover
+
+import (
+	"crypto/rand"
+	"crypto/sha256"
+	"encoding/binary"
+	"io"
+	"math/big"
+	mathrand "math/rand/v2"
+	"sync"
+)
+
+// randomnessMutex is held exclusively by setups drawing their randomness from a
+// seed, and shared by every other operation drawing from crypto/rand.Reader
+var randomnessMutex sync.RWMutex
+
+// seedBytes expands a seed into 32 bytes
+func seedBytes(seed uint64) [32]byte {
+	return sha256.Sum256(binary.BigEndian.AppendUint64([]byte("rsnark insecure seed"), seed))
+}
+
+// seedScalar derives a secret scalar from a seed, reduced modulo the scalar
+// field by gnark
+func seedScalar(seed uint64) *big.Int {
+	b := seedBytes(seed)
+	return new(big.Int).SetBytes(b[:])
+}
+
+// lockedReader makes a deterministic stream safe for concurrent reads
+type lockedReader struct {
+	mu sync.Mutex
+	r  io.Reader
+}
+
+func (l *lockedReader) Read(p []byte) (int, error) {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	return l.r.Read(p)
+}
+
+// withSeededRandomness runs f with crypto/rand.Reader replaced by a stream
+// derived from seed, so the toxic waste sampled by gnark is reproducible.
+//
+// The reader is process-wide, so f runs while no other operation of this
+// package draws randomness, see withRandomness. Code outside of this package
+// drawing randomness in the meantime reads the same stream: seeded setups are
+// only meant for tests.
+func withSeededRandomness(seed uint64, f func() error) error {
+	randomnessMutex.Lock()
+	defer randomnessMutex.Unlock()
+
+	reader := rand.Reader
+	rand.Reader = &lockedReader{r: mathrand.NewChaCha8(seedBytes(seed))}
+	defer func() { rand.Reader = reader }()
+
+	return f()
+}
+
+// withRandomness runs f, which draws from crypto/rand.Reader, outside of any
+// seeded setup
+func withRandomness(f func() error) error {
+	randomnessMutex.RLock()
+	defer randomnessMutex.RUnlock()
+
+	return f()
+}
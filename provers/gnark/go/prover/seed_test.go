@@ -0,0 +1,56 @@
+package prover
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/tiannian/rsnark/provers-gnark/prover/types"
+)
+
+func seededGroth16VerifyingKey(t *testing.T, seed uint64) []byte {
+	prover := NewGroth16Prover(types.CurveBN254)
+	prover.SetSeed(seed)
+
+	_, vk, err := prover.Setup(compileTestGroth16Circuit(t, prover))
+	if err != nil {
+		t.Fatalf("Failed to setup Groth16: %v", err)
+	}
+	data, err := vk.Serialize()
+	if err != nil {
+		t.Fatalf("Failed to serialize verifying key: %v", err)
+	}
+	return data
+}
+
+func seededPlonkVerifyingKey(t *testing.T, seed uint64) []byte {
+	prover := NewPlonkProver(types.CurveBN254)
+	prover.SetSeed(seed)
+
+	_, vk, err := prover.Setup(compileTestPlonkCircuit(t, prover))
+	if err != nil {
+		t.Fatalf("Failed to setup PLONK: %v", err)
+	}
+	data, err := vk.Serialize()
+	if err != nil {
+		t.Fatalf("Failed to serialize verifying key: %v", err)
+	}
+	return data
+}
+
+func TestGroth16SeededSetup(t *testing.T) {
+	if !bytes.Equal(seededGroth16VerifyingKey(t, 1), seededGroth16VerifyingKey(t, 1)) {
+		t.Error("Expected the same verifying key for the same seed")
+	}
+	if bytes.Equal(seededGroth16VerifyingKey(t, 1), seededGroth16VerifyingKey(t, 2)) {
+		t.Error("Expected different verifying keys for different seeds")
+	}
+}
+
+func TestPlonkSeededSetup(t *testing.T) {
+	if !bytes.Equal(seededPlonkVerifyingKey(t, 1), seededPlonkVerifyingKey(t, 1)) {
+		t.Error("Expected the same verifying key for the same seed")
+	}
+	if bytes.Equal(seededPlonkVerifyingKey(t, 1), seededPlonkVerifyingKey(t, 2)) {
+		t.Error("Expected different verifying keys for different seeds")
+	}
+}
@@ -0,0 +1,236 @@
+package prover
+
+import (
+	"bufio"
+	"errors"
+	"fmt"
+	"os"
+	"slices"
+	"sync"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	kzg_bls12377 "github.com/consensys/gnark-crypto/ecc/bls12-377/kzg"
+	kzg_bls12381 "github.com/consensys/gnark-crypto/ecc/bls12-381/kzg"
+	kzg_bls24315 "github.com/consensys/gnark-crypto/ecc/bls24-315/kzg"
+	kzg_bls24317 "github.com/consensys/gnark-crypto/ecc/bls24-317/kzg"
+	kzg_bn254 "github.com/consensys/gnark-crypto/ecc/bn254/kzg"
+	kzg_bw6633 "github.com/consensys/gnark-crypto/ecc/bw6-633/kzg"
+	kzg_bw6761 "github.com/consensys/gnark-crypto/ecc/bw6-761/kzg"
+	"github.com/consensys/gnark-crypto/kzg"
+	"github.com/consensys/gnark/constraint"
+	"github.com/consensys/gnark/test/unsafekzg"
+)
+
+var (
+	// ErrMissingSRS is returned by Setup when no KZG SRS is configured
+	ErrMissingSRS = errors.New("no KZG SRS configured")
+	// ErrSRS is returned by Setup when the KZG SRS cannot be loaded
+	ErrSRS = errors.New("failed to load KZG SRS")
+)
+
+// SRSFormat is the origin of a KZG SRS
+type SRSFormat uint64
+
+const (
+	// SRSInsecure is a test SRS generated with known toxic waste
+	SRSInsecure SRSFormat = 0
+	// SRSGnark is a canonical SRS serialized by gnark
+	SRSGnark SRSFormat = 1
+	// SRSPtau is a powers-of-tau transcript in the snarkjs format, over BN254
+	SRSPtau SRSFormat = 2
+	// SRSSeeded is a test SRS whose toxic waste is derived from a seed
+	SRSSeeded SRSFormat = 3
+)
+
+// SRSSource is where a PLONK prover gets its KZG SRS from
+type SRSSource struct {
+	Format SRSFormat
+	Path   string
+	Seed   uint64
+}
+
+type srsKey struct {
+	curve  ecc.ID
+	source SRSSource
+	size   int
+}
+
+type srsPair struct {
+	canonical kzg.SRS
+	lagrange  kzg.SRS
+}
+
+var (
+	// srsCache holds the SRSes derived for each circuit size
+	srsCache = make(map[srsKey]srsPair)
+	// srsFiles holds the SRSes read from gnark files, of any size
+	srsFiles   = make(map[srsKey]kzg.SRS)
+	srsMutex   sync.Mutex
+	srsLoading sync.Mutex
+)
+
+// srsSize returns the sizes of the canonical and Lagrange SRSes of a circuit
+func srsSize(ccs constraint.ConstraintSystem) (int, int) {
+	lagrange := int(ecc.NextPowerOfTwo(uint64(ccs.GetNbConstraints() + ccs.GetNbPublicVariables())))
+	return lagrange + 3, lagrange
+}
+
+// loadSRS returns the canonical and Lagrange SRSes of a circuit from the source,
+// caching them per curve and size
+func loadSRS(curve ecc.ID, source SRSSource, ccs constraint.ConstraintSystem) (kzg.SRS, kzg.SRS, error) {
+	canonical, lagrange := srsSize(ccs)
+	key := srsKey{curve: curve, source: source, size: lagrange}
+
+	// Loading is serialized so concurrent setups share the work
+	srsLoading.Lock()
+	defer srsLoading.Unlock()
+
+	srsMutex.Lock()
+	pair, exists := srsCache[key]
+	srsMutex.Unlock()
+	if exists {
+		return pair.canonical, pair.lagrange, nil
+	}
+
+	var err error
+	switch source.Format {
+	case SRSInsecure:
+		pair.canonical, pair.lagrange, err = unsafekzg.NewSRS(ccs)
+	case SRSGnark:
+		var full kzg.SRS
+		full, err = readGnarkSRS(curve, source.Path)
+		if err == nil {
+			pair.canonical, pair.lagrange, err = deriveSRS(full, canonical, lagrange)
+		}
+	case SRSPtau:
+		if curve != ecc.BN254 {
+			err = fmt.Errorf(".ptau files are only supported on BN254, not %s", curve)
+			break
+		}
+		var full *kzg_bn254.SRS
+		full, err = readPtau(source.Path, canonical)
+		if err == nil {
+			pair.canonical, pair.lagrange, err = deriveSRS(full, canonical, lagrange)
+		}
+	case SRSSeeded:
+		var full kzg.SRS
+		full, err = seededSRS(curve, source.Seed, canonical)
+		if err == nil {
+			pair.canonical, pair.lagrange, err = deriveSRS(full, canonical, lagrange)
+		}
+	default:
+		err = fmt.Errorf("unknown SRS format %d", source.Format)
+	}
+	if err != nil {
+		return nil, nil, fmt.Errorf("%w: %w", ErrSRS, err)
+	}
+
+	srsMutex.Lock()
+	srsCache[key] = pair
+	srsMutex.Unlock()
+
+	return pair.canonical, pair.lagrange, nil
+}
+
+// readGnarkSRS reads a canonical SRS serialized by gnark, once per file
+func readGnarkSRS(curve ecc.ID, path string) (kzg.SRS, error) {
+	key := srsKey{curve: curve, source: SRSSource{Format: SRSGnark, Path: path}}
+
+	srsMutex.Lock()
+	srs, exists := srsFiles[key]
+	srsMutex.Unlock()
+	if exists {
+		return srs, nil
+	}
+
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer file.Close()
+
+	srs = kzg.NewSRS(curve)
+	if _, err := srs.ReadFrom(bufio.NewReader(file)); err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", path, err)
+	}
+
+	srsMutex.Lock()
+	srsFiles[key] = srs
+	srsMutex.Unlock()
+
+	return srs, nil
+}
+
+// seededSRS generates a canonical SRS of the given size, with a toxic waste
+// derived from seed
+func seededSRS(curve ecc.ID, seed uint64, size int) (kzg.SRS, error) {
+	tau := seedScalar(seed)
+	switch curve {
+	case ecc.BN254:
+		return kzg_bn254.NewSRS(uint64(size), tau)
+	case ecc.BLS12_381:
+		return kzg_bls12381.NewSRS(uint64(size), tau)
+	case ecc.BLS12_377:
+		return kzg_bls12377.NewSRS(uint64(size), tau)
+	case ecc.BW6_761:
+		return kzg_bw6761.NewSRS(uint64(size), tau)
+	case ecc.BLS24_315:
+		return kzg_bls24315.NewSRS(uint64(size), tau)
+	case ecc.BLS24_317:
+		return kzg_bls24317.NewSRS(uint64(size), tau)
+	case ecc.BW6_633:
+		return kzg_bw6633.NewSRS(uint64(size), tau)
+	default:
+		return nil, fmt.Errorf("unsupported curve %s", curve)
+	}
+}
+
+// deriveSRS truncates a canonical SRS to the size of a circuit, and computes
+// its Lagrange form
+func deriveSRS(srs kzg.SRS, canonical, lagrange int) (kzg.SRS, kzg.SRS, error) {
+	switch s := srs.(type) {
+	case *kzg_bn254.SRS:
+		pk, lpk, err := deriveG1(s.Pk.G1, canonical, lagrange, kzg_bn254.ToLagrangeG1)
+		return &kzg_bn254.SRS{Pk: kzg_bn254.ProvingKey{G1: pk}, Vk: s.Vk},
+			&kzg_bn254.SRS{Pk: kzg_bn254.ProvingKey{G1: lpk}, Vk: s.Vk}, err
+	case *kzg_bls12381.SRS:
+		pk, lpk, err := deriveG1(s.Pk.G1, canonical, lagrange, kzg_bls12381.ToLagrangeG1)
+		return &kzg_bls12381.SRS{Pk: kzg_bls12381.ProvingKey{G1: pk}, Vk: s.Vk},
+			&kzg_bls12381.SRS{Pk: kzg_bls12381.ProvingKey{G1: lpk}, Vk: s.Vk}, err
+	case *kzg_bls12377.SRS:
+		pk, lpk, err := deriveG1(s.Pk.G1, canonical, lagrange, kzg_bls12377.ToLagrangeG1)
+		return &kzg_bls12377.SRS{Pk: kzg_bls12377.ProvingKey{G1: pk}, Vk: s.Vk},
+			&kzg_bls12377.SRS{Pk: kzg_bls12377.ProvingKey{G1: lpk}, Vk: s.Vk}, err
+	case *kzg_bw6761.SRS:
+		pk, lpk, err := deriveG1(s.Pk.G1, canonical, lagrange, kzg_bw6761.ToLagrangeG1)
+		return &kzg_bw6761.SRS{Pk: kzg_bw6761.ProvingKey{G1: pk}, Vk: s.Vk},
+			&kzg_bw6761.SRS{Pk: kzg_bw6761.ProvingKey{G1: lpk}, Vk: s.Vk}, err
+	case *kzg_bls24315.SRS:
+		pk, lpk, err := deriveG1(s.Pk.G1, canonical, lagrange, kzg_bls24315.ToLagrangeG1)
+		return &kzg_bls24315.SRS{Pk: kzg_bls24315.ProvingKey{G1: pk}, Vk: s.Vk},
+			&kzg_bls24315.SRS{Pk: kzg_bls24315.ProvingKey{G1: lpk}, Vk: s.Vk}, err
+	case *kzg_bls24317.SRS:
+		pk, lpk, err := deriveG1(s.Pk.G1, canonical, lagrange, kzg_bls24317.ToLagrangeG1)
+		return &kzg_bls24317.SRS{Pk: kzg_bls24317.ProvingKey{G1: pk}, Vk: s.Vk},
+			&kzg_bls24317.SRS{Pk: kzg_bls24317.ProvingKey{G1: lpk}, Vk: s.Vk}, err
+	case *kzg_bw6633.SRS:
+		pk, lpk, err := deriveG1(s.Pk.G1, canonical, lagrange, kzg_bw6633.ToLagrangeG1)
+		return &kzg_bw6633.SRS{Pk: kzg_bw6633.ProvingKey{G1: pk}, Vk: s.Vk},
+			&kzg_bw6633.SRS{Pk: kzg_bw6633.ProvingKey{G1: lpk}, Vk: s.Vk}, err
+	default:
+		return nil, nil, fmt.Errorf("unsupported SRS type %T", srs)
+	}
+}
+
+func deriveG1[P any](g1 []P, canonical, lagrange int, toLagrange func([]P) ([]P, error)) ([]P, []P, error) {
+	if len(g1) < canonical {
+		return nil, nil, fmt.Errorf("SRS has %d points, the circuit needs %d", len(g1), canonical)
+	}
+
+	lagrangeG1, err := toLagrange(slices.Clone(g1[:lagrange]))
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to compute the Lagrange SRS: %w", err)
+	}
+
+	return g1[:canonical], lagrangeG1, nil
+}
@@ -0,0 +1,182 @@
+package prover
+
+import (
+	"encoding/binary"
+	"errors"
+	"math/big"
+	"os"
+	"path/filepath"
+	"slices"
+	"testing"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark-crypto/ecc/bn254/fp"
+	kzg_bn254 "github.com/consensys/gnark-crypto/ecc/bn254/kzg"
+
+	"github.com/tiannian/rsnark/provers-gnark/circuit"
+	"github.com/tiannian/rsnark/provers-gnark/prover/types"
+)
+
+// newTestPlonkProver creates a PLONK prover with an insecure test SRS
+func newTestPlonkProver(curve types.CurveType) *PlonkProver {
+	prover := NewPlonkProver(curve)
+	prover.SetSRS(SRSSource{Format: SRSInsecure})
+	return prover
+}
+
+func compileTestPlonkCircuit(t *testing.T, prover *PlonkProver) *types.PlonkCompiledCircuit {
+	circuitDef, err := circuit.ParseCircuitDefinition([]byte(testPlonkCircuitJSON))
+	if err != nil {
+		t.Fatalf("Failed to create circuit definition: %v", err)
+	}
+	compiled, err := prover.Compile(circuitDef)
+	if err != nil {
+		t.Fatalf("Failed to compile circuit: %v", err)
+	}
+	return compiled
+}
+
+// writeTestPtau writes the powers of srs as a .ptau file with the given power
+func writeTestPtau(t *testing.T, srs *kzg_bn254.SRS, power uint32) string {
+	le := binary.LittleEndian
+	element := func(b []byte, e *fp.Element) []byte {
+		for i := range e {
+			b = le.AppendUint64(b, e[i])
+		}
+		return b
+	}
+	section := func(b []byte, ty uint32, data []byte) []byte {
+		b = le.AppendUint32(b, ty)
+		b = le.AppendUint64(b, uint64(len(data)))
+		return append(b, data...)
+	}
+
+	header := le.AppendUint32(nil, fp.Bytes)
+	q := fp.Modulus().FillBytes(make([]byte, fp.Bytes))
+	slices.Reverse(q)
+	header = append(header, q...)
+	header = le.AppendUint32(header, power)
+	header = le.AppendUint32(header, power)
+
+	var tauG1, tauG2 []byte
+	for i := range srs.Pk.G1[:(1<<(power+1))-1] {
+		tauG1 = element(tauG1, &srs.Pk.G1[i].X)
+		tauG1 = element(tauG1, &srs.Pk.G1[i].Y)
+	}
+	for i := range srs.Vk.G2 {
+		for _, e := range []*fp.Element{&srs.Vk.G2[i].X.A0, &srs.Vk.G2[i].X.A1, &srs.Vk.G2[i].Y.A0, &srs.Vk.G2[i].Y.A1} {
+			tauG2 = element(tauG2, e)
+		}
+	}
+
+	data := append([]byte("ptau"), le.AppendUint32(le.AppendUint32(nil, 1), 3)...)
+	data = section(data, ptauSectionHeader, header)
+	data = section(data, ptauSectionTauG1, tauG1)
+	data = section(data, ptauSectionTauG2, tauG2)
+
+	path := filepath.Join(t.TempDir(), "test.ptau")
+	if err := os.WriteFile(path, data, 0o644); err != nil {
+		t.Fatalf("Failed to write .ptau file: %v", err)
+	}
+	return path
+}
+
+func TestPlonkSetupWithoutSRS(t *testing.T) {
+	prover := NewPlonkProver(types.CurveBN254)
+	_, _, err := prover.Setup(compileTestPlonkCircuit(t, prover))
+	if !errors.Is(err, ErrMissingSRS) {
+		t.Errorf("Expected ErrMissingSRS, got %v", err)
+	}
+
+	prover.SetSRS(SRSSource{Format: SRSGnark, Path: filepath.Join(t.TempDir(), "missing.srs")})
+	_, _, err = prover.Setup(compileTestPlonkCircuit(t, prover))
+	if !errors.Is(err, ErrSRS) {
+		t.Errorf("Expected ErrSRS, got %v", err)
+	}
+}
+
+func TestPlonkGnarkSRS(t *testing.T) {
+	srs, err := kzg_bn254.NewSRS(64, big.NewInt(42))
+	if err != nil {
+		t.Fatalf("Failed to create SRS: %v", err)
+	}
+	path := filepath.Join(t.TempDir(), "test.srs")
+	file, err := os.Create(path)
+	if err != nil {
+		t.Fatalf("Failed to create SRS file: %v", err)
+	}
+	if _, err := srs.WriteTo(file); err != nil {
+		t.Fatalf("Failed to write SRS: %v", err)
+	}
+	file.Close()
+
+	prover := NewPlonkProver(types.CurveBN254)
+	prover.SetSRS(SRSSource{Format: SRSGnark, Path: path})
+	compiled := compileTestPlonkCircuit(t, prover)
+	pk, vk, err := prover.Setup(compiled)
+	if err != nil {
+		t.Fatalf("Failed to setup: %v", err)
+	}
+
+	witness := types.NewTemplateWitness(
+		[]*big.Int{big.NewInt(8)},
+		[]*big.Int{big.NewInt(3), big.NewInt(5)},
+	)
+	proof, err := prover.Prove(compiled, pk, witness)
+	if err != nil {
+		t.Fatalf("Failed to prove: %v", err)
+	}
+	err = prover.Verify(proof, vk, types.NewTemplatePublicWitnessFromTemplate(witness))
+	if err != nil {
+		t.Fatalf("Failed to verify: %v", err)
+	}
+
+	// The SRS of this size is cached
+	canonical, _, err := loadSRS(ecc.BN254, SRSSource{Format: SRSGnark, Path: path}, compiled.CS)
+	if err != nil {
+		t.Fatalf("Failed to load cached SRS: %v", err)
+	}
+	again, _, _ := loadSRS(ecc.BN254, SRSSource{Format: SRSGnark, Path: path}, compiled.CS)
+	if canonical != again {
+		t.Error("Expected the SRS to be cached")
+	}
+}
+
+func TestPtauSRS(t *testing.T) {
+	srs, err := kzg_bn254.NewSRS(64, big.NewInt(42))
+	if err != nil {
+		t.Fatalf("Failed to create SRS: %v", err)
+	}
+	path := writeTestPtau(t, srs, 5)
+
+	loaded, err := readPtau(path, 10)
+	if err != nil {
+		t.Fatalf("Failed to read .ptau file: %v", err)
+	}
+	if !slices.Equal(loaded.Pk.G1, srs.Pk.G1[:10]) {
+		t.Error("Expected the powers of tau in G1 to match")
+	}
+	if loaded.Vk.G1 != srs.Vk.G1 || loaded.Vk.G2 != srs.Vk.G2 {
+		t.Error("Expected the verifying key to match")
+	}
+
+	// A power of 5 holds 2^6 - 1 powers in G1
+	if _, err := readPtau(path, 64); err == nil {
+		t.Error("Expected reading too many powers to fail")
+	}
+
+	// The SRS proves and verifies circuits
+	prover := NewPlonkProver(types.CurveBN254)
+	prover.SetSRS(SRSSource{Format: SRSPtau, Path: path})
+	if _, _, err := prover.Setup(compileTestPlonkCircuit(t, prover)); err != nil {
+		t.Fatalf("Failed to setup: %v", err)
+	}
+
+	// .ptau files are only supported on BN254
+	prover = NewPlonkProver(types.CurveBLS12_381)
+	prover.SetSRS(SRSSource{Format: SRSPtau, Path: path})
+	if _, _, err := prover.Setup(compileTestPlonkCircuit(t, prover)); !errors.Is(err, ErrSRS) {
+		t.Errorf("Expected ErrSRS, got %v", err)
+	}
+
+}
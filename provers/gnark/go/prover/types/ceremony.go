@@ -0,0 +1,111 @@
+package types
+
+import (
+	"bytes"
+	"fmt"
+
+	"github.com/consensys/gnark/backend/groth16/bn254/mpcsetup"
+)
+
+// Groth16Phase1 wraps the sealed output of a Groth16 MPC phase 1, the powers
+// of tau shared by all circuits
+type Groth16Phase1 struct {
+	Commons *mpcsetup.SrsCommons
+}
+
+// Serialize serializes the phase 1 output to bytes
+func (p *Groth16Phase1) Serialize() ([]byte, error) {
+	var buf bytes.Buffer
+	_, err := p.Commons.WriteTo(&buf)
+	if err != nil {
+		return nil, fmt.Errorf("failed to serialize phase 1: %w", err)
+	}
+	return buf.Bytes(), nil
+}
+
+// Deserialize deserializes the phase 1 output from bytes
+func (p *Groth16Phase1) Deserialize(data []byte, curve CurveType) error {
+	if curve != CurveBN254 {
+		return fmt.Errorf("MPC setup is only supported on BN254")
+	}
+
+	p.Commons = new(mpcsetup.SrsCommons)
+	_, err := p.Commons.ReadFrom(bytes.NewReader(data))
+	if err != nil {
+		return fmt.Errorf("failed to deserialize phase 1: %w", err)
+	}
+	return nil
+}
+
+// Groth16Phase2 wraps the state of a Groth16 MPC phase 2 after a contribution
+type Groth16Phase2 struct {
+	Phase *mpcsetup.Phase2
+}
+
+// Serialize serializes the phase 2 state to bytes
+func (p *Groth16Phase2) Serialize() ([]byte, error) {
+	var buf bytes.Buffer
+	_, err := p.Phase.WriteTo(&buf)
+	if err != nil {
+		return nil, fmt.Errorf("failed to serialize phase 2: %w", err)
+	}
+	return buf.Bytes(), nil
+}
+
+// Deserialize deserializes the phase 2 state from bytes
+func (p *Groth16Phase2) Deserialize(data []byte, curve CurveType) error {
+	if curve != CurveBN254 {
+		return fmt.Errorf("MPC setup is only supported on BN254")
+	}
+
+	p.Phase = new(mpcsetup.Phase2)
+	_, err := p.Phase.ReadFrom(bytes.NewReader(data))
+	if err != nil {
+		return fmt.Errorf("failed to deserialize phase 2: %w", err)
+	}
+	return nil
+}
+
+// Clone returns a deep copy of the phase 2 state, which contributions mutate
+func (p *Groth16Phase2) Clone() (*Groth16Phase2, error) {
+	data, err := p.Serialize()
+	if err != nil {
+		return nil, err
+	}
+
+	var clone Groth16Phase2
+	if err := clone.Deserialize(data, CurveBN254); err != nil {
+		return nil, err
+	}
+	return &clone, nil
+}
+
+// Groth16Phase2Evaluations wraps the evaluations of the circuit on the phase 1
+// output, which do not depend on phase 2 contributions
+type Groth16Phase2Evaluations struct {
+	Evaluations *mpcsetup.Phase2Evaluations
+}
+
+// Serialize serializes the evaluations to bytes
+func (e *Groth16Phase2Evaluations) Serialize() ([]byte, error) {
+	var buf bytes.Buffer
+	_, err := e.Evaluations.WriteTo(&buf)
+	if err != nil {
+		return nil, fmt.Errorf("failed to serialize phase 2 evaluations: %w", err)
+	}
+	return buf.Bytes(), nil
+}
+
+// Deserialize deserializes the evaluations from bytes
+func (e *Groth16Phase2Evaluations) Deserialize(data []byte, curve CurveType) error {
+	if curve != CurveBN254 {
+		return fmt.Errorf("MPC setup is only supported on BN254")
+	}
+
+	e.Evaluations = new(mpcsetup.Phase2Evaluations)
+	_, err := e.Evaluations.ReadFrom(bytes.NewReader(data))
+	if err != nil {
+		return fmt.Errorf("failed to deserialize phase 2 evaluations: %w", err)
+	}
+	return nil
+}
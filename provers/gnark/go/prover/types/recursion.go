@@ -0,0 +1,106 @@
+package types
+
+import (
+	"fmt"
+	"math/big"
+
+	bls12377 "github.com/consensys/gnark-crypto/ecc/bls12-377"
+	"github.com/consensys/gnark-crypto/ecc/bls12-377/fp"
+	bn254 "github.com/consensys/gnark-crypto/ecc/bn254"
+	bls12377groth16 "github.com/consensys/gnark/backend/groth16/bls12-377"
+	bn254plonk "github.com/consensys/gnark/backend/plonk/bn254"
+)
+
+// RecursionValueBytes is the size of the big-endian encoding of a value returned
+// by RecursionValues, large enough for the base field of BLS12-377
+const RecursionValueBytes = 48
+
+// RecursionValues flattens the proof into elements of the BW6-761 scalar field,
+// in the layout of rsnark_core::recursion::groth16::Proof
+func (p *Groth16Proof) RecursionValues() ([]*big.Int, error) {
+	proof, ok := p.Proof.(*bls12377groth16.Proof)
+	if !ok {
+		return nil, fmt.Errorf("failed to cast proof to bls12377groth16.Proof")
+	}
+	if len(proof.Commitments) != 0 {
+		return nil, fmt.Errorf("proofs with commitments are not supported")
+	}
+
+	return fpToBigInts([]fp.Element{
+		proof.Ar.X, proof.Ar.Y,
+		proof.Krs.X, proof.Krs.Y,
+		proof.Bs.X.A0, proof.Bs.X.A1, proof.Bs.Y.A0, proof.Bs.Y.A1,
+	}), nil
+}
+
+// RecursionValues flattens the verifying key into elements of the BW6-761 scalar
+// field, in the layout of rsnark_core::recursion::groth16::VerifyingKey
+func (vk *Groth16VerifyingKey) RecursionValues() ([]*big.Int, error) {
+	key, ok := vk.Key.(*bls12377groth16.VerifyingKey)
+	if !ok {
+		return nil, fmt.Errorf("failed to cast verifying key to bls12377groth16.VerifyingKey")
+	}
+	if len(key.CommitmentKeys) != 0 || len(key.PublicAndCommitmentCommitted) != 0 {
+		return nil, fmt.Errorf("verifying keys with commitments are not supported")
+	}
+
+	// The verifier circuit checks e(Ar, Bs) = e(α, β) · e(K, γ) · e(Krs, δ),
+	// with e(α, β) precomputed and γ, δ negated as in gnark's ValueOfVerifyingKey
+	e, err := bls12377.Pair([]bls12377.G1Affine{key.G1.Alpha}, []bls12377.G2Affine{key.G2.Beta})
+	if err != nil {
+		return nil, fmt.Errorf("failed to compute e(alpha, beta): %w", err)
+	}
+	var gammaNeg, deltaNeg bls12377.G2Affine
+	gammaNeg.Neg(&key.G2.Gamma)
+	deltaNeg.Neg(&key.G2.Delta)
+
+	values := []fp.Element{
+		e.C0.B0.A0, e.C0.B0.A1, e.C0.B1.A0, e.C0.B1.A1, e.C0.B2.A0, e.C0.B2.A1,
+		e.C1.B0.A0, e.C1.B0.A1, e.C1.B1.A0, e.C1.B1.A1, e.C1.B2.A0, e.C1.B2.A1,
+		gammaNeg.X.A0, gammaNeg.X.A1, gammaNeg.Y.A0, gammaNeg.Y.A1,
+		deltaNeg.X.A0, deltaNeg.X.A1, deltaNeg.Y.A0, deltaNeg.Y.A1,
+	}
+	for _, k := range key.G1.K {
+		values = append(values, k.X, k.Y)
+	}
+
+	return fpToBigInts(values), nil
+}
+
+// RecursionValues flattens the proof into elements of the BN254 base and scalar
+// fields, in the layout of rsnark_core::recursion::plonk::Proof
+func (p *PlonkProof) RecursionValues() ([]*big.Int, error) {
+	proof, ok := p.Proof.(*bn254plonk.Proof)
+	if !ok {
+		return nil, fmt.Errorf("failed to cast proof to bn254plonk.Proof")
+	}
+	if len(proof.Bsb22Commitments) != 0 {
+		return nil, fmt.Errorf("proofs with commitments are not supported")
+	}
+
+	points := []bn254.G1Affine{
+		proof.LRO[0], proof.LRO[1], proof.LRO[2],
+		proof.Z,
+		proof.H[0], proof.H[1], proof.H[2],
+		proof.BatchedProof.H,
+		proof.ZShiftedOpening.H,
+	}
+	values := make([]*big.Int, 0, 2*len(points)+len(proof.BatchedProof.ClaimedValues)+1)
+	for _, point := range points {
+		values = append(values, point.X.BigInt(new(big.Int)), point.Y.BigInt(new(big.Int)))
+	}
+	for _, value := range proof.BatchedProof.ClaimedValues {
+		values = append(values, value.BigInt(new(big.Int)))
+	}
+	values = append(values, proof.ZShiftedOpening.ClaimedValue.BigInt(new(big.Int)))
+
+	return values, nil
+}
+
+func fpToBigInts(elements []fp.Element) []*big.Int {
+	values := make([]*big.Int, len(elements))
+	for i := range elements {
+		values[i] = elements[i].BigInt(new(big.Int))
+	}
+	return values
+}
@@ -0,0 +1,227 @@
+//! Groth16 MPC setup, built on gnark's `mpcsetup` over BN254. The steps are
+//! described on [`Groth16Phase2`].
+
+use rsnark_core::curve::BN254;
+
+use crate::{
+    Error, Result, ffi,
+    types::{
+        CompiledCircuit, GoInnerRef, Groth16Phase1, Groth16Phase2, Groth16Phase2Evaluations,
+        Groth16ProvingKey, Groth16VerifyingKey,
+    },
+};
+
+/// Reads the two object ids returned by Gnark, or its error code.
+fn object_pair(res: &[u8]) -> Result<(i64, i64)> {
+    let res0 = i64::from_be_bytes(res[0..8].try_into().unwrap());
+    let res1 = i64::from_be_bytes(res[8..16].try_into().unwrap());
+
+    if res0 >= 0 && res1 >= 0 {
+        Ok((res0, res1))
+    } else {
+        Err(Error::from_go_error(res0))
+    }
+}
+
+impl Groth16Phase1<BN254> {
+    /// Runs phase 1 with a single contribution, large enough for `cs`.
+    ///
+    /// This is insecure: the toxic waste of the contribution is known to this
+    /// process. It is meant for tests of the rest of the ceremony.
+    pub fn insecure_test(cs: &CompiledCircuit<BN254>) -> Result<Self> {
+        let res = ffi::groth16::phase1_insecure(cs.go_inner_ref());
+
+        if res >= 0 {
+            Ok(Self::from_go_inner_ref(res))
+        } else {
+            Err(Error::from_go_error(res))
+        }
+    }
+}
+
+impl Groth16Phase2<BN254> {
+    /// Starts phase 2 for `cs`, returning the initial state and the evaluations
+    /// of the circuit needed by [`Self::extract`].
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::CeremonyError`] if `phase1` is too small for `cs`.
+    pub fn initialize(
+        phase1: &Groth16Phase1<BN254>,
+        cs: &CompiledCircuit<BN254>,
+    ) -> Result<(Self, Groth16Phase2Evaluations<BN254>)> {
+        let res = ffi::groth16::phase2_initialize(phase1.go_inner_ref(), cs.go_inner_ref());
+        let (phase2, evaluations) = object_pair(&res)?;
+
+        Ok((
+            Self::from_go_inner_ref(phase2),
+            Groth16Phase2Evaluations::from_go_inner_ref(evaluations),
+        ))
+    }
+
+    /// Contributes fresh randomness on top of this state, returning the next
+    /// state. The randomness is discarded once the contribution is made.
+    pub fn contribute(&self) -> Result<Self> {
+        let res = ffi::groth16::phase2_contribute(self.go_inner_ref());
+
+        if res >= 0 {
+            Ok(Self::from_go_inner_ref(res))
+        } else {
+            Err(Error::from_go_error(res))
+        }
+    }
+
+    /// Verifies that `next` is a valid contribution on top of this state.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::CeremonyError`] if it is not.
+    pub fn verify(&self, next: &Self) -> Result<()> {
+        let res = ffi::groth16::phase2_verify(self.go_inner_ref(), next.go_inner_ref());
+
+        if res == 0 {
+            Ok(())
+        } else {
+            Err(Error::from_go_error(res))
+        }
+    }
+
+    /// Verifies a transcript and extracts the keys of the ceremony.
+    ///
+    /// `transcript` starts with the initial state of `cs` and is followed by
+    /// every contribution in order. The last state is sealed with `beacon`, a
+    /// public random value that no participant could predict.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::CeremonyError`] if the transcript has no contribution,
+    /// does not start from the initial state, or has an invalid contribution.
+    pub fn extract(
+        phase1: &Groth16Phase1<BN254>,
+        cs: &CompiledCircuit<BN254>,
+        evaluations: &Groth16Phase2Evaluations<BN254>,
+        transcript: &[Self],
+        beacon: &[u8],
+    ) -> Result<(Groth16ProvingKey<BN254>, Groth16VerifyingKey<BN254>)> {
+        let [initial, .., last] = transcript else {
+            return Err(Error::CeremonyError);
+        };
+
+        let (expected, _) = Self::initialize(phase1, cs)?;
+        if initial.serialize()? != expected.serialize()? {
+            return Err(Error::CeremonyError);
+        }
+
+        for pair in transcript.windows(2) {
+            pair[0].verify(&pair[1])?;
+        }
+
+        let res = ffi::groth16::phase2_seal(
+            phase1.go_inner_ref(),
+            evaluations.go_inner_ref(),
+            last.go_inner_ref(),
+            beacon.to_vec(),
+        );
+        let (pk, vk) = object_pair(&res)?;
+
+        Ok((
+            Groth16ProvingKey::from_go_inner_ref(pk),
+            Groth16VerifyingKey::from_go_inner_ref(vk),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnark_core::{CircuitWitness, Witness};
+    use rsnark_provers_core::{CircuitProver, Prover, test_circuits::TestCircuit};
+
+    use crate::Groth16Backend;
+
+    struct Ceremony {
+        prover: CircuitProver<Groth16Backend<BN254>, Witness<TestCircuit>>,
+        phase1: Groth16Phase1<BN254>,
+        evaluations: Groth16Phase2Evaluations<BN254>,
+        transcript: Vec<Groth16Phase2<BN254>>,
+    }
+
+    impl Ceremony {
+        fn run(contributions: usize) -> Self {
+            let prover = Prover::<Groth16Backend<BN254>>::new()
+                .compile_circuit::<TestCircuit>()
+                .unwrap();
+
+            let phase1 = Groth16Phase1::insecure_test(prover.constraint()).unwrap();
+            let (initial, evaluations) =
+                Groth16Phase2::initialize(&phase1, prover.constraint()).unwrap();
+
+            let mut transcript = vec![initial];
+            for _ in 0..contributions {
+                let next = transcript.last().unwrap().contribute().unwrap();
+                transcript.push(next);
+            }
+
+            Self {
+                prover,
+                phase1,
+                evaluations,
+                transcript,
+            }
+        }
+
+        fn extract(
+            &self,
+            transcript: &[Groth16Phase2<BN254>],
+        ) -> Result<(Groth16ProvingKey<BN254>, Groth16VerifyingKey<BN254>)> {
+            Groth16Phase2::extract(
+                &self.phase1,
+                self.prover.constraint(),
+                &self.evaluations,
+                transcript,
+                b"beacon",
+            )
+        }
+    }
+
+    #[test]
+    fn test_extract_and_prove() {
+        let ceremony = Ceremony::run(2);
+        let (pk, vk) = ceremony.extract(&ceremony.transcript).unwrap();
+
+        let witness = Witness::<TestCircuit> { a: 3, b: 4, c: 7 };
+        let proof = ceremony.prover.prove(&pk, &witness).unwrap();
+        ceremony
+            .prover
+            .verify(&vk, &proof, witness.into_public_witness())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_deserialized_contribution() {
+        let ceremony = Ceremony::run(1);
+
+        let bytes = ceremony.transcript[1].serialize().unwrap();
+        let next = Groth16Phase2::<BN254>::deserialize(bytes).unwrap();
+        ceremony.transcript[0].verify(&next).unwrap();
+    }
+
+    #[test]
+    fn test_extract_rejects_invalid_transcripts() {
+        let ceremony = Ceremony::run(2);
+        let transcript = &ceremony.transcript;
+
+        assert!(matches!(
+            ceremony.extract(&transcript[..1]),
+            Err(Error::CeremonyError)
+        ));
+        assert!(matches!(
+            ceremony.extract(&transcript[1..]),
+            Err(Error::CeremonyError)
+        ));
+        assert!(matches!(
+            transcript[0].verify(&transcript[2]),
+            Err(Error::CeremonyError)
+        ));
+    }
+}
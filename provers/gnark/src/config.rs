@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+
+use crate::KzgSrs;
+
+/// Options of the gnark backends: the config of
+/// [`Groth16Backend`](crate::Groth16Backend), and part of [`PlonkConfig`].
+///
+/// ```rust,ignore
+/// let prover = Groth16BN254GnarkProver::with_config(GnarkConfig {
+///     max_procs: Some(4),
+///     profile: Some("circuit.pprof".into()),
+///     ..Default::default()
+/// })?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GnarkConfig {
+    /// Maximum number of threads running Go code at once, the `GOMAXPROCS` of
+    /// the Go runtime. This applies to the whole process, not only to this
+    /// backend.
+    pub max_procs: Option<usize>,
+    /// Path of a pprof profile of the constraints of the compiled circuit,
+    /// written by gnark's `profile` package on every compilation.
+    pub profile: Option<PathBuf>,
+    /// Options of gnark's `frontend.Compile`.
+    pub compile: CompileOptions,
+    /// Seed of an insecure setup, see
+    /// [`Groth16Backend::with_insecure_seed`](crate::Groth16Backend::with_insecure_seed).
+    pub insecure_seed: Option<u64>,
+}
+
+/// Options of gnark's `frontend.Compile`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompileOptions {
+    /// Number of constraints allocated up front.
+    pub capacity: Option<usize>,
+    /// Accepts inputs that are not part of any constraint, which gnark rejects
+    /// by default.
+    pub ignore_unconstrained_inputs: bool,
+    /// Length above which linear expressions are compressed into a new
+    /// variable. Only used by Groth16.
+    pub compress_threshold: Option<usize>,
+}
+
+impl GnarkConfig {
+    /// Serializes the config into the JSON read by the Go side.
+    pub(crate) fn to_json(&self) -> Vec<u8> {
+        serde_json::json!({
+            "max_procs": self.max_procs,
+            "profile": self.profile.as_ref().map(|path| path.to_string_lossy()),
+            "compile": {
+                "capacity": self.compile.capacity,
+                "ignore_unconstrained_inputs": self.compile.ignore_unconstrained_inputs,
+                "compress_threshold": self.compile.compress_threshold,
+            },
+            "insecure_seed": self.insecure_seed,
+        })
+        .to_string()
+        .into_bytes()
+    }
+}
+
+/// Options of the [`PlonkBackend`](crate::PlonkBackend), passed by
+/// `Backend::with_config`.
+///
+/// The fields match the builders of the backend, which are applied in order:
+/// the SRS, the recursion, then the options of [`GnarkConfig`], whose seed
+/// replaces the SRS.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlonkConfig {
+    /// Options shared with the Groth16 backend.
+    pub gnark: GnarkConfig,
+    /// SRS of the setup, see
+    /// [`PlonkBackend::with_srs`](crate::PlonkBackend::with_srs).
+    pub srs: Option<KzgSrs>,
+    /// Generates proofs for in-circuit verification, see
+    /// [`PlonkBackend::with_recursion`](crate::PlonkBackend::with_recursion).
+    pub recursion: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_json() {
+        let json: serde_json::Value =
+            serde_json::from_slice(&GnarkConfig::default().to_json()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "max_procs": null,
+                "profile": null,
+                "compile": {
+                    "capacity": null,
+                    "ignore_unconstrained_inputs": false,
+                    "compress_threshold": null,
+                },
+                "insecure_seed": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_config_json() {
+        let config = GnarkConfig {
+            max_procs: Some(2),
+            profile: Some(PathBuf::from("circuit.pprof")),
+            compile: CompileOptions {
+                capacity: Some(1024),
+                ignore_unconstrained_inputs: true,
+                compress_threshold: Some(300),
+            },
+            insecure_seed: Some(7),
+        };
+        let json: serde_json::Value = serde_json::from_slice(&config.to_json()).unwrap();
+
+        assert_eq!(json["max_procs"], 2);
+        assert_eq!(json["profile"], "circuit.pprof");
+        assert_eq!(json["compile"]["capacity"], 1024);
+        assert_eq!(json["compile"]["ignore_unconstrained_inputs"], true);
+        assert_eq!(json["compile"]["compress_threshold"], 300);
+        assert_eq!(json["insecure_seed"], 7);
+    }
+}
@@ -51,6 +51,24 @@ pub enum Error {
     #[error("failed to export solidity")]
     ExportSolidityError,
 
+    #[error("failed to convert to a witness of a recursive circuit")]
+    RecursionError,
+
+    #[error("verifying key has {found} public inputs, expected {expected}")]
+    WrongPublicInputCount { expected: usize, found: usize },
+
+    #[error("no KZG SRS configured, use `with_srs` or `insecure_test_srs`")]
+    MissingSrsError,
+
+    #[error("failed to load the KZG SRS")]
+    SrsError,
+
+    #[error("invalid MPC setup step or contribution")]
+    CeremonyError,
+
+    #[error("invalid gnark config")]
+    ConfigError,
+
     #[error("prover not found")]
     ProverNotFoundError,
 
@@ -59,6 +77,9 @@ pub enum Error {
 
     #[error(transparent)]
     ConvertToStringError(#[from] std::string::FromUtf8Error),
+
+    #[error(transparent)]
+    FingerprintError(#[from] rsnark_core::fingerprint::FingerprintError),
 }
 
 impl Error {
@@ -94,9 +115,22 @@ impl Error {
             -20011 => Self::ProverNotFoundError,
             -20012 => Self::ObjectNotFoundError,
             -10006 => Self::ExportSolidityError,
+            -10008 => Self::RecursionError,
+            -10009 => Self::MissingSrsError,
+            -10010 => Self::SrsError,
+            -10011 => Self::CeremonyError,
+            -10012 => Self::ConfigError,
             _ => Self::UnknownGoError(code),
         }
     }
+
+    /// Converts the status code of a Go call, which is `0` on success.
+    pub(crate) fn check(code: i64) -> Result<()> {
+        match code {
+            0 => Ok(()),
+            _ => Err(Self::from_go_error(code)),
+        }
+    }
 }
 
 /// Convenience type alias for Results with Gnark Error.
@@ -8,7 +8,7 @@ pub mod binding {
 pub trait Groth16Prover {
     fn groth16_create(curve_id: u64) -> u64;
 
-    fn groth16_compile(curve_id: u64, circuit: Vec<u8>) -> i64;
+    fn groth16_compile(prover: u64, circuit: Vec<u8>) -> i64;
 
     fn groth16_setup(prover: u64, compiled_circuit: i64) -> Vec<u8>;
 
@@ -17,6 +17,20 @@ pub trait Groth16Prover {
     fn groth16_verify(prover: u64, vk: i64, proof: i64, public_witness: Vec<u8>) -> i64;
 
     fn groth16_remove_prover(prover: u64);
+
+    fn groth16_set_seed(prover: u64, seed: u64) -> i64;
+
+    fn groth16_set_config(prover: u64, config: Vec<u8>) -> i64;
+
+    fn groth16_phase1_insecure(compiled_circuit: i64) -> i64;
+
+    fn groth16_phase2_initialize(phase1: i64, compiled_circuit: i64) -> Vec<u8>;
+
+    fn groth16_phase2_contribute(phase2: i64) -> i64;
+
+    fn groth16_phase2_verify(phase2: i64, next: i64) -> i64;
+
+    fn groth16_phase2_seal(phase1: i64, evaluations: i64, phase2: i64, beacon: Vec<u8>) -> Vec<u8>;
 }
 
 #[cfg(not(docsrs))]
@@ -24,7 +38,7 @@ pub trait Groth16Prover {
 pub trait PlonkProver {
     fn plonk_create(curve_id: u64) -> u64;
 
-    fn plonk_compile(curve_id: u64, circuit: Vec<u8>) -> i64;
+    fn plonk_compile(prover: u64, circuit: Vec<u8>) -> i64;
 
     fn plonk_setup(prover: u64, compiled_circuit: i64) -> Vec<u8>;
 
@@ -33,6 +47,14 @@ pub trait PlonkProver {
     fn plonk_verify(prover: u64, vk: i64, proof: i64, public_witness: Vec<u8>) -> i64;
 
     fn plonk_remove_prover(prover: u64);
+
+    fn plonk_set_recursion(prover: u64) -> i64;
+
+    fn plonk_set_srs(prover: u64, format: u64, path: String) -> i64;
+
+    fn plonk_set_seed(prover: u64, seed: u64) -> i64;
+
+    fn plonk_set_config(prover: u64, config: Vec<u8>) -> i64;
 }
 
 #[cfg(not(docsrs))]
@@ -49,6 +71,10 @@ pub trait Object {
     fn remove_object(object_id: i64);
 
     fn export_solidity(object_id: i64, type_id: u64) -> Vec<u8>;
+
+    fn constraint_stats(object_id: i64) -> Vec<u8>;
+
+    fn recursion_values(object_id: i64) -> Vec<u8>;
 }
 
 #[cfg(docsrs)]
@@ -57,7 +83,7 @@ pub mod groth16 {
         unimplemented!()
     }
 
-    pub fn compile(_curve_id: u64, _circuit: Vec<u8>) -> i64 {
+    pub fn compile(_prover: u64, _circuit: Vec<u8>) -> i64 {
         unimplemented!()
     }
 
@@ -76,6 +102,34 @@ pub mod groth16 {
     pub fn remove_prover(_prover: u64) {
         unimplemented!()
     }
+
+    pub fn set_seed(_prover: u64, _seed: u64) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn set_config(_prover: u64, _config: Vec<u8>) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn phase1_insecure(_compiled_circuit: i64) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn phase2_initialize(_phase1: i64, _compiled_circuit: i64) -> Vec<u8> {
+        unimplemented!()
+    }
+
+    pub fn phase2_contribute(_phase2: i64) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn phase2_verify(_phase2: i64, _next: i64) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn phase2_seal(_phase1: i64, _evaluations: i64, _phase2: i64, _beacon: Vec<u8>) -> Vec<u8> {
+        unimplemented!()
+    }
 }
 
 #[cfg(docsrs)]
@@ -100,6 +154,14 @@ pub mod object {
         unimplemented!()
     }
 
+    pub fn constraint_stats(_object_id: i64) -> Vec<u8> {
+        unimplemented!()
+    }
+
+    pub fn recursion_values(_object_id: i64) -> Vec<u8> {
+        unimplemented!()
+    }
+
     pub fn remove_object(_object_id: i64) {
         unimplemented!()
     }
@@ -111,7 +173,7 @@ pub mod plonk {
         unimplemented!()
     }
 
-    pub fn compile(_curve_id: u64, _circuit: Vec<u8>) -> i64 {
+    pub fn compile(_prover: u64, _circuit: Vec<u8>) -> i64 {
         unimplemented!()
     }
 
@@ -130,6 +192,22 @@ pub mod plonk {
     pub fn remove_prover(_prover: u64) {
         unimplemented!()
     }
+
+    pub fn set_recursion(_prover: u64) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn set_srs(_prover: u64, _format: u64, _path: String) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn set_seed(_prover: u64, _seed: u64) -> i64 {
+        unimplemented!()
+    }
+
+    pub fn set_config(_prover: u64, _config: Vec<u8>) -> i64 {
+        unimplemented!()
+    }
 }
 
 #[cfg(not(docsrs))]
@@ -140,8 +218,8 @@ pub mod groth16 {
         super::Groth16ProverImpl::groth16_create(curve_id)
     }
 
-    pub fn compile(curve_id: u64, circuit: Vec<u8>) -> i64 {
-        super::Groth16ProverImpl::groth16_compile(curve_id, circuit)
+    pub fn compile(prover: u64, circuit: Vec<u8>) -> i64 {
+        super::Groth16ProverImpl::groth16_compile(prover, circuit)
     }
 
     pub fn setup(prover: u64, compiled_circuit: i64) -> Vec<u8> {
@@ -159,6 +237,34 @@ pub mod groth16 {
     pub fn remove_prover(prover: u64) {
         super::Groth16ProverImpl::groth16_remove_prover(prover)
     }
+
+    pub fn set_seed(prover: u64, seed: u64) -> i64 {
+        super::Groth16ProverImpl::groth16_set_seed(prover, seed)
+    }
+
+    pub fn set_config(prover: u64, config: Vec<u8>) -> i64 {
+        super::Groth16ProverImpl::groth16_set_config(prover, config)
+    }
+
+    pub fn phase1_insecure(compiled_circuit: i64) -> i64 {
+        super::Groth16ProverImpl::groth16_phase1_insecure(compiled_circuit)
+    }
+
+    pub fn phase2_initialize(phase1: i64, compiled_circuit: i64) -> Vec<u8> {
+        super::Groth16ProverImpl::groth16_phase2_initialize(phase1, compiled_circuit)
+    }
+
+    pub fn phase2_contribute(phase2: i64) -> i64 {
+        super::Groth16ProverImpl::groth16_phase2_contribute(phase2)
+    }
+
+    pub fn phase2_verify(phase2: i64, next: i64) -> i64 {
+        super::Groth16ProverImpl::groth16_phase2_verify(phase2, next)
+    }
+
+    pub fn phase2_seal(phase1: i64, evaluations: i64, phase2: i64, beacon: Vec<u8>) -> Vec<u8> {
+        super::Groth16ProverImpl::groth16_phase2_seal(phase1, evaluations, phase2, beacon)
+    }
 }
 
 #[cfg(not(docsrs))]
@@ -185,6 +291,14 @@ pub mod object {
         super::ObjectImpl::export_solidity(object_id, type_id)
     }
 
+    pub fn constraint_stats(object_id: i64) -> Vec<u8> {
+        super::ObjectImpl::constraint_stats(object_id)
+    }
+
+    pub fn recursion_values(object_id: i64) -> Vec<u8> {
+        super::ObjectImpl::recursion_values(object_id)
+    }
+
     pub fn remove_object(object_id: i64) {
         super::ObjectImpl::remove_object(object_id)
     }
@@ -198,8 +312,8 @@ pub mod plonk {
         super::PlonkProverImpl::plonk_create(curve_id)
     }
 
-    pub fn compile(curve_id: u64, circuit: Vec<u8>) -> i64 {
-        super::PlonkProverImpl::plonk_compile(curve_id, circuit)
+    pub fn compile(prover: u64, circuit: Vec<u8>) -> i64 {
+        super::PlonkProverImpl::plonk_compile(prover, circuit)
     }
 
     pub fn setup(prover: u64, compiled_circuit: i64) -> Vec<u8> {
@@ -217,4 +331,20 @@ pub mod plonk {
     pub fn remove_prover(prover: u64) {
         super::PlonkProverImpl::plonk_remove_prover(prover)
     }
+
+    pub fn set_recursion(prover: u64) -> i64 {
+        super::PlonkProverImpl::plonk_set_recursion(prover)
+    }
+
+    pub fn set_srs(prover: u64, format: u64, path: String) -> i64 {
+        super::PlonkProverImpl::plonk_set_srs(prover, format, path)
+    }
+
+    pub fn set_seed(prover: u64, seed: u64) -> i64 {
+        super::PlonkProverImpl::plonk_set_seed(prover, seed)
+    }
+
+    pub fn set_config(prover: u64, config: Vec<u8>) -> i64 {
+        super::PlonkProverImpl::plonk_set_config(prover, config)
+    }
 }
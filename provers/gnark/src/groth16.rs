@@ -2,12 +2,13 @@ use std::marker::PhantomData;
 
 use rsnark_core::{
     CurveId, MetadataInfo, ProvingSystem,
+    stats::ConstraintStats,
     types::{CircuitDefinition, PublicWitness, Witness},
 };
 use rsnark_provers_core::Backend;
 
 use crate::{
-    Error, Result, ffi,
+    Error, GnarkConfig, Result, ffi,
     types::{CompiledCircuit, GoInnerRef, Groth16Proof, Groth16ProvingKey, Groth16VerifyingKey},
 };
 
@@ -26,9 +27,11 @@ use crate::{
 ///
 /// The backend maintains a reference to Go-side objects through `go_ref_id`.
 /// These resources are managed by the Gnark library and cleaned up automatically
-/// when no longer referenced.
+/// when no longer referenced. A clone creates its own Go-side prover with the
+/// same config.
 pub struct Groth16Backend<C> {
     go_ref_id: u64,
+    config: GnarkConfig,
     marker: PhantomData<C>,
 }
 
@@ -43,10 +46,7 @@ where
     C: CurveId,
 {
     fn clone(&self) -> Self {
-        Self {
-            go_ref_id: self.go_ref_id,
-            marker: PhantomData,
-        }
+        Self::with_config(self.config.clone()).expect("the config was applied before")
     }
 }
 
@@ -61,16 +61,28 @@ where
 
         Self {
             go_ref_id: prover,
+            config: GnarkConfig::default(),
             marker: PhantomData,
         }
     }
 
+    /// Draws the toxic waste of `setup` from `seed`, so keys are reproducible.
+    ///
+    /// Anyone knowing the seed can forge proofs for these keys, so they must
+    /// only be used in tests, such as snapshots of verifying keys or Solidity
+    /// verifiers. While such a setup runs, the randomness of the whole Go
+    /// runtime is drawn from the seed, so other gnark setups, proofs and
+    /// ceremony contributions wait for it to finish. Proofs are still randomized.
+    pub fn with_insecure_seed(mut self, seed: u64) -> Result<Self> {
+        Error::check(ffi::groth16::set_seed(self.go_ref_id, seed))?;
+        self.config.insecure_seed = Some(seed);
+        Ok(self)
+    }
+
     fn _compile(&self, circuit: &CircuitDefinition) -> Result<CompiledCircuit<C>> {
         let circuit = serde_json::to_vec(circuit)?;
 
-        let curve = C::curve_id();
-
-        let res = ffi::groth16::compile(curve, circuit);
+        let res = ffi::groth16::compile(self.go_ref_id, circuit);
 
         if res >= 0 {
             Ok(CompiledCircuit::from_go_inner_ref(res))
@@ -158,10 +170,18 @@ where
     type VerifyingKey = Groth16VerifyingKey<C>;
     type Proof = Groth16Proof<C>;
 
+    type Config = GnarkConfig;
+
     type Error = Error;
 
-    fn new() -> Self {
-        Self::_new()
+    fn with_config(config: GnarkConfig) -> Result<Self> {
+        let mut backend = Self::_new();
+        Error::check(ffi::groth16::set_config(
+            backend.go_ref_id,
+            config.to_json(),
+        ))?;
+        backend.config = config;
+        Ok(backend)
     }
 
     fn metadata(&self) -> MetadataInfo {
@@ -176,6 +196,15 @@ where
         self._compile(circuit)
     }
 
+    fn constraint_stats(
+        &self,
+        compiled_circuit: &Self::CircuitConstraint,
+    ) -> Result<Option<ConstraintStats>> {
+        let stats = compiled_circuit.constraint_stats()?;
+
+        Ok(Some(stats))
+    }
+
     fn setup(
         &self,
         compiled_circuit: &Self::CircuitConstraint,
@@ -207,23 +236,66 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rsnark_core::{API, Circuit, CircuitWitness, Witness, circuit, curve::BN254};
-    use rsnark_provers_core::Prover;
+    use crate::CompileOptions;
+    use rsnark_core::{
+        API, Circuit, CircuitElement, CircuitWitness, Witness, circuit, curve::BN254, r1cs::R1cs,
+        typed::Context, types,
+    };
+    use rsnark_provers_core::{Prover, test_circuits::TestCircuit};
 
     #[circuit]
-    pub struct TestCircuit {
+    pub struct CheckedCircuit {
         a: u32,
         b: u32,
         pub c: u32,
     }
 
-    impl Circuit for TestCircuit {
+    impl Circuit for CheckedCircuit {
         fn define(&self, api: &mut impl API) {
-            let c = api.add(&self.a, &self.b);
-            api.assert_is_equal(&c, &self.c);
+            let q = api.div(&self.a, &self.b);
+            api.assert_is_equal(&q, &self.c);
+            let greater = api.cmp(&self.a, &self.b);
+            api.assert_is_equal(&greater, &1);
+            api.assert_is_less_or_equal(&self.b, &self.a);
+            api.variable_to_binary(&self.a, BN254::field().bits());
+        }
+    }
+
+    #[circuit]
+    pub struct UIntCircuit {
+        a: u8,
+        b: u8,
+        pub c: u8,
+    }
+
+    impl Circuit for UIntCircuit {
+        fn define(&self, api: &mut impl API) {
+            let ctx = Context::new(api);
+            let a = ctx.uint::<8>(&self.a);
+            let b = ctx.uint::<8>(&self.b);
+
+            (&(&a + &b) ^ &b).assert_eq(&self.c);
         }
     }
 
+    #[test]
+    fn test_typed_uint() {
+        let prover: Prover<Groth16Backend<BN254>> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<UIntCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        // (200 + 100) mod 256 = 44, and 44 ^ 100 = 72
+        let circuit_witness = Witness::<UIntCircuit> {
+            a: 200,
+            b: 100,
+            c: 72,
+        };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+
+        let public_witness = circuit_witness.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+    }
+
     #[test]
     fn test_groth16_with_core_prover() {
         let prover: Prover<Groth16Backend<BN254>> = Prover::new();
@@ -243,4 +315,122 @@ mod tests {
         let public_witness = circuit_witness.into_public_witness();
         circuit_prover.verify(&vk, &proof, public_witness).unwrap();
     }
+
+    #[test]
+    fn test_native_r1cs_agrees_with_gnark() {
+        fn check<C>(valid: Vec<Witness<C>>, invalid: Vec<Witness<C>>)
+        where
+            C: CircuitElement,
+            <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+        {
+            let prover: Prover<Groth16Backend<BN254>> = Prover::new();
+            let r1cs = R1cs::compile(&prover.define_circuit::<C>(), &BN254::field()).unwrap();
+            let circuit_prover = prover.compile_circuit::<C>().unwrap();
+            let (pk, vk) = circuit_prover.setup().unwrap();
+
+            let native = |circuit_witness: &Witness<C>| {
+                let (mut public, mut private) = (Vec::new(), Vec::new());
+                circuit_witness.append_witness(&mut public, &mut private, false);
+                r1cs.is_satisfied(&types::Witness::from((public, private)))
+            };
+
+            for witness in valid {
+                assert!(native(&witness));
+                let proof = circuit_prover.prove(&pk, &witness).unwrap();
+                circuit_prover
+                    .verify(&vk, &proof, witness.into_public_witness())
+                    .unwrap();
+            }
+
+            for witness in invalid {
+                assert!(!native(&witness));
+                let err = circuit_prover.prove(&pk, &witness).err().unwrap();
+                assert!(matches!(err.downcast_ref(), Some(Error::ProveError)));
+            }
+        }
+
+        check::<TestCircuit>(
+            vec![Witness::<TestCircuit> { a: 3, b: 4, c: 7 }],
+            vec![Witness::<TestCircuit> { a: 3, b: 4, c: 8 }],
+        );
+        check::<CheckedCircuit>(
+            vec![Witness::<CheckedCircuit> { a: 12, b: 4, c: 3 }],
+            vec![
+                Witness::<CheckedCircuit> { a: 12, b: 4, c: 4 },
+                Witness::<CheckedCircuit> { a: 0, b: 0, c: 5 },
+                Witness::<CheckedCircuit> { a: 4, b: 4, c: 1 },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_seeded_setup_is_reproducible() {
+        let vk = |seed| {
+            let prover = Prover::from_backend(
+                Groth16Backend::<BN254>::new()
+                    .with_insecure_seed(seed)
+                    .unwrap(),
+            );
+            let (_, vk) = prover
+                .compile_circuit::<TestCircuit>()
+                .unwrap()
+                .setup()
+                .unwrap();
+            vk.export_solidity().unwrap()
+        };
+
+        assert_eq!(vk(1), vk(1));
+        assert_ne!(vk(1), vk(2));
+    }
+
+    #[test]
+    fn test_with_config() {
+        let config = GnarkConfig {
+            compile: CompileOptions {
+                capacity: Some(16),
+                ignore_unconstrained_inputs: true,
+                compress_threshold: Some(300),
+            },
+            insecure_seed: Some(1),
+            ..Default::default()
+        };
+        let vk = |prover: Prover<Groth16Backend<BN254>>| {
+            let (_, vk) = prover
+                .compile_circuit::<TestCircuit>()
+                .unwrap()
+                .setup()
+                .unwrap();
+            vk.export_solidity().unwrap()
+        };
+
+        assert_eq!(
+            vk(Prover::with_config(config).unwrap()),
+            vk(Prover::from_backend(
+                Groth16Backend::new().with_insecure_seed(1).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_clone_owns_its_prover() {
+        let backend = Groth16Backend::<BN254>::new()
+            .with_insecure_seed(1)
+            .unwrap();
+        let clone = backend.clone();
+        assert_ne!(clone.go_ref_id, backend.go_ref_id);
+        drop(backend);
+
+        let vk = |backend: Groth16Backend<BN254>| {
+            let (_, vk) = Prover::from_backend(backend)
+                .compile_circuit::<TestCircuit>()
+                .unwrap()
+                .setup()
+                .unwrap();
+            vk.export_solidity().unwrap()
+        };
+        assert_eq!(
+            vk(clone),
+            vk(Groth16Backend::new().with_insecure_seed(1).unwrap())
+        );
+    }
 }
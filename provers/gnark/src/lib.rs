@@ -10,6 +10,12 @@
 //! ## Key Components
 //!
 //! - [`Groth16Backend`]: Backend implementation using Gnark's Groth16 prover
+//! - [`PlonkBackend`]: Backend implementation using Gnark's PLONK prover, with keys
+//!   generated from a [`KzgSrs`]
+//! - [`GnarkConfig`] and [`PlonkConfig`]: Options of the backends, such as
+//!   `GOMAXPROCS`, profiling and the options of `frontend.Compile`
+//! - [`Groth16Phase2`](types::Groth16Phase2): Groth16 keys from a multi-party
+//!   setup ceremony over BN254
 //! - [`Error`]: Error types for Gnark operations and Go FFI interactions
 //! - [`types`]: Type definitions for compiled circuits, proving keys, and verifying keys
 //!
@@ -37,3 +43,11 @@ pub use groth16::*;
 
 mod plonk;
 pub use plonk::*;
+
+mod srs;
+pub use srs::*;
+
+mod config;
+pub use config::*;
+
+mod ceremony;
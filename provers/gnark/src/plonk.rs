@@ -2,13 +2,14 @@ use std::marker::PhantomData;
 
 use rsnark_core::{
     CurveId, MetadataInfo, ProvingSystem,
+    stats::ConstraintStats,
     types::{CircuitDefinition, PublicWitness, Witness},
 };
 use rsnark_provers_core::Backend;
 
 use crate::{
-    Error, Result, ffi,
-    types::{CompiledCircuit, GoInnerRef, PlonkProof, PlonkProvingKey, PlonkVerifyingKey},
+    Error, KzgSrs, PlonkConfig, Result, ffi,
+    types::{GoInnerRef, PlonkCompiledCircuit, PlonkProof, PlonkProvingKey, PlonkVerifyingKey},
 };
 
 /// PLONK backend implementation using the Gnark library.
@@ -27,6 +28,13 @@ use crate::{
 /// The backend maintains a reference to Go-side objects through `go_ref_id`.
 /// These resources are managed by the Gnark library and cleaned up automatically
 /// when no longer referenced.
+///
+/// # Setup
+///
+/// Keys are generated from a KZG SRS, which must be configured with
+/// [`with_srs`](Self::with_srs) before calling `setup`. Tests may use
+/// [`insecure_test_srs`](Self::insecure_test_srs) or
+/// [`with_insecure_seed`](Self::with_insecure_seed) instead.
 pub struct PlonkBackend<C> {
     go_ref_id: u64,
     marker: PhantomData<C>,
@@ -53,15 +61,56 @@ where
         }
     }
 
-    fn _compile(&self, circuit: &CircuitDefinition) -> Result<CompiledCircuit<C>> {
-        let circuit = serde_json::to_vec(circuit)?;
+    /// Generates keys from `srs`.
+    ///
+    /// The SRS is loaded by `setup`, which fails with [`Error::SrsError`] if the
+    /// file cannot be read or is too small for the circuit.
+    pub fn with_srs(self, srs: KzgSrs) -> Result<Self> {
+        Error::check(ffi::plonk::set_srs(
+            self.go_ref_id,
+            srs.format(),
+            srs.path(),
+        ))?;
+        Ok(self)
+    }
 
-        let curve = C::curve_id();
+    /// Generates keys from an SRS with known toxic waste.
+    ///
+    /// Anyone can forge proofs for these keys, so they must only be used in
+    /// tests.
+    pub fn insecure_test_srs(self) -> Result<Self> {
+        Error::check(ffi::plonk::set_srs(self.go_ref_id, 0, String::new()))?;
+        Ok(self)
+    }
 
-        let res = ffi::plonk::compile(curve, circuit);
+    /// Generates keys from an SRS whose toxic waste is derived from `seed`, so
+    /// keys are reproducible. This replaces any SRS configured before.
+    ///
+    /// Anyone knowing the seed can forge proofs for these keys, so they must
+    /// only be used in tests, such as snapshots of verifying keys or Solidity
+    /// verifiers. Proofs are still randomized.
+    pub fn with_insecure_seed(self, seed: u64) -> Result<Self> {
+        Error::check(ffi::plonk::set_seed(self.go_ref_id, seed))?;
+        Ok(self)
+    }
+
+    /// Generates proofs that can be verified in circuits over the same curve, see
+    /// [`rsnark_core::recursion::plonk`].
+    ///
+    /// This selects the hash functions of the in-circuit verifier for the
+    /// transcript, so these proofs cannot be verified by the Solidity verifier.
+    pub fn with_recursion(self) -> Result<Self> {
+        Error::check(ffi::plonk::set_recursion(self.go_ref_id))?;
+        Ok(self)
+    }
+
+    fn _compile(&self, circuit: &CircuitDefinition) -> Result<PlonkCompiledCircuit<C>> {
+        let circuit = serde_json::to_vec(circuit)?;
+
+        let res = ffi::plonk::compile(self.go_ref_id, circuit);
 
         if res >= 0 {
-            Ok(CompiledCircuit::from_go_inner_ref(res))
+            Ok(PlonkCompiledCircuit::from_go_inner_ref(res))
         } else {
             Err(Error::from_go_error(res))
         }
@@ -69,7 +118,7 @@ where
 
     fn _setup(
         &self,
-        compiled_circuit: &CompiledCircuit<C>,
+        compiled_circuit: &PlonkCompiledCircuit<C>,
     ) -> Result<(PlonkProvingKey<C>, PlonkVerifyingKey<C>)> {
         let res = ffi::plonk::setup(self.go_ref_id, compiled_circuit.go_inner_ref());
 
@@ -88,7 +137,7 @@ where
 
     fn _prove(
         &self,
-        compiled_circuit: &CompiledCircuit<C>,
+        compiled_circuit: &PlonkCompiledCircuit<C>,
         pk: &PlonkProvingKey<C>,
         witness: &Witness,
     ) -> Result<PlonkProof<C>> {
@@ -141,15 +190,28 @@ impl<C> Backend for PlonkBackend<C>
 where
     C: CurveId,
 {
-    type CircuitConstraint = CompiledCircuit<C>;
+    type CircuitConstraint = PlonkCompiledCircuit<C>;
     type ProvingKey = PlonkProvingKey<C>;
     type VerifyingKey = PlonkVerifyingKey<C>;
     type Proof = PlonkProof<C>;
 
+    type Config = PlonkConfig;
+
     type Error = Error;
 
-    fn new() -> Self {
-        Self::_new()
+    fn with_config(config: PlonkConfig) -> Result<Self> {
+        let mut backend = Self::_new();
+        if let Some(srs) = config.srs {
+            backend = backend.with_srs(srs)?;
+        }
+        if config.recursion {
+            backend = backend.with_recursion()?;
+        }
+        Error::check(ffi::plonk::set_config(
+            backend.go_ref_id,
+            config.gnark.to_json(),
+        ))?;
+        Ok(backend)
     }
 
     fn metadata(&self) -> MetadataInfo {
@@ -164,6 +226,12 @@ where
         self._compile(circuit)
     }
 
+    fn constraint_stats(&self, cs: &Self::CircuitConstraint) -> Result<Option<ConstraintStats>> {
+        let stats = cs.constraint_stats()?;
+
+        Ok(Some(stats))
+    }
+
     fn setup(
         &self,
         cs: &Self::CircuitConstraint,
@@ -191,3 +259,87 @@ where
         self._verify(vk, proof, public_witness)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnark_core::{CircuitWitness, Witness, curve::BN254, types};
+    use rsnark_provers_core::{Prover, test_circuits::TestCircuit};
+
+    fn setup(backend: PlonkBackend<BN254>) -> Result<()> {
+        let definition = Prover::<PlonkBackend<BN254>>::new().define_circuit::<TestCircuit>();
+        let cs = backend.compile(&definition)?;
+        backend.setup(&cs).map(|_| ())
+    }
+
+    #[test]
+    fn test_plonk_with_insecure_test_srs() {
+        let prover =
+            Prover::from_backend(PlonkBackend::<BN254>::new().insecure_test_srs().unwrap());
+
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<TestCircuit> { a: 3, b: 4, c: 7 };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+
+        let public_witness = circuit_witness.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+    }
+
+    #[test]
+    fn test_setup_without_srs() {
+        assert!(matches!(
+            setup(PlonkBackend::new()),
+            Err(Error::MissingSrsError)
+        ));
+    }
+
+    #[test]
+    fn test_seeded_setup_is_reproducible() {
+        let vk = |seed| {
+            let prover = Prover::from_backend(
+                PlonkBackend::<BN254>::new()
+                    .with_insecure_seed(seed)
+                    .unwrap(),
+            );
+            let (_, vk) = prover
+                .compile_circuit::<TestCircuit>()
+                .unwrap()
+                .setup()
+                .unwrap();
+            vk.serialize().unwrap()
+        };
+
+        assert_eq!(vk(1), vk(1));
+        assert_ne!(vk(1), vk(2));
+    }
+
+    #[test]
+    fn test_setup_with_missing_srs_file() {
+        let backend =
+            PlonkBackend::new().with_srs(KzgSrs::Ptau("/nonexistent/powersOfTau.ptau".into()));
+
+        assert!(matches!(setup(backend.unwrap()), Err(Error::SrsError)));
+    }
+
+    #[test]
+    fn test_dyn_backend() {
+        use rsnark_provers_core::DynBackend;
+
+        let backend: Box<dyn DynBackend> =
+            Box::new(PlonkBackend::<BN254>::new().insecure_test_srs().unwrap());
+        let definition = Prover::<PlonkBackend<BN254>>::new().define_circuit::<TestCircuit>();
+
+        // The constraint system is deserialized as a sparse one by every call
+        let cs = backend.compile(&definition).unwrap();
+        let (pk, vk) = backend.setup(&cs).unwrap();
+
+        let witness = types::Witness::from((vec![7.into()], vec![3.into(), 4.into()]));
+        let proof = backend.prove(&cs, &pk, &witness).unwrap();
+
+        let mut public = types::PublicWitness::new();
+        public.public_mut().push(7.into());
+        assert!(backend.verify(&vk, &proof, &public).unwrap());
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+/// A KZG structured reference string, used by [`PlonkBackend`](crate::PlonkBackend)
+/// to generate keys.
+///
+/// The file holds the powers of a secret `τ` from a trusted setup ceremony.
+/// It is read once per circuit size and cached, and must have at least as many
+/// powers as the size of the circuit plus 3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KzgSrs {
+    /// A canonical SRS serialized by gnark, over the curve of the backend.
+    Gnark(PathBuf),
+    /// A powers-of-tau transcript in the snarkjs `.ptau` format, such as the
+    /// files of the perpetual powers of tau ceremony. Only supported on BN254.
+    Ptau(PathBuf),
+}
+
+impl KzgSrs {
+    pub(crate) fn format(&self) -> u64 {
+        match self {
+            Self::Gnark(_) => 1,
+            Self::Ptau(_) => 2,
+        }
+    }
+
+    pub(crate) fn path(&self) -> String {
+        match self {
+            Self::Gnark(path) | Self::Ptau(path) => path.to_string_lossy().into_owned(),
+        }
+    }
+}
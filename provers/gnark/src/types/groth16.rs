@@ -1,6 +1,14 @@
 use std::marker::PhantomData;
 
-use rsnark_core::{CurveId, curve::BN254};
+use num::{BigInt, bigint::Sign};
+use rsnark_core::{
+    CurveId,
+    curve::{BLS12_377, BN254},
+    fingerprint::{self, Fingerprint},
+    recursion::{groth16, plonk},
+    stats::{ConstraintStats, ConstraintSystemKind},
+};
+use rsnark_provers_core::Artifact;
 use ruint::aliases::U256;
 
 use crate::{
@@ -37,6 +45,11 @@ macro_rules! impl_groth16_object {
             pub fn write_to_file(object_id: i64, path: String) -> Result<()> {
                 Self::inner_write_to_file(object_id, path)
             }
+
+            /// Serializes this object, sealed with the fingerprint of its circuit.
+            pub fn serialize_with_fingerprint(&self, fingerprint: &Fingerprint) -> Result<Vec<u8>> {
+                Ok(fingerprint::seal(fingerprint, &self.serialize()?))
+            }
         }
 
         impl<C> $name<C>
@@ -50,6 +63,31 @@ macro_rules! impl_groth16_object {
             pub fn read_from_file(path: String) -> Result<Self> {
                 Self::inner_read_from_file::<C>($type_id, path)
             }
+
+            /// Deserializes an object sealed by `serialize_with_fingerprint`.
+            ///
+            /// Fails with [`Error::FingerprintError`] if it was sealed for another circuit.
+            pub fn deserialize_with_fingerprint(
+                fingerprint: &Fingerprint,
+                data: &[u8],
+            ) -> Result<Self> {
+                Self::deserialize(fingerprint::open(fingerprint, data)?.to_vec())
+            }
+        }
+
+        impl<C> Artifact for $name<C>
+        where
+            C: CurveId,
+        {
+            type Error = Error;
+
+            fn to_bytes(&self) -> Result<Vec<u8>> {
+                self.serialize()
+            }
+
+            fn from_bytes(data: &[u8]) -> Result<Self> {
+                Self::deserialize(data.to_vec())
+            }
         }
     };
 }
@@ -88,6 +126,50 @@ pub struct CompiledCircuit<C> {
 }
 impl_groth16_object!(CompiledCircuit, 3);
 
+impl<C> CompiledCircuit<C> {
+    /// Reads the size of the compiled R1CS from Gnark.
+    pub(crate) fn constraint_stats(&self) -> Result<ConstraintStats> {
+        constraint_stats(self.go_ref_id, ConstraintSystemKind::R1cs)
+    }
+}
+
+/// Compiled circuit wrapper for Go-side PLONK constraint systems.
+///
+/// This is the [`CompiledCircuit`] of the [`PlonkBackend`](crate::PlonkBackend),
+/// compiled into Gnark's sparse constraint system format.
+pub struct PlonkCompiledCircuit<C> {
+    go_ref_id: i64,
+    marker: PhantomData<C>,
+}
+impl_groth16_object!(PlonkCompiledCircuit, 11);
+
+impl<C> PlonkCompiledCircuit<C> {
+    /// Reads the size of the compiled sparse constraint system from Gnark.
+    pub(crate) fn constraint_stats(&self) -> Result<ConstraintStats> {
+        constraint_stats(self.go_ref_id, ConstraintSystemKind::Scs)
+    }
+}
+
+fn constraint_stats(go_ref_id: i64, system: ConstraintSystemKind) -> Result<ConstraintStats> {
+    let res = ffi::object::constraint_stats(go_ref_id);
+
+    let code = i64::from_be_bytes(res[0..8].try_into().unwrap());
+
+    if code != 0 {
+        return Err(Error::from_go_error(code));
+    }
+
+    let count = |i: usize| u64::from_be_bytes(res[8 * i..8 * (i + 1)].try_into().unwrap());
+
+    Ok(ConstraintStats {
+        system,
+        constraints: count(1),
+        internal_variables: count(2),
+        public_variables: count(3),
+        secret_variables: count(4),
+    })
+}
+
 impl Groth16VerifyingKey<BN254> {
     /// Exports the verifying key as Solidity contract code.
     ///
@@ -116,6 +198,49 @@ impl Groth16VerifyingKey<BN254> {
     }
 }
 
+/// Size of an element returned by Gnark, large enough for the base field of
+/// BLS12-377.
+const RECURSION_VALUE_BYTES: usize = 48;
+
+/// Reads the flattened elements of an object from Gnark.
+fn recursion_values(go_ref_id: i64) -> Result<Vec<BigInt>> {
+    let res = ffi::object::recursion_values(go_ref_id);
+
+    let code = i64::from_be_bytes(res[0..8].try_into().unwrap());
+
+    if code != 0 {
+        return Err(Error::from_go_error(code));
+    }
+
+    Ok(res[8..]
+        .chunks(RECURSION_VALUE_BYTES)
+        .map(|bytes| BigInt::from_bytes_be(Sign::Plus, bytes))
+        .collect())
+}
+
+impl Groth16VerifyingKey<BLS12_377> {
+    /// Converts the verifying key into a constant of a BW6-761 circuit verifying
+    /// its proofs, see [`groth16::assert_proof`].
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::WrongPublicInputCount`] if the circuit of the key does
+    /// not have `N` public inputs, and with [`Error::RecursionError`] if the key
+    /// has commitments.
+    pub fn to_recursion_key<const N: usize>(&self) -> Result<groth16::VerifyingKey<N>> {
+        let values = recursion_values(self.go_ref_id)?;
+
+        if values.len() != groth16::verifying_key_len(N) {
+            return Err(Error::WrongPublicInputCount {
+                expected: N,
+                found: (values.len() - groth16::verifying_key_len(0)) / 2,
+            });
+        }
+
+        Ok(groth16::VerifyingKey::new(values))
+    }
+}
+
 pub struct Groth16Proof<C> {
     go_ref_id: i64,
     marker: PhantomData<C>,
@@ -143,6 +268,18 @@ impl Groth16Proof<BN254> {
     }
 }
 
+impl Groth16Proof<BLS12_377> {
+    /// Converts the proof into a witness of a BW6-761 circuit verifying it, see
+    /// [`groth16::assert_proof`].
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::RecursionError`] if the proof has commitments.
+    pub fn to_recursion_proof(&self) -> Result<groth16::Proof> {
+        Ok(groth16::Proof::new(recursion_values(self.go_ref_id)?))
+    }
+}
+
 pub struct PlonkProvingKey<C> {
     go_ref_id: i64,
     marker: PhantomData<C>,
@@ -168,6 +305,12 @@ impl PlonkVerifyingKey<BN254> {
             Ok(string)
         }
     }
+
+    /// Converts the verifying key into a constant of a BN254 circuit verifying
+    /// its proofs, see [`plonk::assert_proof`].
+    pub fn to_recursion_key(&self) -> Result<plonk::VerifyingKey> {
+        Ok(plonk::VerifyingKey::from_bytes(&self.inner_serialize()?))
+    }
 }
 
 pub struct PlonkProof<C> {
@@ -194,4 +337,60 @@ impl PlonkProof<BN254> {
             Ok(data)
         }
     }
+
+    /// Converts the proof into a witness of a BN254 circuit verifying it, see
+    /// [`plonk::assert_proof`]. The proof must come from a backend created with
+    /// [`PlonkBackend::with_recursion`](crate::PlonkBackend::with_recursion).
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::RecursionError`] if the proof has commitments.
+    pub fn to_recursion_proof(&self) -> Result<plonk::Proof> {
+        let values = recursion_values(self.go_ref_id)?;
+
+        if values.len() != plonk::PROOF_ELEMENTS {
+            return Err(Error::RecursionError);
+        }
+
+        Ok(plonk::Proof::new(values))
+    }
+}
+
+/// Sealed output of phase 1 of a Groth16 MPC setup: the powers of `τ`, `α` and
+/// `β` shared by all circuits. Only supported on BN254.
+pub struct Groth16Phase1<C> {
+    go_ref_id: i64,
+    marker: PhantomData<C>,
+}
+impl_groth16_object!(Groth16Phase1, 8);
+
+/// State of phase 2 of a Groth16 MPC setup after a contribution.
+///
+/// Keys from [`Groth16Backend`](crate::Groth16Backend) are generated by a single
+/// party, which could forge proofs. A ceremony instead splits the secrets
+/// between participants, and its keys are sound as long as one of them is
+/// honest. Ceremonies are only supported on BN254:
+///
+/// 1. Phase 1 is shared by all circuits. Its sealed output is deserialized
+///    into a [`Groth16Phase1`].
+/// 2. Phase 2 is [initialized](Self::initialize) for a circuit, then each
+///    participant [contributes](Self::contribute) fresh randomness on top of
+///    the previous state and publishes the result.
+/// 3. Anyone can [verify](Self::verify) a contribution, and the keys are
+///    [extracted](Self::extract) from the whole transcript and a random beacon
+///    announced after the last contribution.
+///
+/// States are exchanged with `serialize` and `deserialize`.
+pub struct Groth16Phase2<C> {
+    go_ref_id: i64,
+    marker: PhantomData<C>,
+}
+impl_groth16_object!(Groth16Phase2, 9);
+
+/// Evaluations of a circuit on a [`Groth16Phase1`], computed when phase 2 is
+/// initialized and needed to extract the keys.
+pub struct Groth16Phase2Evaluations<C> {
+    go_ref_id: i64,
+    marker: PhantomData<C>,
 }
+impl_groth16_object!(Groth16Phase2Evaluations, 10);
@@ -23,11 +23,13 @@ pub trait InnerSerializableObject: GoInnerRef {
     fn inner_serialize(&self) -> Result<Vec<u8>> {
         let res = ffi::object::serialize(self.go_inner_ref());
 
-        if res.is_empty() {
-            return Err(Error::SerializeError);
+        let code = i64::from_be_bytes(res[0..8].try_into().unwrap());
+
+        if code != 0 {
+            return Err(Error::from_go_error(code));
         }
 
-        Ok(res)
+        Ok(res[8..].to_vec())
     }
 
     fn inner_deserialize<C>(ty: u64, data: Vec<u8>) -> Result<Self>
@@ -37,11 +39,11 @@ pub trait InnerSerializableObject: GoInnerRef {
     {
         let res = ffi::object::deserialize(ty, C::curve_id(), data);
 
-        if res != 0 {
-            return Err(Error::DeserializeError);
+        match res {
+            0 => Err(Error::DeserializeError),
+            res if res < 0 => Err(Error::from_go_error(res)),
+            res => Ok(Self::from_go_inner_ref(res)),
         }
-
-        Ok(Self::from_go_inner_ref(res))
     }
 
     fn inner_write_to_file(object_id: i64, path: String) -> Result<()> {
@@ -61,10 +63,10 @@ pub trait InnerSerializableObject: GoInnerRef {
     {
         let res = ffi::object::read_from_file(ty, C::curve_id(), path);
 
-        if res == 0 {
-            Ok(Self::from_go_inner_ref(res))
-        } else {
-            Err(Error::from_go_error(res))
+        match res {
+            0 => Err(Error::ReadFromFileError),
+            res if res < 0 => Err(Error::from_go_error(res)),
+            res => Ok(Self::from_go_inner_ref(res)),
         }
     }
 }
@@ -0,0 +1,332 @@
+use halo2_axiom::{
+    halo2curves::bn256::{Bn256, Fr, G1Affine},
+    plonk::{create_proof, keygen_pk, keygen_vk, verify_proof},
+    poly::kzg::{
+        commitment::{KZGCommitmentScheme, ParamsKZG},
+        multiopen::{ProverSHPLONK, VerifierSHPLONK},
+        strategy::SingleStrategy,
+    },
+    transcript::{
+        Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer,
+    },
+};
+use rand_chacha::ChaCha20Rng;
+use rand_core::{OsRng, RngCore, SeedableRng};
+use rsnark_core::{
+    CurveId, MetadataInfo, ProvingSystem,
+    curve::BN254,
+    plonkish::Plonkish,
+    stats::ConstraintStats,
+    types::{CircuitDefinition, PublicWitness, Witness},
+};
+use rsnark_provers_core::Backend;
+
+use crate::{
+    Error, Halo2Params, Result,
+    circuit::{PlonkishCircuit, to_field},
+    types::{Halo2Proof, Halo2ProvingKey, Halo2VerifyingKey},
+};
+
+/// Configuration of the [`Halo2Backend`].
+#[derive(Clone, Default)]
+pub struct Halo2Config {
+    /// KZG parameters used by the setup, see [`Halo2Backend::with_params`].
+    pub params: Option<Halo2Params>,
+    /// Seed of insecure params generated for every circuit, see
+    /// [`Halo2Backend::with_insecure_seed`].
+    pub insecure_seed: Option<u64>,
+}
+
+/// Backend implementation using halo2 with KZG commitments over BN254.
+///
+/// Circuits are compiled to a native [`Plonkish`] gate list, which is also
+/// used to compute the full assignment of a witness. Every gate takes one row
+/// of a standard PLONK gate, except boolean constraints, which are checked by a
+/// lookup into the bit table. Proofs use the SHPLONK multi-opening scheme.
+///
+/// The setup uses the parameters given with [`with_params`](Self::with_params),
+/// downsized to the circuit, and fails with [`Error::ParamsTooSmall`] if the
+/// circuit does not fit, or with [`Error::MissingParams`] if none are configured.
+/// Tests may use [`insecure_test_params`](Self::insecure_test_params) or
+/// [`with_insecure_seed`](Self::with_insecure_seed) instead.
+///
+/// Proving fails with [`Error::SolveError`] if the witness does not satisfy
+/// the circuit.
+#[derive(Clone, Default)]
+pub struct Halo2Backend {
+    params: Option<Halo2Params>,
+    insecure_seed: Option<u64>,
+}
+
+impl Halo2Backend {
+    /// Sets the KZG parameters used by the setup, usually read from a params file.
+    ///
+    /// ```rust,ignore
+    /// let backend = Halo2Backend::default().with_params(Halo2Params::read_file("kzg_bn254_20.srs")?);
+    /// let prover = Prover::from_backend(backend);
+    /// ```
+    pub fn with_params(mut self, params: Halo2Params) -> Self {
+        self.params = Some(params);
+        self
+    }
+
+    /// Generates params with known toxic waste for every circuit.
+    ///
+    /// Anyone can forge proofs for these keys, so they must only be used in
+    /// tests.
+    pub fn insecure_test_params(self) -> Self {
+        self.with_insecure_seed(OsRng.next_u64())
+    }
+
+    /// Generates params whose toxic waste is derived from `seed` for every
+    /// circuit, so keys are reproducible. This replaces any params configured
+    /// before.
+    ///
+    /// Anyone knowing the seed can forge proofs for these keys, so they must
+    /// only be used in tests.
+    pub fn with_insecure_seed(mut self, seed: u64) -> Self {
+        self.insecure_seed = Some(seed);
+        self
+    }
+
+    fn params(&self, k: u32) -> Result<ParamsKZG<Bn256>> {
+        match (self.insecure_seed, &self.params) {
+            (Some(seed), _) => Ok(ParamsKZG::setup(k, ChaCha20Rng::seed_from_u64(seed))),
+            (None, Some(params)) => params.downsized(k),
+            (None, None) => Err(Error::MissingParams),
+        }
+    }
+}
+
+impl Backend for Halo2Backend {
+    type CircuitConstraint = Plonkish;
+    type ProvingKey = Halo2ProvingKey;
+    type VerifyingKey = Halo2VerifyingKey;
+    type Proof = Halo2Proof;
+
+    type Config = Halo2Config;
+
+    type Error = Error;
+
+    fn with_config(config: Halo2Config) -> Result<Self> {
+        Ok(Self {
+            params: config.params,
+            insecure_seed: config.insecure_seed,
+        })
+    }
+
+    fn metadata(&self) -> MetadataInfo {
+        MetadataInfo {
+            field: BN254::field(),
+            curve: BN254::curve_type(),
+            proving_system: ProvingSystem::Plonk,
+        }
+    }
+
+    fn compile(&self, circuit: &CircuitDefinition) -> Result<Self::CircuitConstraint> {
+        Ok(Plonkish::compile(circuit, &BN254::field())?)
+    }
+
+    fn constraint_stats(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+    ) -> Result<Option<ConstraintStats>> {
+        Ok(Some(plonkish.stats()))
+    }
+
+    fn setup(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let params = self.params(PlonkishCircuit::degree(plonkish))?;
+        let circuit = PlonkishCircuit {
+            plonkish: Some(plonkish),
+            assignment: None,
+        };
+
+        let vk = keygen_vk(&params, &circuit)?;
+        let pk = keygen_pk(&params, vk.clone(), &circuit)?;
+
+        Ok((
+            Halo2ProvingKey {
+                params: params.clone(),
+                key: pk,
+            },
+            Halo2VerifyingKey { params, key: vk },
+        ))
+    }
+
+    fn prove(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+        pk: &Self::ProvingKey,
+        witness: &Witness,
+    ) -> Result<Self::Proof> {
+        let assignment = plonkish.solve(witness)?;
+        plonkish.check(&assignment)?;
+
+        let circuit = PlonkishCircuit {
+            plonkish: Some(plonkish),
+            assignment: Some(&assignment),
+        };
+        let instance: Vec<Fr> = witness.public().iter().map(to_field).collect();
+
+        let mut transcript = Blake2bWrite::<_, G1Affine, Challenge255<_>>::init(vec![]);
+        create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+            &pk.params,
+            &pk.key,
+            &[circuit],
+            &[&[&instance]],
+            OsRng,
+            &mut transcript,
+        )?;
+
+        Ok(Halo2Proof(transcript.finalize()))
+    }
+
+    fn verify(
+        &self,
+        vk: &Self::VerifyingKey,
+        proof: &Self::Proof,
+        public_witness: &PublicWitness,
+    ) -> Result<bool> {
+        let instance: Vec<Fr> = public_witness.public.iter().map(to_field).collect();
+
+        let mut transcript = Blake2bRead::<_, G1Affine, Challenge255<_>>::init(proof.0.as_slice());
+        let verified = verify_proof::<_, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+            &vk.params,
+            &vk.key,
+            SingleStrategy::new(&vk.params),
+            &[&[&instance]],
+            &mut transcript,
+        );
+
+        Ok(verified.is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnark_core::{CircuitWitness, Witness, r1cs::SolveError, types};
+    use rsnark_provers_core::{
+        Prover,
+        test_circuits::{RangeCircuit, TestCircuit},
+    };
+
+    #[test]
+    fn test_halo2_with_core_prover() {
+        let prover = Prover::from_backend(Halo2Backend::new().insecure_test_params());
+
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<TestCircuit> {
+            a: 3,
+            b: 4,
+            c: 7, // 3 + 4 = 7
+        };
+
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+
+        let public_witness = circuit_witness.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+
+        let mut wrong_public = types::PublicWitness::new();
+        wrong_public.public_mut().push(8.into());
+        let backend = Halo2Backend::new();
+        assert!(!backend.verify(&vk, &proof, &wrong_public).unwrap());
+
+        let wrong = Witness::<TestCircuit> { a: 3, b: 4, c: 8 };
+        let err = circuit_prover.prove(&pk, &wrong).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::SolveError(SolveError::Unsatisfied { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_internal_wires_and_lookups() {
+        let prover = Prover::from_backend(Halo2Backend::new().insecure_test_params());
+        let circuit_prover = prover.compile_circuit::<RangeCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+        assert!(!vk.inner().cs().lookups().is_empty());
+
+        let circuit_witness = Witness::<RangeCircuit> { x: 6, y: 5, z: 15 };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+        circuit_prover
+            .verify(&vk, &proof, circuit_witness.into_public_witness())
+            .unwrap();
+
+        let out_of_range = Witness::<RangeCircuit> {
+            x: 256,
+            y: 1,
+            z: 128,
+        };
+        assert!(circuit_prover.prove(&pk, &out_of_range).is_err());
+    }
+
+    #[test]
+    fn test_params_file_and_serialize() {
+        let path = std::env::temp_dir().join("rsnark-halo2-test.params");
+        Halo2Params::setup(10).write_file(&path).unwrap();
+        let params = Halo2Params::read_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let backend = Halo2Backend::new().with_params(params);
+        let circuit_prover = Prover::from_backend(backend)
+            .compile_circuit::<TestCircuit>()
+            .unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+        let fingerprint = circuit_prover.fingerprint();
+
+        let witness = Witness::<TestCircuit> { a: 3, b: 4, c: 7 };
+        let pk = Halo2ProvingKey::deserialize(pk.serialize().unwrap()).unwrap();
+        let proof = circuit_prover.prove(&pk, &witness).unwrap();
+
+        let data = vk.serialize_with_fingerprint(fingerprint).unwrap();
+        let vk = Halo2VerifyingKey::deserialize_with_fingerprint(fingerprint, &data).unwrap();
+        let proof = Halo2Proof::deserialize(proof.serialize());
+        circuit_prover
+            .verify(&vk, &proof, witness.into_public_witness())
+            .unwrap();
+
+        let small = Halo2Config {
+            params: Some(Halo2Params::setup(2)),
+            ..Default::default()
+        };
+        let err = Prover::<Halo2Backend>::with_config(small)
+            .unwrap()
+            .compile_circuit::<TestCircuit>()
+            .unwrap()
+            .setup()
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::ParamsTooSmall { supported: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_missing_and_seeded_params() {
+        let err = Prover::<Halo2Backend>::new()
+            .compile_circuit::<TestCircuit>()
+            .unwrap()
+            .setup()
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::MissingParams)
+        ));
+
+        let vk = |seed| {
+            let circuit_prover = Prover::from_backend(Halo2Backend::new().with_insecure_seed(seed))
+                .compile_circuit::<TestCircuit>()
+                .unwrap();
+            circuit_prover.setup().unwrap().1.serialize().unwrap()
+        };
+        assert_eq!(vk(1), vk(1));
+        assert_ne!(vk(1), vk(2));
+    }
+}
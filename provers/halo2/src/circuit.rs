@@ -0,0 +1,184 @@
+use std::sync::LazyLock;
+
+use halo2_axiom::{
+    circuit::{Cell, Layouter, Region, SimpleFloorPlanner, Value},
+    halo2curves::{
+        bn256::Fr,
+        ff::{Field, PrimeField},
+    },
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed},
+    poly::Rotation,
+};
+use num::{BigInt, Integer, Zero};
+use rsnark_core::{
+    CurveId,
+    curve::BN254,
+    plonkish::{COLUMNS, Gate, Plonkish},
+};
+
+static MODULUS: LazyLock<BigInt> = LazyLock::new(BN254::field);
+
+/// Converts `value` into a scalar, reducing it modulo the field.
+pub(crate) fn to_field(value: &BigInt) -> Fr {
+    let value = value.mod_floor(&MODULUS);
+
+    Fr::from_str_vartime(&value.to_string()).expect("reduced modulo the field")
+}
+
+/// Returns the variable a gate constrains to be boolean, if it is a boolean constraint.
+///
+/// Such gates only use one variable `x` on wires `a` and `b`, and reduce to
+/// `qM * (x * x - x) = 0`.
+fn boolean(gate: &Gate, p: &BigInt) -> Option<usize> {
+    let [Some(a), Some(b), c] = gate.wires else {
+        return None;
+    };
+    if a != b || c.is_some_and(|c| c != a) || !gate.q_c.is_zero() || gate.q_m.is_zero() {
+        return None;
+    }
+
+    let linear = &gate.q_l + &gate.q_r + c.map_or(BigInt::zero(), |_| gate.q_o.clone());
+    (linear + &gate.q_m).mod_floor(p).is_zero().then_some(a)
+}
+
+/// Columns of the standard PLONK gate
+///
+/// ```text
+/// qL * a + qR * b + qO * c + qM * a * b + qC + instance = 0
+/// ```
+///
+/// over one instance column, with equality enabled on the wires, and a lookup of `qLookup * a` into the
+/// bit table.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PlonkishConfig {
+    wires: [Column<Advice>; COLUMNS],
+    /// Selectors `[qL, qR, qO, qM, qC]`.
+    selectors: [Column<Fixed>; 5],
+    q_lookup: Column<Fixed>,
+    bits: Column<Fixed>,
+}
+
+/// A [`Plonkish`] gate list as a halo2 circuit, with one row per gate.
+///
+/// Public input gates come first, so that public input `i` sits at row `i` of
+/// the instance column.
+#[derive(Debug, Clone)]
+pub(crate) struct PlonkishCircuit<'a> {
+    pub plonkish: Option<&'a Plonkish>,
+    pub assignment: Option<&'a [BigInt]>,
+}
+
+impl PlonkishCircuit<'_> {
+    /// Returns the base-2 logarithm of the number of rows the circuit needs.
+    pub fn degree(plonkish: &Plonkish) -> u32 {
+        let mut cs = ConstraintSystem::<Fr>::default();
+        PlonkishCircuit::configure(&mut cs);
+
+        let rows = plonkish.gates.len().max(2) + cs.minimum_rows();
+        rows.next_power_of_two().trailing_zeros()
+    }
+}
+
+impl Circuit<Fr> for PlonkishCircuit<'_> {
+    type Config = PlonkishConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+    type Params = ();
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            plonkish: self.plonkish,
+            assignment: None,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        let wires = [(); COLUMNS].map(|_| meta.advice_column());
+        let selectors = [(); 5].map(|_| meta.fixed_column());
+        let q_lookup = meta.fixed_column();
+        let bits = meta.fixed_column();
+        let instance = meta.instance_column();
+
+        for column in wires {
+            meta.enable_equality(column);
+        }
+
+        meta.create_gate("plonk", |meta| {
+            let [a, b, c] = wires.map(|column| meta.query_advice(column, Rotation::cur()));
+            let [q_l, q_r, q_o, q_m, q_c] =
+                selectors.map(|column| meta.query_fixed(column, Rotation::cur()));
+            let instance = meta.query_instance(instance, Rotation::cur());
+
+            vec![q_l * a.clone() + q_r * b.clone() + q_o * c + q_m * a * b + q_c + instance]
+        });
+
+        meta.lookup_any("boolean", |meta| {
+            let q_lookup = meta.query_fixed(q_lookup, Rotation::cur());
+            let a = meta.query_advice(wires[0], Rotation::cur());
+            let bits: Expression<Fr> = meta.query_fixed(bits, Rotation::cur());
+
+            vec![(q_lookup * a, bits)]
+        });
+
+        PlonkishConfig {
+            wires,
+            selectors,
+            q_lookup,
+            bits,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        let plonkish = self.plonkish.ok_or(Error::Synthesis)?;
+        let p = plonkish.field();
+
+        layouter.assign_region(
+            || "gates",
+            |mut region| {
+                region.assign_fixed(config.bits, 0, Fr::ZERO);
+                region.assign_fixed(config.bits, 1, Fr::ONE);
+
+                let mut cells: Vec<Option<Cell>> = vec![None; plonkish.num_variables()];
+                let mut assign = |region: &mut Region<'_, Fr>, column, row, var: usize| {
+                    let value = match self.assignment {
+                        Some(assignment) => Value::known(to_field(&assignment[var])),
+                        None => Value::unknown(),
+                    };
+                    let cell = region.assign_advice(column, row, value).cell();
+
+                    // Copy constraints chain every wire of a variable to its first wire
+                    match cells[var] {
+                        Some(first) => region.constrain_equal(first, cell),
+                        None => cells[var] = Some(cell),
+                    }
+                };
+
+                for (row, gate) in plonkish.gates.iter().enumerate() {
+                    if let Some(var) = boolean(gate, p) {
+                        region.assign_fixed(config.q_lookup, row, Fr::ONE);
+                        assign(&mut region, config.wires[0], row, var);
+                        continue;
+                    }
+
+                    let selectors = [&gate.q_l, &gate.q_r, &gate.q_o, &gate.q_m, &gate.q_c];
+                    for (column, selector) in config.selectors.into_iter().zip(selectors) {
+                        if !selector.is_zero() {
+                            region.assign_fixed(column, row, to_field(selector));
+                        }
+                    }
+
+                    for (column, wire) in config.wires.into_iter().zip(gate.wires) {
+                        if let Some(var) = wire {
+                            assign(&mut region, column, row, var);
+                        }
+                    }
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use rsnark_core::{
+    fingerprint::FingerprintError,
+    r1cs::{CompileError, SolveError},
+};
+
+/// Error types for halo2 backend operations.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SolveError(#[from] SolveError),
+
+    #[error(transparent)]
+    Halo2Error(#[from] halo2_axiom::plonk::Error),
+
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    #[error("no KZG params configured, see Halo2Backend::with_params")]
+    MissingParams,
+
+    #[error("circuit needs 2^{needed} rows, but the params only support 2^{supported}")]
+    ParamsTooSmall { needed: u32, supported: u32 },
+
+    #[error(transparent)]
+    FingerprintError(#[from] FingerprintError),
+}
+
+/// Convenience type alias for Results with halo2 Error.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,35 @@
+//! # rsnark-provers-halo2
+//!
+//! Backend implementation based on the KZG flavour of
+//! [halo2](https://github.com/axiom-crypto/halo2).
+//!
+//! This crate proves circuits over BN254 with halo2's PLONK prover and KZG
+//! commitments, whose proofs can be verified on-chain. Circuit definitions are
+//! lowered with the native [`Plonkish`](rsnark_core::plonkish::Plonkish)
+//! arithmetization of `rsnark-core` onto a standard PLONK gate, and boolean
+//! constraints become lookups into a bit table.
+//!
+//! ## Key Components
+//!
+//! - [`Halo2Backend`]: Backend implementation using halo2's prover
+//! - [`Halo2Config`]: Options of the backend, such as its KZG parameters
+//! - [`Halo2Params`]: KZG parameters, generated or read from a params file
+//! - [`Error`]: Error types for compilation, solving and halo2 operations
+//! - [`types`]: Keys and proofs with their serialization
+//!
+//! ## Supported Curves
+//!
+//! - BN254
+
+mod circuit;
+
+mod params;
+pub use params::*;
+
+pub mod types;
+
+mod error;
+pub use error::*;
+
+mod backend;
+pub use backend::*;
@@ -0,0 +1,69 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use halo2_axiom::{
+    halo2curves::bn256::Bn256,
+    poly::{commitment::Params, kzg::commitment::ParamsKZG},
+};
+use rand_core::OsRng;
+
+use crate::{Error, Result};
+
+/// KZG parameters, the structured reference string of the halo2 backend.
+///
+/// Parameters support circuits of up to `2^k` rows. They are usually read from
+/// a params file produced by a ceremony, in the format used by halo2 tools.
+#[derive(Debug, Clone)]
+pub struct Halo2Params(pub(crate) ParamsKZG<Bn256>);
+
+impl Halo2Params {
+    /// Generates parameters for up to `2^k` rows from fresh randomness.
+    ///
+    /// Anyone who learns the randomness can forge proofs, so parameters
+    /// generated this way should only be trusted by the party who made them.
+    pub fn setup(k: u32) -> Self {
+        Self(ParamsKZG::setup(k, OsRng))
+    }
+
+    /// Returns the base-2 logarithm of the number of rows supported.
+    pub fn k(&self) -> u32 {
+        self.0.k()
+    }
+
+    pub fn read(reader: &mut impl Read) -> Result<Self> {
+        Ok(Self(ParamsKZG::read(reader)?))
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> Result<()> {
+        Ok(self.0.write(writer)?)
+    }
+
+    /// Reads parameters from a params file.
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read(&mut BufReader::new(File::open(path)?))
+    }
+
+    /// Writes parameters to a params file.
+    pub fn write_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        Ok(writer.flush()?)
+    }
+
+    /// Returns these parameters, downsized to exactly `2^k` rows.
+    pub(crate) fn downsized(&self, k: u32) -> Result<ParamsKZG<Bn256>> {
+        if self.k() < k {
+            return Err(Error::ParamsTooSmall {
+                needed: k,
+                supported: self.k(),
+            });
+        }
+
+        let mut params = self.0.clone();
+        params.downsize(k);
+        Ok(params)
+    }
+}
@@ -0,0 +1,130 @@
+//! Keys and proofs of the halo2 backend.
+//!
+//! Keys carry the KZG parameters they were generated with, downsized to the
+//! circuit. They serialize as the parameters followed by the halo2 key, with
+//! points in uncompressed form.
+
+use halo2_axiom::{
+    SerdeFormat,
+    halo2curves::bn256::{Bn256, G1Affine},
+    plonk::{ProvingKey, VerifyingKey},
+    poly::{commitment::Params, kzg::commitment::ParamsKZG},
+};
+use std::convert::Infallible;
+
+use rsnark_core::fingerprint::{self, Fingerprint};
+use rsnark_provers_core::Artifact;
+
+use crate::{Error, Result, circuit::PlonkishCircuit};
+
+const FORMAT: SerdeFormat = SerdeFormat::RawBytes;
+
+macro_rules! impl_key {
+    ($name:ident, $key:ident) => {
+        impl $name {
+            pub fn serialize(&self) -> Result<Vec<u8>> {
+                let mut data = Vec::new();
+                self.params.write(&mut data)?;
+                self.key.write(&mut data, FORMAT)?;
+                Ok(data)
+            }
+
+            pub fn deserialize(data: Vec<u8>) -> Result<Self> {
+                let mut reader = data.as_slice();
+                let params = ParamsKZG::read(&mut reader)?;
+                let key = $key::read::<_, PlonkishCircuit<'static>>(&mut reader, FORMAT, ())?;
+                ensure_consumed(reader)?;
+
+                Ok(Self { params, key })
+            }
+
+            /// Serializes this key, sealed with the fingerprint of its circuit.
+            pub fn serialize_with_fingerprint(&self, fingerprint: &Fingerprint) -> Result<Vec<u8>> {
+                Ok(fingerprint::seal(fingerprint, &self.serialize()?))
+            }
+
+            /// Deserializes a key sealed by `serialize_with_fingerprint`.
+            ///
+            /// Fails with [`Error::FingerprintError`](crate::Error::FingerprintError)
+            /// if it was sealed for another circuit.
+            pub fn deserialize_with_fingerprint(
+                fingerprint: &Fingerprint,
+                data: &[u8],
+            ) -> Result<Self> {
+                Self::deserialize(fingerprint::open(fingerprint, data)?.to_vec())
+            }
+        }
+
+        impl Artifact for $name {
+            type Error = Error;
+
+            fn to_bytes(&self) -> Result<Vec<u8>> {
+                self.serialize()
+            }
+
+            fn from_bytes(data: &[u8]) -> Result<Self> {
+                Self::deserialize(data.to_vec())
+            }
+        }
+    };
+}
+
+/// Proving key of a circuit, with the KZG parameters of its size.
+#[derive(Debug)]
+pub struct Halo2ProvingKey {
+    pub(crate) params: ParamsKZG<Bn256>,
+    pub(crate) key: ProvingKey<G1Affine>,
+}
+impl_key!(Halo2ProvingKey, ProvingKey);
+
+/// Verifying key of a circuit, with the KZG parameters of its size.
+#[derive(Debug)]
+pub struct Halo2VerifyingKey {
+    pub(crate) params: ParamsKZG<Bn256>,
+    pub(crate) key: VerifyingKey<G1Affine>,
+}
+impl_key!(Halo2VerifyingKey, VerifyingKey);
+
+impl Halo2VerifyingKey {
+    /// Returns the underlying halo2 verifying key.
+    pub fn inner(&self) -> &VerifyingKey<G1Affine> {
+        &self.key
+    }
+}
+
+/// A proof, as the bytes of its Blake2b transcript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Halo2Proof(pub(crate) Vec<u8>);
+
+impl Halo2Proof {
+    pub fn serialize(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    pub fn deserialize(data: Vec<u8>) -> Self {
+        Self(data)
+    }
+}
+
+impl Artifact for Halo2Proof {
+    type Error = Infallible;
+
+    fn to_bytes(&self) -> std::result::Result<Vec<u8>, Infallible> {
+        Ok(self.serialize())
+    }
+
+    fn from_bytes(data: &[u8]) -> std::result::Result<Self, Infallible> {
+        Ok(Self::deserialize(data.to_vec()))
+    }
+}
+
+fn ensure_consumed(reader: &[u8]) -> std::io::Result<()> {
+    if reader.is_empty() {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "trailing bytes after key",
+        ))
+    }
+}
@@ -11,7 +11,7 @@
 //!
 //! - **Fast execution**: No complex cryptographic operations
 //! - **Deterministic behavior**: Proofs are based on SHA3 hashing
-//! - **Simple setup**: No key generation required
+//! - **Simple setup**: Keys are hashes of the circuit and a seed
 //! - **Testing friendly**: Ideal for unit tests and development
 //!
 //! ## Limitations
@@ -35,30 +35,55 @@ use sha3::{Digest, Sha3_256};
 #[derive(Debug, thiserror::Error)]
 pub enum Error {}
 
+/// Configuration of the [`MockProverBackend`].
+#[derive(Debug, Clone, Default)]
+pub struct MockConfig {
+    /// Seed the keys are derived from, see
+    /// [`MockProverBackend::with_insecure_seed`].
+    pub insecure_seed: u64,
+}
+
 /// Mock backend implementation for testing and development.
 ///
 /// This backend provides a simple, non-cryptographic implementation of the
 /// [`Backend`] trait that can be used for testing circuit compilation and
 /// proof workflows. Instead of generating real zero-knowledge proofs, it
-/// creates deterministic "proofs" based on SHA3 hashing of public inputs.
+/// creates deterministic "proofs" based on SHA3 hashing of the key and the
+/// public inputs.
 ///
 /// # Security Warning
 ///
 /// This implementation provides **no cryptographic security** and should
 /// never be used in production. It's designed only for testing and development.
-#[derive(Clone)]
-pub struct MockProverBackend;
+#[derive(Clone, Default)]
+pub struct MockProverBackend {
+    seed: u64,
+}
+
+impl MockProverBackend {
+    /// Derives the keys from `seed`, like the seeded setups of real backends.
+    ///
+    /// Keys are always deterministic; without a seed, they use seed 0.
+    pub fn with_insecure_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
 
 impl Backend for MockProverBackend {
     type CircuitConstraint = CircuitDefinition;
-    type ProvingKey = ();
-    type VerifyingKey = ();
+    type ProvingKey = BigInt;
+    type VerifyingKey = BigInt;
     type Proof = BigInt;
 
+    type Config = MockConfig;
+
     type Error = Error;
 
-    fn new() -> Self {
-        Self
+    fn with_config(config: MockConfig) -> Result<Self, Self::Error> {
+        Ok(Self {
+            seed: config.insecure_seed,
+        })
     }
 
     fn metadata(&self) -> MetadataInfo {
@@ -77,43 +102,49 @@ impl Backend for MockProverBackend {
         Ok(circuit.clone())
     }
 
-    /// Returns unit values as mock proving and verifying keys.
+    /// Returns the hash of the seed and the circuit as both keys.
     ///
-    /// The mock backend doesn't require actual key generation, so it
-    /// returns empty unit values for both keys.
+    /// The mock backend doesn't perform a real setup, but keys still identify
+    /// the circuit, so proofs of one circuit do not verify against another.
     fn setup(
         &self,
-        _cs: &Self::CircuitConstraint,
+        cs: &Self::CircuitConstraint,
     ) -> Result<(Self::ProvingKey, Self::VerifyingKey), Self::Error> {
-        Ok(((), ()))
+        let mut hasher = Sha3_256::new();
+        hasher.update(self.seed.to_be_bytes());
+        hasher.update(serde_json::to_vec(cs).expect("circuit definitions serialize to JSON"));
+
+        let key = BigInt::from_be_bytes(&hasher.finalize());
+        Ok((key.clone(), key))
     }
 
     /// Generates a mock proof by hashing the public witness.
     ///
-    /// The mock proof is simply the SHA3-256 hash of the public witness values.
-    /// This provides deterministic behavior for testing while avoiding complex
-    /// cryptographic operations.
+    /// The mock proof is simply the SHA3-256 hash of the key and the public
+    /// witness values. This provides deterministic behavior for testing while
+    /// avoiding complex cryptographic operations.
     fn prove(
         &self,
         _cs: &Self::CircuitConstraint,
-        _pk: &Self::ProvingKey,
+        pk: &Self::ProvingKey,
         witness: &Witness,
     ) -> Result<Self::Proof, Self::Error> {
-        let res_hash = hash_public_witness(witness.public());
+        let res_hash = hash_public_witness(pk, witness.public());
         Ok(res_hash)
     }
 
     /// Verifies a mock proof by comparing hashes.
     ///
     /// Verification simply checks if the proof hash matches the hash of the
-    /// provided public witness. Returns `true` if they match, `false` otherwise.
+    /// key and the provided public witness. Returns `true` if they match, `false`
+    /// otherwise.
     fn verify(
         &self,
-        _vk: &Self::VerifyingKey,
+        vk: &Self::VerifyingKey,
         proof: &Self::Proof,
         public_witness: &PublicWitness,
     ) -> Result<bool, Self::Error> {
-        let res_hash = hash_public_witness(&public_witness.public);
+        let res_hash = hash_public_witness(vk, &public_witness.public);
         if res_hash == *proof {
             Ok(true)
         } else {
@@ -122,9 +153,11 @@ impl Backend for MockProverBackend {
     }
 }
 
-fn hash_public_witness(public_witness: &[BigInt]) -> BigInt {
+fn hash_public_witness(key: &BigInt, public_witness: &[BigInt]) -> BigInt {
     let mut hasher = Sha3_256::new();
 
+    hasher.update(key.to_be_bytes());
+
     for value in public_witness {
         let bytes = value.to_be_bytes();
         hasher.update(bytes);
@@ -133,3 +166,45 @@ fn hash_public_witness(public_witness: &[BigInt]) -> BigInt {
     let res_bytes = hasher.finalize();
     BigInt::from_be_bytes(&res_bytes)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnark_core::{Witness, types};
+    use rsnark_provers_core::{Prover, test_circuits::TestCircuit};
+
+    fn setup(backend: MockProverBackend) -> (BigInt, BigInt) {
+        Prover::from_backend(backend)
+            .compile_circuit::<TestCircuit>()
+            .unwrap()
+            .setup()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_seeded_setup() {
+        let seeded = |seed| setup(MockProverBackend::new().with_insecure_seed(seed));
+
+        assert_eq!(seeded(1), seeded(1));
+        assert_ne!(seeded(1), seeded(2));
+        assert_eq!(seeded(0), setup(MockProverBackend::new()));
+    }
+
+    #[test]
+    fn test_proof_is_bound_to_seed() {
+        let prover = Prover::<MockProverBackend>::new();
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+        let (pk, vk) = setup(MockProverBackend::new().with_insecure_seed(1));
+        let (_, other_vk) = setup(MockProverBackend::new().with_insecure_seed(2));
+
+        let proof = circuit_prover
+            .prove(&pk, &Witness::<TestCircuit> { a: 3, b: 4, c: 7 })
+            .unwrap();
+
+        let mut public = types::PublicWitness::new();
+        public.public_mut().push(7.into());
+        let backend = MockProverBackend::new();
+        assert!(backend.verify(&vk, &proof, &public).unwrap());
+        assert!(!backend.verify(&other_vk, &proof, &public).unwrap());
+    }
+}
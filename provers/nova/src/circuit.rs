@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use ff::PrimeField;
+use nova_snark::{
+    frontend::{ConstraintSystem, LinearCombination, SynthesisError, Variable, num::AllocatedNum},
+    provider::{Bn256EngineIPA, GrumpkinEngine},
+    traits::{Engine, circuit::StepCircuit},
+};
+use num::{BigInt, Integer};
+use rsnark_core::r1cs::{self, ONE, R1cs};
+
+/// Engine of the primary curve, whose scalar field is the circuit field.
+pub(crate) type E1 = Bn256EngineIPA;
+/// Engine of the secondary curve, closing the cycle.
+pub(crate) type E2 = GrumpkinEngine;
+pub(crate) type Scalar = <E1 as Engine>::Scalar;
+
+/// Converts `value` into a scalar, reducing it modulo `p`.
+pub(crate) fn to_field(value: &BigInt, p: &BigInt) -> Scalar {
+    let value = value.mod_floor(p);
+
+    Scalar::from_str_vartime(&value.to_string()).expect("reduced modulo the field")
+}
+
+/// A compiled step circuit, with the linear combinations of its outputs.
+#[derive(Debug)]
+pub(crate) struct StepR1cs {
+    pub r1cs: R1cs,
+    pub z_out: Vec<r1cs::LinearCombination>,
+}
+
+impl StepR1cs {
+    pub fn arity(&self) -> usize {
+        self.z_out.len()
+    }
+}
+
+/// One step of the computation as a Nova step circuit.
+///
+/// The assignment is computed natively by the prover, from the state the step
+/// is applied to. Without it, only the shape of the circuit can be synthesized.
+#[derive(Debug, Clone)]
+pub(crate) struct NovaStep {
+    pub step: Arc<StepR1cs>,
+    pub assignment: Option<Arc<Vec<BigInt>>>,
+}
+
+impl NovaStep {
+    fn evaluate(&self, lc: &r1cs::LinearCombination) -> Result<Scalar, SynthesisError> {
+        let p = &self.step.r1cs.field;
+        match &self.assignment {
+            Some(assignment) => Ok(to_field(&lc.evaluate(assignment, p), p)),
+            None => Err(SynthesisError::AssignmentMissing),
+        }
+    }
+}
+
+fn linear_combination(
+    lc: &r1cs::LinearCombination,
+    wires: &[Variable],
+    p: &BigInt,
+) -> LinearCombination<Scalar> {
+    lc.0.iter()
+        .fold(LinearCombination::zero(), |acc, (wire, coeff)| {
+            acc + (to_field(coeff, p), wires[*wire])
+        })
+}
+
+impl StepCircuit<Scalar> for NovaStep {
+    fn arity(&self) -> usize {
+        self.step.arity()
+    }
+
+    fn synthesize<CS: ConstraintSystem<Scalar>>(
+        &self,
+        cs: &mut CS,
+        z: &[AllocatedNum<Scalar>],
+    ) -> Result<Vec<AllocatedNum<Scalar>>, SynthesisError> {
+        let r1cs = &self.step.r1cs;
+        let p = &r1cs.field;
+
+        // The public inputs of the step are `z_in`, so they reuse the state variables
+        let mut wires = Vec::with_capacity(r1cs.num_wires());
+        wires.push(CS::one());
+        wires.extend(z.iter().map(|z| z.get_variable()));
+        for wire in wires.len()..r1cs.num_wires() {
+            let value = || match &self.assignment {
+                Some(assignment) => Ok(to_field(&assignment[wire], p)),
+                None => Err(SynthesisError::AssignmentMissing),
+            };
+            wires.push(cs.alloc(|| format!("wire {wire}"), value)?);
+        }
+
+        for (idx, constraint) in r1cs.constraints.iter().enumerate() {
+            cs.enforce(
+                || format!("constraint {idx}"),
+                |lc| lc + &linear_combination(&constraint.a, &wires, p),
+                |lc| lc + &linear_combination(&constraint.b, &wires, p),
+                |lc| lc + &linear_combination(&constraint.c, &wires, p),
+            );
+        }
+
+        self.step
+            .z_out
+            .iter()
+            .enumerate()
+            .map(|(idx, output)| {
+                let z_out = AllocatedNum::alloc(cs.namespace(|| format!("z_out {idx}")), || {
+                    self.evaluate(output)
+                })?;
+                cs.enforce(
+                    || format!("z_out {idx} is the output"),
+                    |lc| lc + &linear_combination(output, &wires, p),
+                    |lc| lc + wires[ONE],
+                    |lc| lc + z_out.get_variable(),
+                );
+
+                Ok(z_out)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,29 @@
+use rsnark_core::r1cs::{CompileError, SolveError};
+
+/// Error types for Nova prover operations.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SolveError(#[from] SolveError),
+
+    #[error(transparent)]
+    NovaError(#[from] nova_snark::errors::NovaError),
+
+    #[error(transparent)]
+    EncodeError(#[from] bincode::error::EncodeError),
+
+    #[error(transparent)]
+    DecodeError(#[from] bincode::error::DecodeError),
+
+    #[error("expected a state of {expected} elements, got {found}")]
+    WrongStateLength { expected: usize, found: usize },
+
+    #[error("cannot compress a computation without steps")]
+    NoSteps,
+}
+
+/// Convenience type alias for Results with Nova Error.
+pub type Result<T> = std::result::Result<T, Error>;
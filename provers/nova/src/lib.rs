@@ -0,0 +1,38 @@
+//! # rsnark-provers-nova
+//!
+//! Folding prover for incremental computations, based on
+//! [Nova](https://github.com/microsoft/Nova).
+//!
+//! A computation is split into steps of the same [`StepCircuit`], each
+//! mapping a state `z_in` to the next state `z_out`. Nova folds every step into
+//! a single running instance, so proving a step costs the same whatever the
+//! number of steps before it, and compresses the result into a succinct proof
+//! of `z_n = F(...F(F(z_0)))`.
+//!
+//! Step circuits are lowered with the native [`R1cs`](rsnark_core::r1cs::R1cs)
+//! arithmetization of `rsnark-core` and folded over the BN254/Grumpkin cycle
+//! of curves, with IPA-based Spartan for the compressed proof, which needs no
+//! trusted setup.
+//!
+//! ## Key Components
+//!
+//! - [`NovaProver`]: Compiles a step circuit, folds steps and verifies proofs
+//! - [`Folding`]: A computation being folded one step at a time
+//! - [`Error`]: Error types for compilation, solving and Nova operations
+//! - [`types`]: Keys and proofs with their serialization
+//!
+//! ## Supported Curves
+//!
+//! - BN254, with Grumpkin as the secondary curve
+//!
+//! [`StepCircuit`]: rsnark_core::StepCircuit
+
+mod circuit;
+
+pub mod types;
+
+mod error;
+pub use error::*;
+
+mod prover;
+pub use prover::*;
@@ -0,0 +1,312 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use nova_snark::{
+    nova::{PublicParams, RecursiveSNARK},
+    traits::snark::RelaxedR1CSSNARKTrait,
+};
+use num::BigInt;
+use rsnark_core::{
+    CircuitElement, CircuitElementInner, CurveId, MetadataInfo, ProvingSystem, StepCircuit,
+    StepDefinition, Witness, curve::BN254, r1cs::R1cs, stats::ConstraintStats,
+};
+
+use crate::{
+    Error, Result,
+    circuit::{E1, E2, NovaStep, Scalar, StepR1cs, to_field},
+    types::{Compressed, NovaProof, NovaProvingKey, NovaVerifyingKey, S1, S2},
+};
+
+/// Folding prover for the step circuit `C`.
+///
+/// Every step is solved natively before it is folded, so proving fails with
+/// [`Error::SolveError`] if the inputs of a step do not satisfy the circuit.
+///
+/// ```rust,ignore
+/// let prover = NovaProver::<MyStep>::new()?;
+/// let (pk, vk) = prover.setup()?;
+///
+/// let mut folding = prover.fold(&pk, &z0)?;
+/// for block in blocks {
+///     folding.step(&Witness::<MyStep> { block })?;
+/// }
+/// let proof = folding.compress()?;
+///
+/// assert!(prover.verify(&vk, &proof, &z0)?);
+/// ```
+pub struct NovaProver<C> {
+    step: Arc<StepR1cs>,
+    marker: PhantomData<C>,
+}
+
+impl<C> NovaProver<C>
+where
+    C: CircuitElement,
+    CircuitElementInner<C>: StepCircuit,
+{
+    /// Compiles the step circuit of `C`.
+    pub fn new() -> Result<Self> {
+        let step = StepDefinition::new::<C>(Self::metadata());
+        let (r1cs, z_out) =
+            R1cs::compile_with_outputs(&step.definition, &step.z_out, &BN254::field())?;
+
+        Ok(Self {
+            step: Arc::new(StepR1cs { r1cs, z_out }),
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the metadata step circuits are built with.
+    pub fn metadata() -> MetadataInfo {
+        MetadataInfo {
+            field: BN254::field(),
+            curve: BN254::curve_type(),
+            proving_system: ProvingSystem::Nova,
+        }
+    }
+
+    /// Returns the size of the constraint system of one step, without the
+    /// verifier circuit of the folding scheme.
+    pub fn constraint_stats(&self) -> ConstraintStats {
+        self.step.r1cs.stats()
+    }
+
+    /// Generates the public parameters of the folding scheme, and the keys of
+    /// the compressed proofs.
+    pub fn setup(&self) -> Result<(NovaProvingKey, NovaVerifyingKey)> {
+        let shape = NovaStep {
+            step: self.step.clone(),
+            assignment: None,
+        };
+        let params = PublicParams::setup(&shape, &*S1::ck_floor(), &*S2::ck_floor())?;
+        let (key, vk) = Compressed::setup(&params)?;
+
+        Ok((NovaProvingKey { params, key }, NovaVerifyingKey(vk)))
+    }
+
+    /// Starts folding a computation from the initial state `z0`.
+    pub fn fold<'a>(&'a self, pk: &'a NovaProvingKey, z0: &[BigInt]) -> Result<Folding<'a, C>> {
+        self.check_state(z0)?;
+
+        Ok(Folding {
+            prover: self,
+            pk,
+            z0: z0
+                .iter()
+                .map(|z| to_field(z, &self.step.r1cs.field))
+                .collect(),
+            z: z0.to_vec(),
+            snark: None,
+        })
+    }
+
+    /// Folds one step for each of `steps` from the initial state `z0`, and
+    /// compresses the result.
+    pub fn prove(
+        &self,
+        pk: &NovaProvingKey,
+        z0: &[BigInt],
+        steps: &[Witness<C>],
+    ) -> Result<NovaProof> {
+        let mut folding = self.fold(pk, z0)?;
+        for step in steps {
+            folding.step(step)?;
+        }
+
+        folding.compress()
+    }
+
+    /// Verifies that `proof` reaches its final state from the initial state `z0`.
+    pub fn verify(&self, vk: &NovaVerifyingKey, proof: &NovaProof, z0: &[BigInt]) -> Result<bool> {
+        self.check_state(z0)?;
+
+        let z0 = z0
+            .iter()
+            .map(|z| to_field(z, &self.step.r1cs.field))
+            .collect::<Vec<_>>();
+        let z_out = proof
+            .z_out
+            .iter()
+            .map(|z| to_field(z, &self.step.r1cs.field));
+
+        match proof.snark.verify(&vk.0, proof.num_steps, &z0) {
+            Ok(zn) => Ok(zn.into_iter().eq(z_out)),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn check_state(&self, z: &[BigInt]) -> Result<()> {
+        if z.len() != self.step.arity() {
+            return Err(Error::WrongStateLength {
+                expected: self.step.arity(),
+                found: z.len(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// A computation being folded, see [`NovaProver::fold`].
+pub struct Folding<'a, C> {
+    prover: &'a NovaProver<C>,
+    pk: &'a NovaProvingKey,
+    z0: Vec<Scalar>,
+    z: Vec<BigInt>,
+    snark: Option<RecursiveSNARK<E1, E2, NovaStep>>,
+}
+
+impl<C> Folding<'_, C>
+where
+    C: CircuitElement,
+    CircuitElementInner<C>: StepCircuit,
+{
+    /// Applies one step with the given inputs to the current state, and folds it.
+    pub fn step(&mut self, inputs: &Witness<C>) -> Result<()> {
+        let step = &self.prover.step;
+
+        let witness = StepDefinition::witness::<C>(&self.z, inputs);
+        let assignment = step.r1cs.solve(&witness)?;
+        step.r1cs.check(&assignment)?;
+
+        let z_out = step
+            .z_out
+            .iter()
+            .map(|lc| lc.evaluate(&assignment, &step.r1cs.field))
+            .collect();
+        let circuit = NovaStep {
+            step: step.clone(),
+            assignment: Some(Arc::new(assignment)),
+        };
+
+        let params = &self.pk.params;
+        let snark = match &mut self.snark {
+            Some(snark) => snark,
+            // The first step is proved when the recursive SNARK is created
+            None => self
+                .snark
+                .insert(RecursiveSNARK::new(params, &circuit, &self.z0)?),
+        };
+        snark.prove_step(params, &circuit)?;
+
+        self.z = z_out;
+        Ok(())
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> &[BigInt] {
+        &self.z
+    }
+
+    /// Returns the number of steps folded so far.
+    pub fn num_steps(&self) -> usize {
+        self.snark.as_ref().map_or(0, |snark| snark.num_steps())
+    }
+
+    /// Compresses the folded steps into a succinct proof.
+    ///
+    /// Fails with [`Error::NoSteps`] if no step was folded.
+    pub fn compress(&self) -> Result<NovaProof> {
+        let snark = self.snark.as_ref().ok_or(Error::NoSteps)?;
+        let compressed = Compressed::prove(&self.pk.params, &self.pk.key, snark)?;
+
+        Ok(NovaProof {
+            snark: compressed,
+            num_steps: snark.num_steps(),
+            z_out: self.z.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnark_core::{API, circuit, r1cs::SolveError, types::VariableType};
+
+    #[circuit]
+    pub struct Fibonacci {
+        increment: u32,
+    }
+
+    impl StepCircuit for Fibonacci {
+        const ARITY: usize = 2;
+
+        fn step(&self, api: &mut impl API, z_in: &[VariableType]) -> Vec<VariableType> {
+            api.variable_to_binary(&self.increment, 8);
+            let sum = api.add(&z_in[0], &z_in[1]);
+            let squared = api.mul(&sum, &sum);
+            vec![z_in[1].clone(), api.add(&squared, &self.increment)]
+        }
+    }
+
+    fn state(z: &[i64]) -> Vec<BigInt> {
+        z.iter().map(|&z| z.into()).collect()
+    }
+
+    #[test]
+    fn test_fold_and_verify() {
+        let prover = NovaProver::<Fibonacci>::new().unwrap();
+        let (pk, vk) = prover.setup().unwrap();
+
+        let mut folding = prover.fold(&pk, &state(&[1, 1])).unwrap();
+        for increment in [0, 1, 2] {
+            folding.step(&Witness::<Fibonacci> { increment }).unwrap();
+        }
+        assert_eq!(folding.num_steps(), 3);
+        // (1, 1) -> (1, 4) -> (4, 26) -> (26, 902)
+        assert_eq!(folding.state(), state(&[26, 902]));
+
+        let proof = folding.compress().unwrap();
+        assert_eq!(proof.z_out(), state(&[26, 902]));
+        assert!(prover.verify(&vk, &proof, &state(&[1, 1])).unwrap());
+        assert!(!prover.verify(&vk, &proof, &state(&[1, 2])).unwrap());
+
+        let mut forged = prover
+            .prove(
+                &pk,
+                &state(&[1, 1]),
+                &[Witness::<Fibonacci> { increment: 0 }],
+            )
+            .unwrap();
+        forged.z_out = state(&[1, 5]);
+        assert!(!prover.verify(&vk, &forged, &state(&[1, 1])).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_steps() {
+        let prover = NovaProver::<Fibonacci>::new().unwrap();
+        assert_eq!(prover.constraint_stats().public_variables, 3);
+        let (pk, _) = prover.setup().unwrap();
+
+        let mut folding = prover.fold(&pk, &state(&[1, 1])).unwrap();
+        assert!(matches!(folding.compress(), Err(Error::NoSteps)));
+
+        let out_of_range = Witness::<Fibonacci> { increment: 256 };
+        assert!(matches!(
+            folding.step(&out_of_range),
+            Err(Error::SolveError(SolveError::Unsatisfied { .. }))
+        ));
+        assert_eq!(folding.state(), state(&[1, 1]));
+
+        assert!(matches!(
+            prover.fold(&pk, &state(&[1])),
+            Err(Error::WrongStateLength {
+                expected: 2,
+                found: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_serialize() {
+        let prover = NovaProver::<Fibonacci>::new().unwrap();
+        let (pk, vk) = prover.setup().unwrap();
+        let pk = NovaProvingKey::deserialize(&pk.serialize().unwrap()).unwrap();
+        let vk = NovaVerifyingKey::deserialize(&vk.serialize().unwrap()).unwrap();
+
+        let steps = [3, 4].map(|increment| Witness::<Fibonacci> { increment });
+        let proof = prover.prove(&pk, &state(&[0, 1]), &steps).unwrap();
+        let proof = NovaProof::deserialize(&proof.serialize().unwrap()).unwrap();
+        assert_eq!(proof.num_steps(), 2);
+        assert!(prover.verify(&vk, &proof, &state(&[0, 1])).unwrap());
+    }
+}
@@ -0,0 +1,118 @@
+//! Keys and proofs of the Nova prover.
+//!
+//! All of them serialize with bincode, in the legacy configuration used by Nova.
+
+use std::fmt;
+
+use nova_snark::{
+    nova::{CompressedSNARK, ProverKey, PublicParams, VerifierKey},
+    provider::ipa_pc::EvaluationEngine,
+    spartan::snark::RelaxedR1CSSNARK,
+};
+use num::BigInt;
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    Result,
+    circuit::{E1, E2, NovaStep},
+};
+
+pub(crate) type S1 = RelaxedR1CSSNARK<E1, EvaluationEngine<E1>>;
+pub(crate) type S2 = RelaxedR1CSSNARK<E2, EvaluationEngine<E2>>;
+
+pub(crate) type Params = PublicParams<E1, E2, NovaStep>;
+pub(crate) type Compressed = CompressedSNARK<E1, E2, NovaStep, S1, S2>;
+
+const CONFIG: bincode::config::Configuration<
+    bincode::config::LittleEndian,
+    bincode::config::Fixint,
+> = bincode::config::legacy();
+
+fn encode(value: &impl Serialize) -> Result<Vec<u8>> {
+    Ok(bincode::serde::encode_to_vec(value, CONFIG)?)
+}
+
+fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T> {
+    Ok(bincode::serde::decode_from_slice(data, CONFIG)?.0)
+}
+
+/// Proving key, with the public parameters needed to fold steps.
+pub struct NovaProvingKey {
+    pub(crate) params: Params,
+    pub(crate) key: ProverKey<E1, E2, NovaStep, S1, S2>,
+}
+
+impl NovaProvingKey {
+    /// Returns the number of constraints of a step, on the primary and secondary curve.
+    ///
+    /// This includes the verifier circuit of the folding scheme.
+    pub fn num_constraints(&self) -> (usize, usize) {
+        self.params.num_constraints()
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        encode(&(&self.params, &self.key))
+    }
+
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        let (params, key) = decode(data)?;
+        Ok(Self { params, key })
+    }
+}
+
+/// Verifying key of the compressed proofs.
+pub struct NovaVerifyingKey(pub(crate) VerifierKey<E1, E2, NovaStep, S1, S2>);
+
+impl NovaVerifyingKey {
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        encode(&self.0)
+    }
+
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        Ok(Self(decode(data)?))
+    }
+}
+
+/// A compressed proof of `num_steps` steps, with the final state it claims.
+pub struct NovaProof {
+    pub(crate) snark: Compressed,
+    pub(crate) num_steps: usize,
+    pub(crate) z_out: Vec<BigInt>,
+}
+
+impl NovaProof {
+    /// Returns the number of folded steps.
+    pub fn num_steps(&self) -> usize {
+        self.num_steps
+    }
+
+    /// Returns the state after the last step.
+    pub fn z_out(&self) -> &[BigInt] {
+        &self.z_out
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        let z_out = self.z_out.iter().map(BigInt::to_signed_bytes_le);
+        encode(&(&self.snark, self.num_steps, z_out.collect::<Vec<_>>()))
+    }
+
+    pub fn deserialize(data: &[u8]) -> Result<Self> {
+        let (snark, num_steps, z_out): (_, _, Vec<Vec<u8>>) = decode(data)?;
+        let z_out = z_out.iter().map(|x| BigInt::from_signed_bytes_le(x));
+
+        Ok(Self {
+            snark,
+            num_steps,
+            z_out: z_out.collect(),
+        })
+    }
+}
+
+impl fmt::Debug for NovaProof {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NovaProof")
+            .field("num_steps", &self.num_steps)
+            .field("z_out", &self.z_out)
+            .finish_non_exhaustive()
+    }
+}
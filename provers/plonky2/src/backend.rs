@@ -0,0 +1,227 @@
+use num::Zero;
+use plonky2::{
+    iop::witness::{PartialWitness, WitnessWrite},
+    plonk::{circuit_builder::CircuitBuilder, circuit_data::CircuitConfig},
+};
+use rsnark_core::{
+    CurveId, MetadataInfo, ProvingSystem,
+    curve::Goldilocks,
+    plonkish::Plonkish,
+    stats::ConstraintStats,
+    types::{CircuitDefinition, PublicWitness, Witness},
+};
+use rsnark_provers_core::Backend;
+
+use crate::{
+    Error, Result,
+    types::{C, D, F, Plonky2Proof, Plonky2ProvingKey, Plonky2VerifyingKey, to_field},
+};
+
+/// Backend implementation using plonky2 over the Goldilocks field.
+///
+/// Circuits are compiled to a native [`Plonkish`] gate list, which is also
+/// used to compute the full assignment of a witness. The setup translates every
+/// gate into plonky2 arithmetic over one target per variable, and registers the
+/// public inputs in order. It does not involve any secret randomness, and
+/// proofs are zero-knowledge.
+///
+/// Proving fails with [`Error::SolveError`] if the witness does not satisfy
+/// the circuit.
+#[derive(Clone)]
+pub struct Plonky2Backend;
+
+impl Backend for Plonky2Backend {
+    type CircuitConstraint = Plonkish;
+    type ProvingKey = Plonky2ProvingKey;
+    type VerifyingKey = Plonky2VerifyingKey;
+    type Proof = Plonky2Proof;
+
+    type Config = ();
+
+    type Error = Error;
+
+    fn with_config(_config: ()) -> Result<Self> {
+        Ok(Self)
+    }
+
+    fn metadata(&self) -> MetadataInfo {
+        MetadataInfo {
+            field: Goldilocks::field(),
+            curve: Goldilocks::curve_type(),
+            proving_system: ProvingSystem::Plonky2,
+        }
+    }
+
+    fn compile(&self, circuit: &CircuitDefinition) -> Result<Self::CircuitConstraint> {
+        Ok(Plonkish::compile(circuit, &Goldilocks::field())?)
+    }
+
+    fn constraint_stats(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+    ) -> Result<Option<ConstraintStats>> {
+        Ok(Some(plonkish.stats()))
+    }
+
+    fn setup(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let mut builder =
+            CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_zk_config());
+        let targets = builder.add_virtual_targets(plonkish.num_variables());
+
+        builder.register_public_inputs(&targets[1..=plonkish.public_len()]);
+
+        // Public input gates are replaced by the registration above.
+        for gate in &plonkish.gates[plonkish.public_len()..] {
+            let [a, b, c] = gate.wires.map(|wire| wire.map(|var| targets[var]));
+
+            let mut sum = builder.constant(to_field(&gate.q_c));
+            for (q, wire) in [(&gate.q_l, a), (&gate.q_r, b), (&gate.q_o, c)] {
+                if let Some(wire) = wire
+                    && !q.is_zero()
+                {
+                    sum = builder.mul_const_add(to_field(q), wire, sum);
+                }
+            }
+            if let (Some(a), Some(b)) = (a, b)
+                && !gate.q_m.is_zero()
+            {
+                let ab = builder.mul(a, b);
+                sum = builder.mul_const_add(to_field(&gate.q_m), ab, sum);
+            }
+
+            builder.assert_zero(sum);
+        }
+
+        let data = builder.build::<C>();
+        let vk = Plonky2VerifyingKey(data.verifier_data());
+        let pk = Plonky2ProvingKey {
+            data: data.prover_data(),
+            targets,
+        };
+
+        Ok((pk, vk))
+    }
+
+    fn prove(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+        pk: &Self::ProvingKey,
+        witness: &Witness,
+    ) -> Result<Self::Proof> {
+        let assignment = plonkish.solve(witness)?;
+        plonkish.check(&assignment)?;
+
+        let mut pw = PartialWitness::new();
+        for (target, value) in pk.targets.iter().zip(&assignment) {
+            pw.set_target(*target, to_field(value))?;
+        }
+
+        Ok(Plonky2Proof(pk.data.prove(pw)?))
+    }
+
+    fn verify(
+        &self,
+        vk: &Self::VerifyingKey,
+        proof: &Self::Proof,
+        public_witness: &PublicWitness,
+    ) -> Result<bool> {
+        let public: Vec<F> = public_witness.public.iter().map(to_field).collect();
+        if public != proof.0.public_inputs {
+            return Ok(false);
+        }
+
+        Ok(vk.0.verify(proof.0.clone()).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use plonky2::field::types::Field64;
+    use rsnark_core::{BigInt, CircuitWitness, Witness, types};
+    use rsnark_provers_core::{
+        Prover,
+        test_circuits::{RangeCircuit, TestCircuit},
+    };
+
+    use crate::RecursiveCircuit;
+
+    #[test]
+    fn test_plonky2_with_core_prover() {
+        assert_eq!(Goldilocks::field(), BigInt::from(F::ORDER));
+
+        let prover: Prover<Plonky2Backend> = Prover::new();
+
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<TestCircuit> {
+            a: 3,
+            b: 4,
+            c: 7, // 3 + 4 = 7
+        };
+
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+        assert_eq!(proof.public_inputs(), vec![BigInt::from(7)]);
+
+        let public_witness = circuit_witness.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+
+        let mut wrong_public = types::PublicWitness::new();
+        wrong_public.public_mut().push(8.into());
+        assert!(!Plonky2Backend.verify(&vk, &proof, &wrong_public).unwrap());
+
+        let wrong = Witness::<TestCircuit> { a: 3, b: 4, c: 8 };
+        assert!(circuit_prover.prove(&pk, &wrong).is_err());
+    }
+
+    #[test]
+    fn test_internal_wires() {
+        let prover: Prover<Plonky2Backend> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<RangeCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<RangeCircuit> { x: 6, y: 5, z: 15 };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+        circuit_prover
+            .verify(&vk, &proof, circuit_witness.into_public_witness())
+            .unwrap();
+
+        let out_of_range = Witness::<RangeCircuit> {
+            x: 256,
+            y: 1,
+            z: 128,
+        };
+        assert!(circuit_prover.prove(&pk, &out_of_range).is_err());
+    }
+
+    #[test]
+    fn test_recursion_and_serialize() {
+        let prover: Prover<Plonky2Backend> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+        let fingerprint = circuit_prover.fingerprint();
+
+        let circuit_witness = Witness::<TestCircuit> { a: 3, b: 4, c: 7 };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+
+        let data = vk.serialize_with_fingerprint(fingerprint).unwrap();
+        let vk = Plonky2VerifyingKey::deserialize_with_fingerprint(fingerprint, &data).unwrap();
+        let proof = Plonky2Proof::deserialize(proof.serialize(), &vk).unwrap();
+
+        let recursive = RecursiveCircuit::new(&vk);
+        let outer = recursive.prove(&proof).unwrap();
+
+        let mut public_witness = types::PublicWitness::new();
+        public_witness.public_mut().push(7.into());
+        assert!(
+            Plonky2Backend
+                .verify(&recursive.verifying_key(), &outer, &public_witness)
+                .unwrap()
+        );
+    }
+}
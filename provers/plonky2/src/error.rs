@@ -0,0 +1,29 @@
+use rsnark_core::{
+    fingerprint::FingerprintError,
+    r1cs::{CompileError, SolveError},
+};
+
+/// Error types for plonky2 backend operations.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SolveError(#[from] SolveError),
+
+    #[error(transparent)]
+    Plonky2Error(#[from] anyhow::Error),
+
+    #[error("serialize error")]
+    SerializeError,
+
+    #[error("deserialize error")]
+    DeserializeError,
+
+    #[error(transparent)]
+    FingerprintError(#[from] FingerprintError),
+}
+
+/// Convenience type alias for Results with plonky2 Error.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,33 @@
+//! # rsnark-provers-plonky2
+//!
+//! Backend implementation based on [plonky2](https://github.com/0xPolygonZero/plonky2).
+//!
+//! This crate proves circuits over the Goldilocks field with plonky2's FRI-based
+//! PLONK, entirely on CPU and without a trusted setup. Circuit definitions are
+//! lowered with the native [`Plonkish`](rsnark_core::plonkish::Plonkish)
+//! arithmetization of `rsnark-core`, and every gate becomes plonky2 arithmetic.
+//!
+//! ## Key Components
+//!
+//! - [`Plonky2Backend`]: Backend implementation using plonky2's prover
+//! - [`RecursiveCircuit`]: Proves that a proof of another circuit verifies
+//! - [`Error`]: Error types for compilation, solving and plonky2 operations
+//! - [`types`]: Keys and proofs, with the field and configuration in use
+//!
+//! ## Requirements
+//!
+//! plonky2 needs a nightly toolchain, which `rust-toolchain.toml` selects for
+//! this crate. It is therefore not part of the main workspace, and the `plonky2`
+//! feature of `rsnark` must be built with `cargo +nightly`. Its tests run with
+//! `cargo test` from `provers/plonky2`, separately from `cargo test --workspace`.
+
+mod error;
+pub use error::*;
+
+pub mod types;
+
+mod backend;
+pub use backend::*;
+
+mod recursion;
+pub use recursion::*;
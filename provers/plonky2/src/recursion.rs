@@ -0,0 +1,65 @@
+use plonky2::{
+    iop::witness::{PartialWitness, WitnessWrite},
+    plonk::{
+        circuit_builder::CircuitBuilder,
+        circuit_data::{CircuitConfig, CircuitData},
+        proof::ProofWithPublicInputsTarget,
+    },
+};
+
+use crate::{
+    Result,
+    types::{C, D, F, Plonky2Proof, Plonky2VerifyingKey},
+};
+
+/// A circuit proving that a proof of another circuit verifies.
+///
+/// The outer proof has the same public inputs as the inner one, so it verifies
+/// against the same public witness with [`RecursiveCircuit::verifying_key`].
+/// Recursive circuits can themselves be wrapped, which keeps the proof size
+/// constant while compressing a chain of proofs.
+///
+/// ```rust,ignore
+/// let (pk, vk) = circuit_prover.setup()?;
+/// let proof = circuit_prover.prove(&pk, &witness)?;
+///
+/// let recursive = RecursiveCircuit::new(&vk);
+/// let outer = recursive.prove(&proof)?;
+/// Plonky2Backend::new().verify(&recursive.verifying_key(), &outer, &public_witness)?;
+/// ```
+pub struct RecursiveCircuit {
+    data: CircuitData<F, C, D>,
+    proof: ProofWithPublicInputsTarget<D>,
+}
+
+impl RecursiveCircuit {
+    /// Builds a circuit verifying proofs of the circuit of `inner`.
+    pub fn new(inner: &Plonky2VerifyingKey) -> Self {
+        let inner = &inner.0;
+        let mut builder =
+            CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_zk_config());
+
+        let proof = builder.add_virtual_proof_with_pis(&inner.common);
+        let verifier_data = builder.constant_verifier_data(&inner.verifier_only);
+        builder.verify_proof::<C>(&proof, &verifier_data, &inner.common);
+        builder.register_public_inputs(&proof.public_inputs);
+
+        Self {
+            data: builder.build::<C>(),
+            proof,
+        }
+    }
+
+    /// Returns the verifying key of the outer proofs.
+    pub fn verifying_key(&self) -> Plonky2VerifyingKey {
+        Plonky2VerifyingKey(self.data.verifier_data())
+    }
+
+    /// Proves that `inner` verifies.
+    pub fn prove(&self, inner: &Plonky2Proof) -> Result<Plonky2Proof> {
+        let mut pw = PartialWitness::new();
+        pw.set_proof_with_pis_target(&self.proof, &inner.0)?;
+
+        Ok(Plonky2Proof(self.data.prove(pw)?))
+    }
+}
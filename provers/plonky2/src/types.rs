@@ -0,0 +1,111 @@
+//! Keys and proofs of the plonky2 backend.
+
+use num::{BigInt, Integer, ToPrimitive};
+use plonky2::{
+    field::{
+        goldilocks_field::GoldilocksField,
+        types::{Field, Field64, PrimeField64},
+    },
+    iop::target::Target,
+    plonk::{
+        circuit_data::{ProverCircuitData, VerifierCircuitData},
+        config::PoseidonGoldilocksConfig,
+        proof::ProofWithPublicInputs,
+    },
+    util::serialization::DefaultGateSerializer,
+};
+use rsnark_core::fingerprint::{self, Fingerprint};
+
+use crate::{Error, Result};
+
+/// The field circuits are proven over.
+pub type F = GoldilocksField;
+
+/// The hash configuration of the circuits.
+pub type C = PoseidonGoldilocksConfig;
+
+/// The degree of the extension field used by FRI.
+pub const D: usize = 2;
+
+/// Converts `value` into a field element, reducing it modulo the field.
+pub(crate) fn to_field(value: &BigInt) -> F {
+    let value = value.mod_floor(&BigInt::from(F::ORDER));
+
+    F::from_canonical_u64(value.to_u64().expect("reduced modulo a 64-bit field"))
+}
+
+/// Converts a field element into its canonical integer.
+pub(crate) fn from_field(value: F) -> BigInt {
+    BigInt::from(value.to_canonical_u64())
+}
+
+/// Prover data of a circuit, with the target of every variable.
+pub struct Plonky2ProvingKey {
+    pub(crate) data: ProverCircuitData<F, C, D>,
+    pub(crate) targets: Vec<Target>,
+}
+
+/// Verifier data of a circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plonky2VerifyingKey(pub(crate) VerifierCircuitData<F, C, D>);
+
+impl Plonky2VerifyingKey {
+    /// Returns the underlying plonky2 verifier data.
+    pub fn inner(&self) -> &VerifierCircuitData<F, C, D> {
+        &self.0
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        self.0
+            .to_bytes(&DefaultGateSerializer)
+            .map_err(|_| Error::SerializeError)
+    }
+
+    pub fn deserialize(data: Vec<u8>) -> Result<Self> {
+        VerifierCircuitData::from_bytes(data, &DefaultGateSerializer)
+            .map(Self)
+            .map_err(|_| Error::DeserializeError)
+    }
+
+    /// Serializes this key, sealed with the fingerprint of its circuit.
+    pub fn serialize_with_fingerprint(&self, fingerprint: &Fingerprint) -> Result<Vec<u8>> {
+        Ok(fingerprint::seal(fingerprint, &self.serialize()?))
+    }
+
+    /// Deserializes a key sealed by `serialize_with_fingerprint`.
+    ///
+    /// Fails with [`Error::FingerprintError`] if it was sealed for another circuit.
+    pub fn deserialize_with_fingerprint(fingerprint: &Fingerprint, data: &[u8]) -> Result<Self> {
+        Self::deserialize(fingerprint::open(fingerprint, data)?.to_vec())
+    }
+}
+
+/// A proof, together with its public inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plonky2Proof(pub(crate) ProofWithPublicInputs<F, C, D>);
+
+impl Plonky2Proof {
+    /// Returns the underlying plonky2 proof.
+    pub fn inner(&self) -> &ProofWithPublicInputs<F, C, D> {
+        &self.0
+    }
+
+    /// Returns the public inputs the proof was generated for.
+    pub fn public_inputs(&self) -> Vec<BigInt> {
+        self.0
+            .public_inputs
+            .iter()
+            .copied()
+            .map(from_field)
+            .collect()
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Deserializes a proof of the circuit of `vk`.
+    pub fn deserialize(data: Vec<u8>, vk: &Plonky2VerifyingKey) -> Result<Self> {
+        Ok(Self(ProofWithPublicInputs::from_bytes(data, &vk.0.common)?))
+    }
+}
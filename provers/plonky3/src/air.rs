@@ -0,0 +1,112 @@
+use p3_air::{Air, AirBuilder, BaseAir, WindowAccess};
+use p3_field::PrimeCharacteristicRing;
+use p3_matrix::dense::RowMajorMatrix;
+use rsnark_core::{
+    BigInt,
+    plonkish::{COLUMNS, Plonkish},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Val, to_field};
+
+/// Height of the execution trace.
+///
+/// Every row holds the same assignment, so the height only has to be a power
+/// of two large enough for FRI.
+pub(crate) const TRACE_HEIGHT: usize = 4;
+
+/// An arithmetic gate of a [`GateAir`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct AirGate {
+    /// Selectors `[qL, qR, qO, qM, qC]`.
+    selectors: [Val; 5],
+    /// Columns of the `a`, `b` and `c` wires.
+    wires: [Option<usize>; COLUMNS],
+}
+
+/// A generic arithmetic AIR, lowered from a [`Plonkish`] gate list.
+///
+/// The trace has one column per variable, so that copy constraints hold by
+/// construction, and every row must satisfy every gate
+///
+/// ```text
+/// qL * a + qR * b + qO * c + qM * a * b + qC = 0
+/// ```
+///
+/// Public inputs are public values of the AIR, bound to their columns on the
+/// first row. All constraints have degree at most 2.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GateAir {
+    width: usize,
+    public_len: usize,
+    gates: Vec<AirGate>,
+}
+
+impl GateAir {
+    /// Lowers the gates of `plonkish`, which must be defined over [`Val`].
+    pub(crate) fn new(plonkish: &Plonkish) -> Self {
+        // Public inputs are bound by public values instead of their gates.
+        let gates = plonkish.gates[plonkish.public_len()..]
+            .iter()
+            .map(|gate| AirGate {
+                selectors: [&gate.q_l, &gate.q_r, &gate.q_o, &gate.q_m, &gate.q_c].map(to_field),
+                wires: gate.wires,
+            })
+            .collect();
+
+        Self {
+            width: plonkish.num_variables(),
+            public_len: plonkish.public_len(),
+            gates,
+        }
+    }
+
+    /// Returns the number of gates, excluding public input gates.
+    pub fn num_gates(&self) -> usize {
+        self.gates.len()
+    }
+
+    /// Builds the execution trace of a full assignment of the variables.
+    pub(crate) fn trace(&self, assignment: &[BigInt]) -> RowMajorMatrix<Val> {
+        let row: Vec<Val> = assignment.iter().map(to_field).collect();
+
+        RowMajorMatrix::new(row.repeat(TRACE_HEIGHT), self.width)
+    }
+}
+
+impl<F> BaseAir<F> for GateAir {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn num_public_values(&self) -> usize {
+        self.public_len
+    }
+
+    fn max_constraint_degree(&self) -> Option<usize> {
+        Some(2)
+    }
+}
+
+impl<AB: AirBuilder<F = Val>> Air<AB> for GateAir {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.current_slice();
+        let public_values = builder.public_values().to_vec();
+
+        // Variable 0 is the constant one, public inputs follow it.
+        let mut when_first_row = builder.when_first_row();
+        for (idx, value) in public_values.into_iter().enumerate() {
+            when_first_row.assert_eq(local[idx + 1], value);
+        }
+
+        for gate in &self.gates {
+            let [a, b, c] = gate
+                .wires
+                .map(|wire| wire.map_or(AB::Expr::ZERO, |col| local[col].into()));
+            let [q_l, q_r, q_o, q_m, q_c] = gate.selectors;
+
+            builder.assert_zero(a.clone() * q_l + b.clone() * q_r + c * q_o + a * b * q_m + q_c);
+        }
+    }
+}
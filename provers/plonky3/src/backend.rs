@@ -0,0 +1,209 @@
+use p3_uni_stark::{prove, verify};
+use rsnark_core::{
+    CurveId, MetadataInfo, ProvingSystem,
+    curve::BabyBear,
+    plonkish::Plonkish,
+    stats::ConstraintStats,
+    types::{CircuitDefinition, PublicWitness, Witness},
+};
+use rsnark_provers_core::Backend;
+
+use crate::{
+    Error, GateAir, Result,
+    types::{Plonky3Proof, Plonky3ProvingKey, Plonky3VerifyingKey, Val, config, to_field},
+};
+
+/// Experimental backend implementation using Plonky3 over the BabyBear field.
+///
+/// Circuits are compiled to a native [`Plonkish`] gate list, which is also
+/// used to compute the full assignment of a witness. The setup lowers the gates
+/// to a [`GateAir`] and does not involve any randomness, so both keys are the
+/// AIR itself.
+///
+/// Proving fails with [`Error::SolveError`] if the witness does not satisfy
+/// the circuit. Proofs are not zero-knowledge, see the
+/// [crate documentation](crate).
+#[derive(Clone)]
+pub struct Plonky3Backend;
+
+impl Backend for Plonky3Backend {
+    type CircuitConstraint = Plonkish;
+    type ProvingKey = Plonky3ProvingKey;
+    type VerifyingKey = Plonky3VerifyingKey;
+    type Proof = Plonky3Proof;
+
+    type Config = ();
+
+    type Error = Error;
+
+    fn with_config(_config: ()) -> Result<Self> {
+        Ok(Self)
+    }
+
+    fn metadata(&self) -> MetadataInfo {
+        MetadataInfo {
+            field: BabyBear::field(),
+            curve: BabyBear::curve_type(),
+            proving_system: ProvingSystem::Plonky3,
+        }
+    }
+
+    fn compile(&self, circuit: &CircuitDefinition) -> Result<Self::CircuitConstraint> {
+        Ok(Plonkish::compile(circuit, &BabyBear::field())?)
+    }
+
+    fn constraint_stats(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+    ) -> Result<Option<ConstraintStats>> {
+        Ok(Some(plonkish.stats()))
+    }
+
+    fn setup(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let air = GateAir::new(plonkish);
+
+        Ok((Plonky3ProvingKey(air.clone()), Plonky3VerifyingKey(air)))
+    }
+
+    fn prove(
+        &self,
+        plonkish: &Self::CircuitConstraint,
+        pk: &Self::ProvingKey,
+        witness: &Witness,
+    ) -> Result<Self::Proof> {
+        let assignment = plonkish.solve(witness)?;
+        plonkish.check(&assignment)?;
+
+        let public_values: Vec<Val> = witness.public().iter().map(to_field).collect();
+        let proof = prove(&config(), &pk.0, pk.0.trace(&assignment), &public_values)?;
+
+        Ok(Plonky3Proof {
+            proof,
+            public_values,
+        })
+    }
+
+    fn verify(
+        &self,
+        vk: &Self::VerifyingKey,
+        proof: &Self::Proof,
+        public_witness: &PublicWitness,
+    ) -> Result<bool> {
+        let public: Vec<Val> = public_witness.public.iter().map(to_field).collect();
+        if public != proof.public_values {
+            return Ok(false);
+        }
+
+        Ok(verify(&config(), &vk.0, &proof.proof, &public).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p3_field::PrimeField64;
+    use rsnark_core::{BigInt, CircuitWitness, Metadata, Witness, r1cs::SolveError, types};
+    use rsnark_provers_core::{
+        Prover,
+        test_circuits::{RangeCircuit, TestCircuit},
+    };
+
+    #[test]
+    fn test_plonky3_with_core_prover() {
+        let backend = Plonky3Backend::new();
+        assert_eq!(backend.metadata().field(), &BigInt::from(Val::ORDER_U64));
+        assert!(backend.metadata().is_small_field());
+
+        let prover: Prover<Plonky3Backend> = Prover::new();
+
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<TestCircuit> {
+            a: 3,
+            b: 4,
+            c: 7, // 3 + 4 = 7
+        };
+
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+        assert_eq!(proof.public_inputs(), vec![BigInt::from(7)]);
+
+        let public_witness = circuit_witness.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+
+        let mut wrong_public = types::PublicWitness::new();
+        wrong_public.public_mut().push(8.into());
+        assert!(!backend.verify(&vk, &proof, &wrong_public).unwrap());
+
+        let wrong = Witness::<TestCircuit> { a: 3, b: 4, c: 8 };
+        let err = circuit_prover.prove(&pk, &wrong).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::SolveError(SolveError::Unsatisfied { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_internal_wires() {
+        let prover: Prover<Plonky3Backend> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<RangeCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+
+        let circuit_witness = Witness::<RangeCircuit> { x: 6, y: 5, z: 15 };
+        let proof = circuit_prover.prove(&pk, &circuit_witness).unwrap();
+        circuit_prover
+            .verify(&vk, &proof, circuit_witness.into_public_witness())
+            .unwrap();
+
+        let out_of_range = Witness::<RangeCircuit> {
+            x: 256,
+            y: 1,
+            z: 128,
+        };
+        assert!(circuit_prover.prove(&pk, &out_of_range).is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let prover: Prover<Plonky3Backend> = Prover::new();
+        let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
+        let (pk, vk) = circuit_prover.setup().unwrap();
+        let fingerprint = circuit_prover.fingerprint();
+
+        let proof = circuit_prover
+            .prove(&pk, &Witness::<TestCircuit> { a: 3, b: 4, c: 7 })
+            .unwrap();
+
+        let data = vk.serialize_with_fingerprint(fingerprint).unwrap();
+        let vk = Plonky3VerifyingKey::deserialize_with_fingerprint(fingerprint, &data).unwrap();
+        let proof = Plonky3Proof::deserialize(proof.serialize().unwrap()).unwrap();
+
+        let public_witness = Witness::<TestCircuit> { a: 3, b: 4, c: 7 }.into_public_witness();
+        circuit_prover.verify(&vk, &proof, public_witness).unwrap();
+    }
+
+    #[test]
+    fn test_dyn_backend() {
+        use rsnark_provers_core::DynBackend;
+
+        let backend: Box<dyn DynBackend> = Box::new(Plonky3Backend::new());
+        let definition = Prover::<Plonky3Backend>::new().define_circuit::<TestCircuit>();
+
+        let cs = backend.compile(&definition).unwrap();
+        assert!(backend.constraint_stats(&cs).unwrap().is_some());
+        let (pk, vk) = backend.setup(&cs).unwrap();
+
+        let witness = types::Witness::from((vec![7.into()], vec![3.into(), 4.into()]));
+        let proof = backend.prove(&cs, &pk, &witness).unwrap();
+
+        let mut public = types::PublicWitness::new();
+        public.public_mut().push(7.into());
+        assert!(backend.verify(&vk, &proof, &public).unwrap());
+
+        assert!(backend.verify(&vk, b"not a proof", &public).is_err());
+    }
+}
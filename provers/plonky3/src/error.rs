@@ -0,0 +1,29 @@
+use p3_uni_stark::{PcsProverError, ProvingError};
+use rsnark_core::{
+    fingerprint::FingerprintError,
+    r1cs::{CompileError, SolveError},
+};
+
+use crate::types::Config;
+
+/// Error types for plonky3 backend operations.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SolveError(#[from] SolveError),
+
+    #[error(transparent)]
+    ProvingError(#[from] ProvingError<PcsProverError<Config>>),
+
+    #[error(transparent)]
+    SerializationError(#[from] postcard::Error),
+
+    #[error(transparent)]
+    FingerprintError(#[from] FingerprintError),
+}
+
+/// Convenience type alias for Results with plonky3 Error.
+pub type Result<T> = std::result::Result<T, Error>;
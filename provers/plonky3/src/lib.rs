@@ -0,0 +1,32 @@
+//! # rsnark-provers-plonky3
+//!
+//! Experimental backend implementation based on [Plonky3](https://github.com/Plonky3/Plonky3).
+//!
+//! This crate proves circuits with Plonky3's uni-stark prover over the 31-bit
+//! BabyBear field, without a trusted setup. Circuit definitions are lowered with
+//! the native [`Plonkish`](rsnark_core::plonkish::Plonkish) arithmetization of
+//! `rsnark-core`, whose gates become the constraints of a [`GateAir`].
+//!
+//! ## Key Components
+//!
+//! - [`Plonky3Backend`]: Backend implementation producing STARK proofs
+//! - [`GateAir`]: The generic arithmetic AIR circuits are lowered to
+//! - [`Error`]: Error types for compilation, solving and plonky3 operations
+//! - [`types`]: Keys and proofs, with the field and STARK configuration in use
+//!
+//! ## Limitations
+//!
+//! Proofs are **not zero-knowledge**: the trace is committed without hiding,
+//! and every row of it holds the full assignment. Only use this backend for
+//! computations whose inputs may be revealed.
+
+mod air;
+pub use air::*;
+
+pub mod types;
+
+mod error;
+pub use error::*;
+
+mod backend;
+pub use backend::*;
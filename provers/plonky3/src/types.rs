@@ -0,0 +1,165 @@
+//! Keys, proofs and STARK configuration of the plonky3 backend.
+
+use num::{BigInt, Integer, ToPrimitive};
+use p3_baby_bear::{BabyBear, Poseidon2BabyBear, default_babybear_poseidon2_16};
+use p3_challenger::DuplexChallenger;
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::{Field, PrimeCharacteristicRing, PrimeField64, extension::BinomialExtensionField};
+use p3_fri::{FriParameters, TwoAdicFriPcs};
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{PaddingFreeSponge, TruncatedPermutation};
+use p3_uni_stark::StarkConfig;
+use rsnark_core::fingerprint::{self, Fingerprint};
+use rsnark_provers_core::Artifact;
+
+use crate::{Error, GateAir, Result};
+
+/// The field circuits are proven over.
+pub type Val = BabyBear;
+
+/// The extension field FRI challenges are drawn from.
+pub type Challenge = BinomialExtensionField<Val, 4>;
+
+type Perm = Poseidon2BabyBear<16>;
+type Hash = PaddingFreeSponge<Perm, 16, 8, 8>;
+type Compress = TruncatedPermutation<Perm, 2, 8, 16>;
+type ValMmcs =
+    MerkleTreeMmcs<<Val as Field>::Packing, <Val as Field>::Packing, Hash, Compress, 2, 8>;
+type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+type Challenger = DuplexChallenger<Val, Perm, 16, 8>;
+type Dft = Radix2DitParallel<Val>;
+type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+
+/// The STARK configuration: Poseidon2 Merkle commitments and FRI over BabyBear.
+pub type Config = StarkConfig<Pcs, Challenge, Challenger>;
+
+/// Builds the STARK configuration shared by the prover and the verifier.
+pub(crate) fn config() -> Config {
+    let perm = default_babybear_poseidon2_16();
+    let hash = Hash::new(perm.clone());
+    let compress = Compress::new(perm.clone());
+    let val_mmcs = ValMmcs::new(hash, compress, 0);
+    let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
+    let fri_params = FriParameters::new_benchmark(challenge_mmcs);
+    let pcs = Pcs::new(Dft::default(), val_mmcs, fri_params);
+
+    Config::new(pcs, Challenger::new(perm))
+}
+
+/// Converts `value` into a field element, reducing it modulo the field.
+pub(crate) fn to_field(value: &BigInt) -> Val {
+    let value = value.mod_floor(&BigInt::from(Val::ORDER_U64));
+
+    Val::from_u64(value.to_u64().expect("reduced modulo a 31-bit field"))
+}
+
+/// Converts a field element into its canonical integer.
+pub(crate) fn from_field(value: Val) -> BigInt {
+    BigInt::from(value.as_canonical_u64())
+}
+
+macro_rules! impl_air_key {
+    ($name:ident) => {
+        impl $name {
+            pub fn serialize(&self) -> Result<Vec<u8>> {
+                Ok(postcard::to_allocvec(&self.0)?)
+            }
+
+            pub fn deserialize(data: Vec<u8>) -> Result<Self> {
+                Ok(Self(postcard::from_bytes(&data)?))
+            }
+
+            /// Serializes this key, sealed with the fingerprint of its circuit.
+            pub fn serialize_with_fingerprint(&self, fingerprint: &Fingerprint) -> Result<Vec<u8>> {
+                Ok(fingerprint::seal(fingerprint, &self.serialize()?))
+            }
+
+            /// Deserializes a key sealed by `serialize_with_fingerprint`.
+            ///
+            /// Fails with [`Error::FingerprintError`](crate::Error::FingerprintError) if it was sealed for another circuit.
+            pub fn deserialize_with_fingerprint(
+                fingerprint: &Fingerprint,
+                data: &[u8],
+            ) -> Result<Self> {
+                Self::deserialize(fingerprint::open(fingerprint, data)?.to_vec())
+            }
+        }
+    };
+}
+
+/// Proving key of a circuit, which is its AIR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plonky3ProvingKey(pub(crate) GateAir);
+impl_air_key!(Plonky3ProvingKey);
+
+/// Verifying key of a circuit, which is its AIR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plonky3VerifyingKey(pub(crate) GateAir);
+impl_air_key!(Plonky3VerifyingKey);
+
+impl Plonky3VerifyingKey {
+    /// Returns the AIR proofs are checked against.
+    pub fn air(&self) -> &GateAir {
+        &self.0
+    }
+}
+
+/// A STARK proof, together with its public inputs.
+pub struct Plonky3Proof {
+    pub(crate) proof: p3_uni_stark::Proof<Config>,
+    pub(crate) public_values: Vec<Val>,
+}
+
+impl Plonky3Proof {
+    /// Returns the underlying plonky3 proof.
+    pub fn inner(&self) -> &p3_uni_stark::Proof<Config> {
+        &self.proof
+    }
+
+    /// Returns the public inputs the proof was generated for.
+    pub fn public_inputs(&self) -> Vec<BigInt> {
+        self.public_values.iter().copied().map(from_field).collect()
+    }
+
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        Ok(postcard::to_allocvec(&(&self.proof, &self.public_values))?)
+    }
+
+    pub fn deserialize(data: Vec<u8>) -> Result<Self> {
+        let (proof, public_values) = postcard::from_bytes(&data)?;
+        Ok(Self {
+            proof,
+            public_values,
+        })
+    }
+}
+
+impl std::fmt::Debug for Plonky3Proof {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Plonky3Proof")
+            .field("degree_bits", &self.proof.degree_bits)
+            .field("public_values", &self.public_values)
+            .finish_non_exhaustive()
+    }
+}
+
+macro_rules! impl_artifact {
+    ($($name:ident),*) => {
+        $(
+            impl Artifact for $name {
+                type Error = Error;
+
+                fn to_bytes(&self) -> Result<Vec<u8>> {
+                    self.serialize()
+                }
+
+                fn from_bytes(data: &[u8]) -> Result<Self> {
+                    Self::deserialize(data.to_vec())
+                }
+            }
+        )*
+    };
+}
+
+impl_artifact!(Plonky3ProvingKey, Plonky3VerifyingKey, Plonky3Proof);
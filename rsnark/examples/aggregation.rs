@@ -0,0 +1,94 @@
+use std::sync::OnceLock;
+
+use rsnark::{
+    Groth16BN254GnarkProver,
+    core::{
+        API, Circuit, CircuitWitness, circuit,
+        curve::BN254,
+        recursion::plonk::{self, Proof},
+    },
+    provers::{Backend, Prover, gnark::PlonkBackend},
+};
+use rsnark_core::Witness;
+
+const N: usize = 2;
+
+// The verifying key of `Sum`, fixed in the outer circuit.
+static INNER_VK: OnceLock<plonk::VerifyingKey> = OnceLock::new();
+
+// The inner circuit, proved with PLONK on BN254.
+#[circuit]
+pub struct Sum {
+    a: u32,
+    b: u32,
+    pub c: u32,
+}
+
+impl Circuit for Sum {
+    fn define(&self, api: &mut impl API) {
+        let c = api.add(&self.a, &self.b);
+        api.assert_is_equal(&c, &self.c);
+    }
+}
+
+// The outer circuit, proved with Groth16 on BN254: it verifies `N` proofs of
+// `Sum` for public sums.
+#[circuit]
+pub struct Aggregate {
+    proofs: [Proof; N],
+    pub sums: [u32; N],
+}
+
+impl Circuit for Aggregate {
+    fn define(&self, api: &mut impl API) {
+        let vk = INNER_VK.get().expect("inner verifying key is not set");
+
+        for (proof, sum) in self.proofs.iter().zip(&self.sums) {
+            plonk::assert_proof(api, vk, proof, &[sum]);
+        }
+    }
+}
+
+fn main() {
+    // Production keys need a real SRS, see `PlonkBackend::with_srs`.
+    let backend = PlonkBackend::<BN254>::new()
+        .insecure_test_srs()
+        .and_then(PlonkBackend::with_recursion)
+        .unwrap();
+    let inner = Prover::from_backend(backend)
+        .compile_circuit::<Sum>()
+        .unwrap();
+    let (inner_pk, inner_vk) = inner.setup().unwrap();
+    INNER_VK.set(inner_vk.to_recursion_key().unwrap()).unwrap();
+
+    let inputs = [(3, 4), (10, 20)];
+    let proofs = inputs.map(|(a, b)| {
+        let witness = Witness::<Sum> { a, b, c: a + b };
+        let proof = inner.prove(&inner_pk, &witness).unwrap();
+        proof.to_recursion_proof().unwrap()
+    });
+
+    let outer = Groth16BN254GnarkProver::new()
+        .compile_circuit::<Aggregate>()
+        .unwrap();
+    let (outer_pk, outer_vk) = outer.setup().unwrap();
+
+    let witness = Witness::<Aggregate> {
+        proofs,
+        sums: inputs.map(|(a, b)| a + b),
+    };
+    let proof = outer.prove(&outer_pk, &witness).unwrap();
+
+    outer
+        .verify(&outer_vk, &proof, witness.into_public_witness())
+        .unwrap();
+    println!("aggregated {N} PLONK proofs into one Groth16 proof");
+
+    let contract = outer_vk.export_solidity().unwrap();
+    let calldata = proof.to_solidity().unwrap();
+    println!(
+        "exported a Solidity verifier of {} bytes and a proof of {} words",
+        contract.len(),
+        calldata.len()
+    );
+}
@@ -0,0 +1,146 @@
+//! A Groth16 MPC setup for a circuit, with one step per subcommand:
+//!
+//! ```text
+//! ceremony init <dir> <phase1>            start phase 2 from a sealed phase 1
+//! ceremony init <dir> --insecure-phase1   start phase 2 from an insecure phase 1
+//! ceremony contribute <dir>               add a contribution on top of the last one
+//! ceremony verify <dir>                   verify every contribution
+//! ceremony extract <dir> <beacon>         extract the keys into <dir>
+//! ```
+//!
+//! With `--insecure-phase1`, `init` runs a single-party phase 1 whose toxic
+//! waste is not destroyed, for tests only. Without a subcommand, the whole
+//! ceremony runs in a temporary directory with such a phase 1.
+
+use std::{env, fs, path::Path, process};
+
+use rsnark::{
+    Groth16BN254GnarkProver,
+    core::{API, Circuit, CircuitWitness, circuit, curve::BN254},
+    provers::{
+        CircuitProver,
+        gnark::{
+            Groth16Backend,
+            types::{Groth16Phase1, Groth16Phase2, Groth16Phase2Evaluations},
+        },
+    },
+};
+use rsnark_core::Witness;
+
+#[circuit]
+pub struct Sum {
+    a: u32,
+    b: u32,
+    pub c: u32,
+}
+
+impl Circuit for Sum {
+    fn define(&self, api: &mut impl API) {
+        let c = api.add(&self.a, &self.b);
+        api.assert_is_equal(&c, &self.c);
+    }
+}
+
+type SumProver = CircuitProver<Groth16Backend<BN254>, Witness<Sum>>;
+
+fn compile() -> SumProver {
+    Groth16BN254GnarkProver::new()
+        .compile_circuit::<Sum>()
+        .unwrap()
+}
+
+fn read_phase1(dir: &Path) -> Groth16Phase1<BN254> {
+    Groth16Phase1::deserialize(fs::read(dir.join("phase1")).unwrap()).unwrap()
+}
+
+fn read_transcript(dir: &Path) -> Vec<Groth16Phase2<BN254>> {
+    (0..)
+        .map(|i| dir.join(format!("phase2-{i}")))
+        .take_while(|path| path.exists())
+        .map(|path| Groth16Phase2::deserialize(fs::read(path).unwrap()).unwrap())
+        .collect()
+}
+
+/// Starts phase 2 from the phase 1 at `phase1`, or from an insecure one if `None`.
+fn init(prover: &SumProver, dir: &Path, phase1: Option<&Path>) {
+    let phase1 = match phase1 {
+        Some(path) => Groth16Phase1::deserialize(fs::read(path).unwrap()).unwrap(),
+        None => Groth16Phase1::insecure_test(prover.constraint()).unwrap(),
+    };
+    let (initial, evaluations) = Groth16Phase2::initialize(&phase1, prover.constraint()).unwrap();
+
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join("phase1"), phase1.serialize().unwrap()).unwrap();
+    fs::write(dir.join("evaluations"), evaluations.serialize().unwrap()).unwrap();
+    fs::write(dir.join("phase2-0"), initial.serialize().unwrap()).unwrap();
+    println!("initialized phase 2 in {}", dir.display());
+}
+
+fn contribute(dir: &Path) {
+    let transcript = read_transcript(dir);
+    let next = transcript.last().unwrap().contribute().unwrap();
+
+    let path = dir.join(format!("phase2-{}", transcript.len()));
+    fs::write(&path, next.serialize().unwrap()).unwrap();
+    println!("contributed to {}", path.display());
+}
+
+fn verify(dir: &Path) {
+    let transcript = read_transcript(dir);
+    for (i, pair) in transcript.windows(2).enumerate() {
+        pair[0].verify(&pair[1]).unwrap();
+        println!("contribution {} is valid", i + 1);
+    }
+}
+
+fn extract(prover: &SumProver, dir: &Path, beacon: &str) {
+    let evaluations =
+        Groth16Phase2Evaluations::deserialize(fs::read(dir.join("evaluations")).unwrap()).unwrap();
+    let (pk, vk) = Groth16Phase2::extract(
+        &read_phase1(dir),
+        prover.constraint(),
+        &evaluations,
+        &read_transcript(dir),
+        beacon.as_bytes(),
+    )
+    .unwrap();
+
+    // The keys work like the ones of `setup`
+    let witness = Witness::<Sum> { a: 3, b: 4, c: 7 };
+    let proof = prover.prove(&pk, &witness).unwrap();
+    prover
+        .verify(&vk, &proof, witness.into_public_witness())
+        .unwrap();
+
+    fs::write(dir.join("pk"), pk.serialize().unwrap()).unwrap();
+    fs::write(dir.join("vk"), vk.serialize().unwrap()).unwrap();
+    println!("extracted the keys into {}", dir.display());
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let prover = compile();
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["init", dir, "--insecure-phase1"] => init(&prover, dir.as_ref(), None),
+        ["init", dir, phase1] => init(&prover, dir.as_ref(), Some(phase1.as_ref())),
+        ["contribute", dir] => contribute(dir.as_ref()),
+        ["verify", dir] => verify(dir.as_ref()),
+        ["extract", dir, beacon] => extract(&prover, dir.as_ref(), beacon),
+        [] => {
+            let dir = env::temp_dir().join("rsnark-ceremony");
+            let _ = fs::remove_dir_all(&dir);
+
+            init(&prover, &dir, None);
+            for _ in 0..3 {
+                contribute(&dir);
+            }
+            verify(&dir);
+            extract(&prover, &dir, "beacon");
+        }
+        _ => {
+            eprintln!("usage: ceremony init|contribute|verify|extract <dir> [..]");
+            process::exit(2);
+        }
+    }
+}
@@ -24,25 +24,36 @@ impl Circuit for TestCircuit {
 }
 
 fn main() {
-    run::<Groth16Backend<BN254>>();
-    run::<Groth16Backend<BLS12_377>>();
-    run::<Groth16Backend<BLS12_381>>();
-    run::<Groth16Backend<BLS24_315>>();
-    run::<Groth16Backend<BLS24_317>>();
-    run::<Groth16Backend<BW6_761>>();
-    run::<Groth16Backend<BW6_633>>();
-
-    run::<PlonkBackend<BN254>>();
-    run::<PlonkBackend<BLS12_377>>();
-    run::<PlonkBackend<BLS12_381>>();
-    run::<PlonkBackend<BLS24_315>>();
-    run::<PlonkBackend<BLS24_317>>();
-    run::<PlonkBackend<BW6_761>>();
-    run::<PlonkBackend<BW6_633>>();
+    run(Groth16Backend::<BN254>::new());
+    run(Groth16Backend::<BLS12_377>::new());
+    run(Groth16Backend::<BLS12_381>::new());
+    run(Groth16Backend::<BLS24_315>::new());
+    run(Groth16Backend::<BLS24_317>::new());
+    run(Groth16Backend::<BW6_761>::new());
+    run(Groth16Backend::<BW6_633>::new());
+
+    run(PlonkBackend::<BN254>::new().insecure_test_srs().unwrap());
+    run(PlonkBackend::<BLS12_377>::new()
+        .insecure_test_srs()
+        .unwrap());
+    run(PlonkBackend::<BLS12_381>::new()
+        .insecure_test_srs()
+        .unwrap());
+    run(PlonkBackend::<BLS24_315>::new()
+        .insecure_test_srs()
+        .unwrap());
+    run(PlonkBackend::<BLS24_317>::new()
+        .insecure_test_srs()
+        .unwrap());
+    run(PlonkBackend::<BW6_761>::new().insecure_test_srs().unwrap());
+    run(PlonkBackend::<BW6_633>::new().insecure_test_srs().unwrap());
+
+    #[cfg(feature = "plonky2")]
+    run(rsnark_provers_plonky2::Plonky2Backend::new());
 }
 
-fn run<B: Backend>() {
-    let prover = Prover::<B>::new();
+fn run<B: Backend>(backend: B) {
+    let prover = Prover::from_backend(backend);
 
     let circuit_prover = prover.compile_circuit::<TestCircuit>().unwrap();
     let (pk, vk) = circuit_prover.setup().unwrap();
@@ -0,0 +1,44 @@
+use rsnark::provers::nova::NovaProver;
+use rsnark_core::{API, BigInt, StepCircuit, Witness, circuit, types::VariableType};
+
+// One block of a chain: the state is (height, balance), and every block applies a
+// deposit and a withdrawal to the balance.
+#[circuit]
+pub struct Block {
+    deposit: u64,
+    withdrawal: u64,
+}
+
+impl StepCircuit for Block {
+    const ARITY: usize = 2;
+
+    fn step(&self, api: &mut impl API, z_in: &[VariableType]) -> Vec<VariableType> {
+        let height = api.add(&z_in[0], &1);
+
+        let credited = api.add(&z_in[1], &self.deposit);
+        let balance = api.sub(&credited, &self.withdrawal);
+        // The balance never goes negative
+        api.variable_to_binary(&balance, 64);
+
+        vec![height, balance]
+    }
+}
+
+fn main() {
+    let prover = NovaProver::<Block>::new().unwrap();
+    let (pk, vk) = prover.setup().unwrap();
+
+    let z0 = vec![BigInt::from(0), BigInt::from(100)];
+    let mut folding = prover.fold(&pk, &z0).unwrap();
+    for i in 0..10 {
+        let block = Witness::<Block> {
+            deposit: 10 * i,
+            withdrawal: 5 * i,
+        };
+        folding.step(&block).unwrap();
+    }
+    let proof = folding.compress().unwrap();
+
+    assert!(prover.verify(&vk, &proof, &z0).unwrap());
+    println!("z_out: {:?}", proof.z_out());
+}
@@ -0,0 +1,71 @@
+use std::sync::OnceLock;
+
+use rsnark::{
+    Groth16BLS12_377GnarkProver, Groth16BW6_761GnarkProver,
+    core::{
+        API, Circuit, CircuitWitness, circuit,
+        recursion::groth16::{self, Proof, PublicInputs, VerifyingKey, VerifyingKeyVariable},
+    },
+};
+use rsnark_core::Witness;
+
+// The verifying key of `Sum`, fixed in the outer circuit.
+static INNER_VK: OnceLock<VerifyingKey<1>> = OnceLock::new();
+
+// The inner circuit, proved on BLS12-377.
+#[circuit]
+pub struct Sum {
+    a: u32,
+    b: u32,
+    pub c: u32,
+}
+
+impl Circuit for Sum {
+    fn define(&self, api: &mut impl API) {
+        let c = api.add(&self.a, &self.b);
+        api.assert_is_equal(&c, &self.c);
+    }
+}
+
+// The outer circuit, proved on BW6-761: it verifies a proof of `Sum` for a public `c`.
+#[circuit]
+pub struct VerifySum {
+    proof: Proof,
+    pub c: PublicInputs<1>,
+}
+
+impl Circuit for VerifySum {
+    fn define(&self, api: &mut impl API) {
+        let vk = INNER_VK.get().expect("inner verifying key is not set");
+        let vk = VerifyingKeyVariable::constant(vk);
+
+        groth16::assert_proof(api, &vk, &self.proof, &self.c);
+    }
+}
+
+fn main() {
+    let inner = Groth16BLS12_377GnarkProver::new()
+        .compile_circuit::<Sum>()
+        .unwrap();
+    let (inner_pk, inner_vk) = inner.setup().unwrap();
+    INNER_VK.set(inner_vk.to_recursion_key().unwrap()).unwrap();
+
+    let witness = Witness::<Sum> { a: 3, b: 4, c: 7 };
+    let inner_proof = inner.prove(&inner_pk, &witness).unwrap();
+
+    let outer = Groth16BW6_761GnarkProver::new()
+        .compile_circuit::<VerifySum>()
+        .unwrap();
+    let (outer_pk, outer_vk) = outer.setup().unwrap();
+
+    let witness = Witness::<VerifySum> {
+        proof: inner_proof.to_recursion_proof().unwrap(),
+        c: PublicInputs::from_public_witness(&witness.into_public_witness()),
+    };
+    let proof = outer.prove(&outer_pk, &witness).unwrap();
+
+    outer
+        .verify(&outer_vk, &proof, witness.into_public_witness())
+        .unwrap();
+    println!("verified a Groth16 proof of BLS12-377 in BW6-761");
+}
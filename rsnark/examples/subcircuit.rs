@@ -1,4 +1,7 @@
-use rsnark::PlonkBN254GnarkProver;
+use rsnark::{
+    PlonkBN254GnarkProver,
+    provers::{Backend, gnark::PlonkBackend},
+};
 use rsnark_core::{API, Circuit, CircuitWitness, Witness, circuit};
 
 // Sub-circuit: Adder - computes a + b = sum
@@ -56,7 +59,8 @@ impl Circuit for CompositeCircuit {
 }
 
 fn main() {
-    let prover = PlonkBN254GnarkProver::new();
+    let prover =
+        PlonkBN254GnarkProver::from_backend(PlonkBackend::new().insecure_test_srs().unwrap());
 
     let circuit_prover = prover.compile_circuit::<CompositeCircuit>().unwrap();
     let (pk, vk) = circuit_prover.setup().unwrap();
@@ -151,11 +151,36 @@
 //! - [`plonk-bls24_315-gnark`](PlonkBLS24_315GnarkProver) - PLONK with BLS24-315 curve using Gnark backend
 //! - [`plonk-bw6_761-gnark`](PlonkBW6_761GnarkProver) - PLONK with BW6-761 curve using Gnark backend
 //! - [`plonk-bw6_633-gnark`](PlonkBW6_633GnarkProver) - PLONK with BW6-633 curve using Gnark backend
+//! - [`groth16-bn254-arkworks`](Groth16BN254ArkworksProver) - Groth16 with BN254 curve using arkworks backend
+//! - [`groth16-bls12_381-arkworks`](Groth16BLS12_381ArkworksProver) - Groth16 with BLS12-381 curve using arkworks backend
+//! - [`plonk-bn254-halo2`](PlonkBN254Halo2Prover) - PLONK with BN254 curve using halo2 backend with KZG
+//! - `plonky2-goldilocks-plonky2` - Plonky2 over the Goldilocks field, with the `plonky2` feature
+//! - [`plonky3-babybear-plonky3`](Plonky3BabyBearProver) - Experimental STARKs over the BabyBear field using Plonky3
+//!
+//! Each prover is created with [`Prover::new`](provers::Prover::new), or with the
+//! options of its backend with [`Prover::with_config`](provers::Prover::with_config),
+//! such as [`GnarkConfig`](provers::gnark::GnarkConfig) for Groth16 with gnark.
+//!
+//! Deployments that choose the backend from a configuration file select it at
+//! runtime instead, with [`DynProver::from_triple`], configured by a
+//! [`DynConfig`]. Keys and proofs are then opaque bytes. Plugins that bring their own backends use
+//! [`DynBackend`](provers::DynBackend), implemented by every backend whose
+//! compiled circuits, keys and proofs serialize to bytes.
+//!
+//! Incremental computations are proved with [`provers::nova::NovaProver`] instead,
+//! from a [`StepCircuit`](core::StepCircuit) rather than a circuit.
+//!
+//! Groth16 proofs over BLS12-377 can be verified inside circuits over BW6-761,
+//! and PLONK proofs over BN254 inside circuits over BN254, with the gnark
+//! backend, see [`core::recursion`].
 //!
 
 #[doc(inline)]
 pub use rsnark_core as core;
 
+mod registry;
+pub use registry::*;
+
 /// Prover to generate
 pub mod provers {
     #[doc(inline)]
@@ -165,6 +190,27 @@ pub mod provers {
     #[doc(inline)]
     pub use rsnark_provers_gnark as gnark;
 
+    /// Pure-Rust prover provided by arkworks.
+    #[doc(inline)]
+    pub use rsnark_provers_arkworks as arkworks;
+
+    /// PLONK prover with KZG commitments provided by halo2.
+    #[doc(inline)]
+    pub use rsnark_provers_halo2 as halo2;
+
+    /// Folding prover for incremental computations provided by Nova.
+    #[doc(inline)]
+    pub use rsnark_provers_nova as nova;
+
+    /// Prover provided by plonky2, which needs a nightly toolchain.
+    #[cfg(feature = "plonky2")]
+    #[doc(inline)]
+    pub use rsnark_provers_plonky2 as plonky2;
+
+    /// Experimental STARK prover provided by Plonky3.
+    #[doc(inline)]
+    pub use rsnark_provers_plonky3 as plonky3;
+
     /// Mock Prover.
     #[doc(inline)]
     pub use rsnark_provers_mock as mock;
@@ -224,3 +270,21 @@ pub type PlonkBLS24_315GnarkProver =
 /// Provers with backend triple `plonk-bw6-633-gnark`
 pub type PlonkBW6_633GnarkProver =
     provers::Prover<provers::gnark::PlonkBackend<core::curve::BW6_633>>;
+
+/// Provers with backend triple `groth16-bn254-arkworks`
+pub type Groth16BN254ArkworksProver =
+    provers::Prover<provers::arkworks::Groth16Backend<core::curve::BN254>>;
+
+/// Provers with backend triple `groth16-bls12-381-arkworks`
+pub type Groth16BLS12_381ArkworksProver =
+    provers::Prover<provers::arkworks::Groth16Backend<core::curve::BLS12_381>>;
+
+/// Provers with backend triple `plonk-bn254-halo2`
+pub type PlonkBN254Halo2Prover = provers::Prover<provers::halo2::Halo2Backend>;
+
+/// Provers with backend triple `plonky2-goldilocks-plonky2`
+#[cfg(feature = "plonky2")]
+pub type Plonky2GoldilocksProver = provers::Prover<provers::plonky2::Plonky2Backend>;
+
+/// Provers with backend triple `plonky3-babybear-plonky3`
+pub type Plonky3BabyBearProver = provers::Prover<provers::plonky3::Plonky3Backend>;
@@ -0,0 +1,365 @@
+use std::marker::PhantomData;
+
+use anyhow::Result;
+use rsnark_core::{
+    BigInt, Circuit, CircuitElement, CircuitPublicWitness, CircuitWitness, CurveId, Metadata,
+    MetadataInfo,
+    curve::{BLS12_377, BLS12_381, BLS24_315, BLS24_317, BN254, BW6_633, BW6_761},
+    fingerprint::Fingerprint,
+    passes::OptimizationLevel,
+    types::CircuitDefinition,
+};
+use rsnark_provers_arkworks::ArkCurve;
+use rsnark_provers_core::{
+    Backend, DynBackend, define_circuit, flatten_public_witness, flatten_witness,
+};
+use rsnark_provers_gnark::{GnarkConfig, Groth16Backend, KzgSrs, PlonkBackend, PlonkConfig};
+use rsnark_provers_halo2::{Halo2Backend, Halo2Config};
+use rsnark_provers_mock::MockProverBackend;
+use rsnark_provers_plonky3::Plonky3Backend;
+
+/// Error type for the selection of a backend at runtime.
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    #[error("unknown backend triple: {0}")]
+    UnknownTriple(String),
+
+    #[error("the backend of {triple} does not support the {option} option")]
+    UnsupportedOption {
+        triple: &'static str,
+        option: &'static str,
+    },
+
+    #[error(transparent)]
+    BackendError(anyhow::Error),
+}
+
+/// Options of a [`DynProver`], for the backends that support them.
+///
+/// Backends of the other triples fail with [`RegistryError::UnsupportedOption`].
+///
+/// ```rust,ignore
+/// let config = DynConfig {
+///     srs: Some(KzgSrs::Ptau("powersOfTau28_hez_final_20.ptau".into())),
+///     ..Default::default()
+/// };
+/// let prover = DynProver::from_triple_with_config("plonk-bn254-gnark", &config)?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DynConfig {
+    /// SRS of the setup of `plonk-*-gnark`, see [`PlonkBackend::with_srs`].
+    pub srs: Option<KzgSrs>,
+    /// Seed of an insecure setup of the gnark, halo2 and mock backends, see
+    /// [`Groth16Backend::with_insecure_seed`]. For `plonk-*-gnark`, it replaces
+    /// the SRS with one derived from the seed.
+    pub insecure_seed: Option<u64>,
+}
+
+/// Prover whose backend is selected at runtime by its backend triple.
+///
+/// The triples are those of the prover aliases of this crate, such as
+/// `groth16-bn254-gnark` or `plonk-bls12-381-gnark`, and `mock` for the mock
+/// backend; see [`DynProver::triples`]. Curve names may be written with `-` or
+/// `_`. The backends are created with their default configuration, unless
+/// given a [`DynConfig`]; `plonk-*-gnark` provers need one with an SRS or a
+/// seed for their `setup`, and `plonk-bn254-halo2` one with a seed.
+///
+/// Keys and proofs are opaque bytes in the serialization of the backend, so
+/// they can be stored and loaded without knowing the backend at compile time.
+///
+/// ```rust,ignore
+/// let prover = DynProver::from_triple(&config.backend)?.compile_circuit::<MyCircuit>()?;
+/// let proof = prover.prove(&fs::read("circuit.pk")?, &witness)?;
+/// ```
+pub struct DynProver {
+    triple: &'static str,
+    backend: Box<dyn DynBackend>,
+    optimization_level: OptimizationLevel,
+}
+
+impl DynProver {
+    /// Creates a prover with the backend of `triple`.
+    ///
+    /// Fails with [`RegistryError::UnknownTriple`] if no backend is registered
+    /// for it.
+    pub fn from_triple(triple: &str) -> Result<Self, RegistryError> {
+        Self::from_triple_with_config(triple, &DynConfig::default())
+    }
+
+    /// Creates a prover with the backend of `triple`, configured by `config`.
+    ///
+    /// Fails with [`RegistryError::UnknownTriple`] if no backend is registered
+    /// for it, with [`RegistryError::UnsupportedOption`] if its backend does
+    /// not support an option set in `config`, and with
+    /// [`RegistryError::BackendError`] if its backend rejects `config`.
+    pub fn from_triple_with_config(
+        triple: &str,
+        config: &DynConfig,
+    ) -> Result<Self, RegistryError> {
+        let normalized = triple.replace('_', "-");
+        let (triple, factory) = BACKENDS
+            .iter()
+            .find(|(name, _)| *name == normalized)
+            .ok_or_else(|| RegistryError::UnknownTriple(triple.to_string()))?;
+
+        let backend = factory(triple, config)?;
+
+        Ok(Self {
+            triple,
+            backend,
+            optimization_level: OptimizationLevel::default(),
+        })
+    }
+
+    /// Returns the registered backend triples.
+    pub fn triples() -> impl Iterator<Item = &'static str> {
+        BACKENDS.iter().map(|(triple, _)| *triple)
+    }
+
+    /// Returns the backend triple of this prover.
+    pub fn triple(&self) -> &'static str {
+        self.triple
+    }
+
+    /// Returns the field, curve and proving system of the backend.
+    pub fn metadata(&self) -> MetadataInfo {
+        self.backend.metadata()
+    }
+
+    /// Sets the optimization level applied to circuits before compilation.
+    ///
+    /// Defaults to [`OptimizationLevel::None`].
+    pub fn with_optimization_level(mut self, level: OptimizationLevel) -> Self {
+        self.optimization_level = level;
+        self
+    }
+
+    /// Builds the circuit definition of `C` without compiling it, see
+    /// [`Prover::define_circuit`](rsnark_provers_core::Prover::define_circuit).
+    pub fn define_circuit<C>(&self) -> CircuitDefinition
+    where
+        C: CircuitElement,
+        <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+    {
+        define_circuit::<C>(self.metadata(), self.optimization_level)
+    }
+
+    /// Compiles a circuit and creates a circuit-specific prover.
+    pub fn compile_circuit<C>(self) -> Result<DynCircuitProver<C::CircuitWitness>>
+    where
+        C: CircuitElement,
+        <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+    {
+        let define = self.define_circuit::<C>();
+        let metadata = self.metadata();
+        let fingerprint = Fingerprint::new(&define, &metadata);
+
+        Ok(DynCircuitProver {
+            constraint: self
+                .backend
+                .compile(&define)
+                .map_err(anyhow::Error::from_boxed)?,
+            backend: self.backend,
+            fingerprint,
+            field: metadata.field().clone(),
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Circuit-specific prover of a [`DynProver`], with keys and proofs as bytes.
+pub struct DynCircuitProver<C> {
+    backend: Box<dyn DynBackend>,
+    constraint: Vec<u8>,
+    fingerprint: Fingerprint,
+    field: BigInt,
+    marker: PhantomData<C>,
+}
+
+impl<C> DynCircuitProver<C>
+where
+    C: CircuitWitness,
+{
+    /// Returns the fingerprint of the compiled circuit.
+    pub fn fingerprint(&self) -> &Fingerprint {
+        &self.fingerprint
+    }
+
+    /// Performs the setup, and returns the serialized proving and verifying keys.
+    pub fn setup(&self) -> Result<(Vec<u8>, Vec<u8>)> {
+        self.backend
+            .setup(&self.constraint)
+            .map_err(anyhow::Error::from_boxed)
+    }
+
+    /// Generates a proof for `circuit_witness` with a serialized proving key,
+    /// and returns it serialized.
+    pub fn prove(&self, proving_key: &[u8], circuit_witness: &C) -> Result<Vec<u8>> {
+        let witness = flatten_witness(circuit_witness, &self.field);
+
+        self.backend
+            .prove(&self.constraint, proving_key, &witness)
+            .map_err(anyhow::Error::from_boxed)
+    }
+
+    /// Verifies a serialized proof with a serialized verifying key.
+    ///
+    /// Returns whether the proof is valid, or an error if the key or the proof
+    /// cannot be deserialized.
+    pub fn verify(
+        &self,
+        verifying_key: &[u8],
+        proof: &[u8],
+        public_witness: C::PublicWitness,
+    ) -> Result<bool>
+    where
+        C::PublicWitness: CircuitPublicWitness,
+    {
+        let witness = flatten_public_witness(&public_witness, &self.field);
+
+        self.backend
+            .verify(verifying_key, proof, &witness)
+            .map_err(anyhow::Error::from_boxed)
+    }
+}
+
+/// Creates the backend of a triple.
+type Factory = fn(&'static str, &DynConfig) -> Result<Box<dyn DynBackend>, RegistryError>;
+
+fn factory<B: Configure>(
+    triple: &'static str,
+    config: &DynConfig,
+) -> Result<Box<dyn DynBackend>, RegistryError> {
+    check_options(config, B::OPTIONS)
+        .map_err(|option| RegistryError::UnsupportedOption { triple, option })?;
+    let backend = B::configure(config).map_err(|e| RegistryError::BackendError(e.into()))?;
+
+    Ok(Box::new(backend))
+}
+
+/// The backends of [`DynProver::from_triple`], by backend triple.
+const BACKENDS: &[(&str, Factory)] = &[
+    ("groth16-bn254-gnark", factory::<Groth16Backend<BN254>>),
+    (
+        "groth16-bls12-381-gnark",
+        factory::<Groth16Backend<BLS12_381>>,
+    ),
+    (
+        "groth16-bls12-377-gnark",
+        factory::<Groth16Backend<BLS12_377>>,
+    ),
+    (
+        "groth16-bls24-317-gnark",
+        factory::<Groth16Backend<BLS24_317>>,
+    ),
+    (
+        "groth16-bls24-315-gnark",
+        factory::<Groth16Backend<BLS24_315>>,
+    ),
+    ("groth16-bw6-761-gnark", factory::<Groth16Backend<BW6_761>>),
+    ("groth16-bw6-633-gnark", factory::<Groth16Backend<BW6_633>>),
+    ("plonk-bn254-gnark", factory::<PlonkBackend<BN254>>),
+    ("plonk-bls12-381-gnark", factory::<PlonkBackend<BLS12_381>>),
+    ("plonk-bls12-377-gnark", factory::<PlonkBackend<BLS12_377>>),
+    ("plonk-bls24-317-gnark", factory::<PlonkBackend<BLS24_317>>),
+    ("plonk-bls24-315-gnark", factory::<PlonkBackend<BLS24_315>>),
+    ("plonk-bw6-761-gnark", factory::<PlonkBackend<BW6_761>>),
+    ("plonk-bw6-633-gnark", factory::<PlonkBackend<BW6_633>>),
+    (
+        "groth16-bn254-arkworks",
+        factory::<rsnark_provers_arkworks::Groth16Backend<BN254>>,
+    ),
+    (
+        "groth16-bls12-381-arkworks",
+        factory::<rsnark_provers_arkworks::Groth16Backend<BLS12_381>>,
+    ),
+    ("plonk-bn254-halo2", factory::<Halo2Backend>),
+    ("plonky3-babybear-plonky3", factory::<Plonky3Backend>),
+    ("mock", factory::<MockProverBackend>),
+];
+
+/// Backends of the registry, created from a [`DynConfig`].
+trait Configure: Backend + DynBackend + Sized + 'static {
+    /// Options of [`DynConfig`] supported by the backend.
+    const OPTIONS: &'static [&'static str];
+
+    /// Returns the backend configured by the supported options of `config`.
+    fn configure(config: &DynConfig) -> Result<Self, Self::Error>;
+}
+
+/// Fails with the name of the first option set in `config` that is not `supported`.
+fn check_options(config: &DynConfig, supported: &[&'static str]) -> Result<(), &'static str> {
+    let options = [
+        ("srs", config.srs.is_some()),
+        ("insecure_seed", config.insecure_seed.is_some()),
+    ];
+
+    match options
+        .into_iter()
+        .find(|(option, set)| *set && !supported.contains(option))
+    {
+        Some((option, _)) => Err(option),
+        None => Ok(()),
+    }
+}
+
+impl<C: CurveId + 'static> Configure for Groth16Backend<C> {
+    const OPTIONS: &'static [&'static str] = &["insecure_seed"];
+
+    fn configure(config: &DynConfig) -> Result<Self, Self::Error> {
+        Self::with_config(GnarkConfig {
+            insecure_seed: config.insecure_seed,
+            ..Default::default()
+        })
+    }
+}
+
+impl<C: CurveId + 'static> Configure for PlonkBackend<C> {
+    const OPTIONS: &'static [&'static str] = &["srs", "insecure_seed"];
+
+    fn configure(config: &DynConfig) -> Result<Self, Self::Error> {
+        Self::with_config(PlonkConfig {
+            gnark: GnarkConfig {
+                insecure_seed: config.insecure_seed,
+                ..Default::default()
+            },
+            srs: config.srs.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+impl Configure for MockProverBackend {
+    const OPTIONS: &'static [&'static str] = &["insecure_seed"];
+
+    fn configure(config: &DynConfig) -> Result<Self, Self::Error> {
+        Ok(Self::new().with_insecure_seed(config.insecure_seed.unwrap_or_default()))
+    }
+}
+
+impl<C: ArkCurve + 'static> Configure for rsnark_provers_arkworks::Groth16Backend<C> {
+    const OPTIONS: &'static [&'static str] = &[];
+
+    fn configure(_config: &DynConfig) -> Result<Self, Self::Error> {
+        Self::with_config(())
+    }
+}
+
+impl Configure for Halo2Backend {
+    const OPTIONS: &'static [&'static str] = &["insecure_seed"];
+
+    fn configure(config: &DynConfig) -> Result<Self, Self::Error> {
+        Self::with_config(Halo2Config {
+            insecure_seed: config.insecure_seed,
+            ..Default::default()
+        })
+    }
+}
+
+impl Configure for Plonky3Backend {
+    const OPTIONS: &'static [&'static str] = &[];
+
+    fn configure(_config: &DynConfig) -> Result<Self, Self::Error> {
+        Self::with_config(())
+    }
+}
@@ -0,0 +1,82 @@
+//! Runs the same circuits through the halo2 and gnark PLONK backends over BN254,
+//! and checks that they accept and reject the same witnesses.
+
+use rsnark::core::{
+    Circuit, CircuitElement, CircuitPublicWitness, CircuitWitness, Witness, curve::BN254, types,
+};
+use rsnark_provers_core::{
+    Backend, Prover,
+    test_circuits::{RangeCircuit, TestCircuit},
+};
+use rsnark_provers_gnark::PlonkBackend;
+use rsnark_provers_halo2::Halo2Backend;
+
+/// Returns whether `witness` can be proven, and whether the proof verifies
+/// against its own public witness and against `other`.
+fn outcome<B, C>(
+    backend: B,
+    witness: &Witness<C>,
+    other: &types::PublicWitness,
+) -> (bool, bool, bool)
+where
+    B: Backend,
+    C: CircuitElement,
+    <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+{
+    let circuit_prover = Prover::from_backend(backend)
+        .compile_circuit::<C>()
+        .unwrap();
+    let (pk, vk) = circuit_prover.setup().unwrap();
+
+    let Ok(proof) = circuit_prover.prove(&pk, witness) else {
+        return (false, false, false);
+    };
+
+    let mut public = types::PublicWitness::new();
+    witness.append_public_witness(public.public_mut(), false);
+
+    let backend = B::new();
+    (
+        true,
+        backend.verify(&vk, &proof, &public).unwrap(),
+        backend.verify(&vk, &proof, other).unwrap(),
+    )
+}
+
+fn compare<C>(witness: Witness<C>, other: u32)
+where
+    C: CircuitElement,
+    <C::CircuitWitness as CircuitWitness>::CircuitElement: Circuit,
+{
+    let mut other_public = types::PublicWitness::new();
+    other_public.public_mut().push(other.into());
+
+    assert_eq!(
+        outcome::<_, C>(
+            Halo2Backend::new().insecure_test_params(),
+            &witness,
+            &other_public
+        ),
+        outcome::<_, C>(
+            PlonkBackend::<BN254>::new().insecure_test_srs().unwrap(),
+            &witness,
+            &other_public
+        ),
+    );
+}
+
+#[test]
+fn test_same_outcomes() {
+    compare::<TestCircuit>(Witness::<TestCircuit> { a: 3, b: 4, c: 7 }, 8);
+    compare::<TestCircuit>(Witness::<TestCircuit> { a: 3, b: 4, c: 8 }, 7);
+
+    compare::<RangeCircuit>(Witness::<RangeCircuit> { x: 6, y: 5, z: 15 }, 16);
+    compare::<RangeCircuit>(
+        Witness::<RangeCircuit> {
+            x: 256,
+            y: 1,
+            z: 128,
+        },
+        128,
+    );
+}
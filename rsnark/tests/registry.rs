@@ -0,0 +1,87 @@
+//! Selects backends at runtime by their backend triple.
+
+use rsnark::{
+    DynConfig, DynProver, RegistryError,
+    core::{CircuitWitness, Witness},
+};
+use rsnark_provers_core::test_circuits::TestCircuit;
+
+#[test]
+fn test_prove_with_triples() {
+    let seeded = DynConfig {
+        insecure_seed: Some(1),
+        ..Default::default()
+    };
+
+    for (triple, config) in [
+        ("mock", DynConfig::default()),
+        ("groth16-bn254-arkworks", DynConfig::default()),
+        ("plonky3-babybear-plonky3", DynConfig::default()),
+        ("plonk-bn254-halo2", seeded.clone()),
+        ("groth16-bn254-gnark", DynConfig::default()),
+        ("plonk-bn254-gnark", seeded),
+    ] {
+        let prover = DynProver::from_triple_with_config(triple, &config)
+            .unwrap()
+            .compile_circuit::<TestCircuit>()
+            .unwrap();
+        let (pk, vk) = prover.setup().unwrap();
+
+        let witness = Witness::<TestCircuit> { a: 3, b: 4, c: 7 };
+        let proof = prover.prove(&pk, &witness).unwrap();
+
+        assert!(
+            prover
+                .verify(&vk, &proof, witness.into_public_witness())
+                .unwrap(),
+            "{triple}"
+        );
+    }
+}
+
+#[test]
+fn test_triples() {
+    assert!(DynProver::triples().any(|triple| triple == "plonk-bls12-381-gnark"));
+    assert_eq!(
+        DynProver::from_triple("groth16-bls12_381-gnark")
+            .unwrap()
+            .triple(),
+        "groth16-bls12-381-gnark"
+    );
+    assert!(matches!(
+        DynProver::from_triple("groth16-secp256k1-gnark"),
+        Err(RegistryError::UnknownTriple(_))
+    ));
+}
+
+#[test]
+fn test_config() {
+    // Without an SRS, the PLONK setup of gnark fails instead of guessing one.
+    let prover = DynProver::from_triple("plonk-bn254-gnark")
+        .unwrap()
+        .compile_circuit::<TestCircuit>()
+        .unwrap();
+    assert!(prover.setup().is_err());
+
+    let seeded = DynConfig {
+        insecure_seed: Some(1),
+        ..Default::default()
+    };
+    let vk = |triple| {
+        let prover = DynProver::from_triple_with_config(triple, &seeded)
+            .unwrap()
+            .compile_circuit::<TestCircuit>()
+            .unwrap();
+        prover.setup().unwrap().1
+    };
+    assert_eq!(vk("groth16-bn254-gnark"), vk("groth16-bn254-gnark"));
+    assert_eq!(vk("mock"), vk("mock"));
+
+    assert!(matches!(
+        DynProver::from_triple_with_config("groth16-bn254-arkworks", &seeded),
+        Err(RegistryError::UnsupportedOption {
+            option: "insecure_seed",
+            ..
+        })
+    ));
+}